@@ -0,0 +1,570 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use crate::codec::CodecError;
+pub use crate::connection::PendingResponse;
+use crate::connection::{lock, Incoming, MessageSender, Received};
+use crate::events::{Event, EventBody};
+use crate::paths::{self, PathMappings, Sources};
+use crate::positions::{Bases, Positions, Shift};
+use crate::requests::*;
+use crate::responses::{ErrorResponse, Response, ResponseMessage};
+use crate::session::{Phase, Session};
+use crate::types::{Message, ProgressId};
+use crate::{MessageKind, ProtocolMessage};
+
+/**
+ * The reason a handler failed. The runner turns it into a response with
+ * 'success' set to false.
+ */
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RequestError {
+    /**
+     * The short, machine readable reason for the failure.
+     */
+    pub message: Option<ResponseMessage>,
+
+    /**
+     * An optional, structured error message shown to the user.
+     */
+    pub error: Option<Box<Message>>,
+}
+
+impl RequestError {
+    /**
+     * Fails with an error message that is shown to the user as is.
+     */
+    pub fn new(message: impl Into<String>) -> Self {
+        RequestError {
+            message: Some(ResponseMessage::Error(message.into())),
+            error: None,
+        }
+    }
+
+    /**
+     * Fails because the adapter does not implement the command.
+     */
+    pub fn unsupported(command: &str) -> Self {
+        RequestError::new(format!("the '{}' request is not supported", command))
+    }
+
+    /**
+     * Fails because the request was cancelled by a 'cancel' request.
+     */
+    pub fn cancelled() -> Self {
+        ResponseMessage::Cancelled.into()
+    }
+
+    /**
+     * Fails because the request can only be handled while the debuggee is
+     * stopped.
+     */
+    pub fn not_stopped() -> Self {
+        ResponseMessage::NotStopped.into()
+    }
+
+    /**
+     * Adds a structured error message.
+     */
+    pub fn with_error(mut self, error: Message) -> Self {
+        self.error = Some(Box::new(error));
+        self
+    }
+
+    /**
+     * The failed response to the request with the given sequence number and
+     * command.
+     */
+    pub(crate) fn into_response(self, request_seq: i64, command: String) -> Response {
+        Response {
+            request_seq,
+            message: self.message,
+            result: Err(ErrorResponse {
+                command,
+                error: self.error.map(|error| *error),
+                body: None,
+            }),
+        }
+    }
+}
+
+impl From<ResponseMessage> for RequestError {
+    fn from(message: ResponseMessage) -> Self {
+        RequestError {
+            message: Some(message),
+            error: None,
+        }
+    }
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.message, &self.error) {
+            (_, Some(error)) => f.write_str(&error.format),
+            (Some(ResponseMessage::Cancelled), None) => f.write_str("cancelled"),
+            (Some(ResponseMessage::NotStopped), None) => f.write_str("not stopped"),
+            (Some(ResponseMessage::Error(message)), None) => f.write_str(message),
+            (None, None) => f.write_str("request failed"),
+        }
+    }
+}
+
+impl Error for RequestError {}
+
+/**
+ * The result of a handler: the body of the successful response, or the reason
+ * the request failed.
+ */
+pub type HandlerResult<R> = Result<<R as Request>::Response, RequestError>;
+
+/**
+ * What the client asked to cancel with 'cancel' requests.
+ */
+#[derive(Debug, Default)]
+struct Cancellations {
+    /**
+     * The requests that were received but not answered yet, and whether they
+     * are cancelled.
+     */
+    requests: HashMap<i64, bool>,
+
+    /**
+     * The progress that is cancelled and has not ended yet.
+     */
+    progress: HashSet<ProgressId>,
+}
+
+/**
+ * A handle to the client connected to the adapter. Handlers use it to send
+ * events and reverse requests, also from other threads.
+ */
+#[derive(Debug, Clone)]
+pub struct Client {
+    sender: MessageSender,
+    session: Arc<Mutex<Session>>,
+    positions: Option<Bases>,
+    path_format: Option<PathFormat>,
+    path_mappings: Arc<Mutex<PathMappings>>,
+    cancellations: Arc<Mutex<Cancellations>>,
+    /**
+     * The sequence number of the request being handled, if any.
+     */
+    request_seq: Option<i64>,
+}
+
+impl Client {
+    /**
+     * Sends an event to the client.
+     */
+    pub fn send_event(&self, body: EventBody) -> Result<(), CodecError> {
+        // Events are not held back when they violate the protocol, as the
+        // adapter knows best what happened to the debuggee.
+        let _ = lock(&self.session).event(&body);
+        if let EventBody::ProgressEnd(end) = &body {
+            lock(&self.cancellations).progress.remove(&end.progress_id);
+        }
+        let mut event = Event::new(body);
+        self.outgoing(&mut event);
+        self.sender.send(MessageKind::Event(event))
+    }
+
+    /**
+     * The shift from the positions of the adapter to those of the client, and
+     * the path formats of the adapter and the client. There are none before
+     * 'initialize'.
+     */
+    fn conventions(&self) -> Option<(Shift, PathFormat, PathFormat)> {
+        let session = lock(&self.session);
+        let client = &session.negotiated()?.client;
+        let shift = self
+            .positions
+            .map(|adapter| Shift::new(adapter, Bases::of_client(client)))
+            .unwrap_or_default();
+        let client_format = paths::client_format(client);
+        let adapter_format = self.path_format.clone().unwrap_or(client_format.clone());
+        Some((shift, adapter_format, client_format))
+    }
+
+    /**
+     * Converts a message from the conventions of the adapter to those of the
+     * client.
+     */
+    fn outgoing<M: Positions + Sources>(&self, message: &mut M) {
+        lock(&self.path_mappings).map_to_local(message);
+        if let Some((shift, adapter, client)) = self.conventions() {
+            message.shift(shift);
+            message.convert_paths(&adapter, &client);
+        }
+    }
+
+    /**
+     * Converts a message from the conventions of the client to those of the
+     * adapter.
+     */
+    fn incoming<M: Positions + Sources>(&self, message: &mut M) {
+        if let Some((shift, adapter, client)) = self.conventions() {
+            message.shift(shift.reverse());
+            message.convert_paths(&client, &adapter);
+        }
+        lock(&self.path_mappings).map_to_remote(message);
+    }
+
+    /**
+     * Sets the rules that map the source paths of the debuggee to those of the
+     * client, for example from the attach configuration. Paths in responses and
+     * events are mapped to local paths, paths in requests to remote paths.
+     */
+    pub fn set_path_mappings(&self, mappings: PathMappings) {
+        *lock(&self.path_mappings) = mappings;
+    }
+
+    /**
+     * The stage the session is in.
+     */
+    pub fn phase(&self) -> Phase {
+        lock(&self.session).phase()
+    }
+
+    /**
+     * A snapshot of the session, including the capabilities returned from
+     * [`DebugAdapter::initialize`].
+     */
+    pub fn session(&self) -> Session {
+        lock(&self.session).clone()
+    }
+
+    /**
+     * Returns true if the client sent a 'cancel' request for the request being
+     * handled. A long running handler can check this, also from another thread,
+     * and fail with [`RequestError::cancelled`].
+     */
+    pub fn is_cancelled(&self) -> bool {
+        self.request_seq
+            .and_then(|seq| lock(&self.cancellations).requests.get(&seq).copied())
+            .unwrap_or(false)
+    }
+
+    /**
+     * Returns true if the client sent a 'cancel' request for the progress with
+     * the given ID, and the adapter has not sent its 'progressEnd' event yet.
+     */
+    pub fn is_progress_cancelled(&self, progress_id: &ProgressId) -> bool {
+        lock(&self.cancellations).progress.contains(progress_id)
+    }
+
+    /**
+     * Answers a 'cancel' request, marking the request or progress it names as
+     * cancelled.
+     */
+    fn cancel(&self, seq: i64, arguments: CancelArguments) -> Response {
+        let command = RequestCommand::Cancel(arguments.clone());
+        if let Err(err) = accept(&mut lock(&self.session), &command) {
+            return err.into_response(seq, CancelArguments::COMMAND.to_string());
+        }
+        let mut cancellations = lock(&self.cancellations);
+        if let Some(cancelled) = arguments
+            .request_id
+            .and_then(|request_id| cancellations.requests.get_mut(&request_id))
+        {
+            *cancelled = true;
+        }
+        if let Some(progress_id) = arguments.progress_id {
+            cancellations.progress.insert(progress_id);
+        }
+        respond::<CancelArguments>(seq, Ok(()))
+    }
+
+    /**
+     * Sends a reverse request, such as 'runInTerminal', to the client. The
+     * response can be waited for from any thread, including from a handler.
+     */
+    pub fn send_request<R: Request>(&self, arguments: R) -> Result<PendingResponse, CodecError> {
+        self.sender.send_request(arguments.into())
+    }
+}
+
+fn respond<R: Request>(request_seq: i64, result: HandlerResult<R>) -> Response {
+    match result {
+        Ok(body) => Response::success(request_seq, R::into_response_body(body)),
+        Err(err) => err.into_response(request_seq, R::COMMAND.to_string()),
+    }
+}
+
+macro_rules! debug_adapter {
+    (@arguments $arguments:ident) => {
+        $arguments::default()
+    };
+    (@arguments $arguments:ident $binding:ident) => {
+        $binding
+    };
+    ($($method:ident($arguments:ident) = $command:ident $(($binding:ident))?,)*) => {
+        /**
+         * A debug adapter, with one handler per request the client may send. Each
+         * handler defaults to failing with [`RequestError::unsupported`], so an
+         * adapter only implements the requests it supports, in line with the
+         * capabilities it returns from [`DebugAdapter::initialize`].
+         *
+         * Handlers run one at a time, in the order the requests arrive. Use the
+         * [`Client`] to send events and reverse requests.
+         *
+         * The runner answers 'cancel' requests itself as soon as they arrive,
+         * also while a handler runs. A request cancelled before its handler
+         * starts fails with [`RequestError::cancelled`] without reaching it; a
+         * running handler can check [`Client::is_cancelled`].
+         *
+         * Requests the client sends out of order, such as 'stackTrace' before
+         * 'launch', fail without reaching a handler, see [`Session`]. So do
+         * requests and arguments that need a capability the adapter did not
+         * return from 'initialize', see
+         * [`Negotiated`](crate::capabilities::Negotiated).
+         */
+        pub trait DebugAdapter {
+            /**
+             * The bases of the lines and columns the handlers work with. The
+             * positions in requests, responses and events are converted between
+             * these and the bases the client announced in 'initialize', see
+             * [`positions`](crate::positions). Defaults to `None`, which passes
+             * positions through as the client sent them.
+             */
+            fn positions(&self) -> Option<Bases> {
+                None
+            }
+
+            /**
+             * The format of the source paths the handlers work with. The paths
+             * in requests, responses and events are converted between this and
+             * the format the client announced in 'initialize', see
+             * [`paths`](crate::paths). Defaults to `None`, which passes paths
+             * through as the client sent them.
+             */
+            fn path_format(&self) -> Option<PathFormat> {
+                None
+            }
+
+            /**
+             * Whether the runner sends the 'initialized' event right after a
+             * successful response to 'initialize'. Defaults to `false`, for
+             * adapters that are ready for configuration requests only later,
+             * for example once the debuggee started; they send the event with
+             * [`Client::send_event`].
+             */
+            fn send_initialized(&self) -> bool {
+                false
+            }
+
+            $(
+                #[doc = concat!("Handles the [`RequestCommand::", stringify!($command), "`] request.")]
+                fn $method(&mut self, client: &Client, arguments: $arguments) -> HandlerResult<$arguments> {
+                    let _ = (client, arguments);
+                    Err(RequestError::unsupported($arguments::COMMAND))
+                }
+            )*
+        }
+
+        fn dispatch<A: DebugAdapter + ?Sized>(
+            adapter: &mut A,
+            client: &Client,
+            seq: i64,
+            command: RequestCommand,
+        ) -> Response {
+            match command {
+                $(
+                    RequestCommand::$command $(($binding))? => respond::<$arguments>(
+                        seq,
+                        adapter.$method(client, debug_adapter!(@arguments $arguments $($binding)?)),
+                    ),
+                )*
+                // 'cancel' is answered by the runner, and reverse requests
+                // are sent by the adapter, not to it.
+                command @ (RequestCommand::Cancel(_)
+                | RequestCommand::RunInTerminal(_)
+                | RequestCommand::StartDebugging(_)
+                | RequestCommand::Unknown { .. }) => {
+                    let command = command.command().to_string();
+                    RequestError::unsupported(&command).into_response(seq, command)
+                }
+            }
+        }
+    };
+}
+
+debug_adapter! {
+    initialize(InitializeRequestArguments) = Initialize(arguments),
+    configuration_done(ConfigurationDoneArguments) = ConfigurationDone,
+    launch(LaunchRequestArguments) = Launch(arguments),
+    attach(AttachRequestArguments) = Attach(arguments),
+    restart(RestartArguments) = Restart(arguments),
+    disconnect(DisconnectArguments) = Disconnect(arguments),
+    terminate(TerminateArguments) = Terminate(arguments),
+    breakpoint_locations(BreakpointLocationsArguments) = BreakpointLocations(arguments),
+    set_breakpoints(SetBreakpointsArguments) = SetBreakpoints(arguments),
+    set_function_breakpoints(SetFunctionBreakpointsArguments) = SetFunctionBreakpoints(arguments),
+    set_exception_breakpoints(SetExceptionBreakpointsArguments) = SetExceptionBreakpoints(arguments),
+    data_breakpoint_info(DataBreakpointInfoArguments) = DataBreakpointInfo(arguments),
+    set_data_breakpoints(SetDataBreakpointsArguments) = SetDataBreakpoints(arguments),
+    set_instruction_breakpoints(SetInstructionBreakpointsArguments) = SetInstructionBreakpoints(arguments),
+    continue_(ContinueArguments) = Continue(arguments),
+    next(NextArguments) = Next(arguments),
+    step_in(StepInArguments) = StepIn(arguments),
+    step_out(StepOutArguments) = StepOut(arguments),
+    step_back(StepBackArguments) = StepBack(arguments),
+    reverse_continue(ReverseContinueArguments) = ReverseContinue(arguments),
+    restart_frame(RestartFrameArguments) = RestartFrame(arguments),
+    goto(GotoArguments) = Goto(arguments),
+    pause(PauseArguments) = Pause(arguments),
+    stack_trace(StackTraceArguments) = StackTrace(arguments),
+    scopes(ScopesArguments) = Scopes(arguments),
+    variables(VariablesArguments) = Variables(arguments),
+    set_variable(SetVariableArguments) = SetVariable(arguments),
+    source(SourceArguments) = Source(arguments),
+    threads(ThreadsArguments) = Threads,
+    terminate_threads(TerminateThreadsArguments) = TerminateThreads(arguments),
+    modules(ModulesArguments) = Modules(arguments),
+    loaded_sources(LoadedSourcesArguments) = LoadedSources,
+    evaluate(EvaluateArguments) = Evaluate(arguments),
+    set_expression(SetExpressionArguments) = SetExpression(arguments),
+    step_in_targets(StepInTargetsArguments) = StepInTargets(arguments),
+    goto_targets(GotoTargetsArguments) = GotoTargets(arguments),
+    completions(CompletionsArguments) = Completions(arguments),
+    exception_info(ExceptionInfoArguments) = ExceptionInfo(arguments),
+    read_memory(ReadMemoryArguments) = ReadMemory(arguments),
+    write_memory(WriteMemoryArguments) = WriteMemory(arguments),
+    disassemble(DisassembleArguments) = Disassemble(arguments),
+    locations(LocationsArguments) = Locations(arguments),
+}
+
+/**
+ * Records a request in the session, unless it is out of order or needs a
+ * capability that was not announced.
+ */
+fn accept(session: &mut Session, command: &RequestCommand) -> Result<(), RequestError> {
+    session
+        .check_request(command)
+        .map_err(|violation| RequestError::new(violation.to_string()))?;
+    if let Some(negotiated) = session.negotiated() {
+        negotiated
+            .check_request(command)
+            .map_err(|unsupported| RequestError::new(unsupported.to_string()))?;
+    }
+    session
+        .request(command)
+        .map_err(|violation| RequestError::new(violation.to_string()))
+}
+
+/**
+ * Runs a debug adapter over a transport, such as stdin and stdout, until the
+ * client sends 'disconnect' or closes the stream.
+ *
+ * Messages are read on a separate thread, so that a handler may block on the
+ * response to a reverse request.
+ *
+ * A request that does not parse, such as one with invalid arguments, fails
+ * without reaching a handler, and the runner goes on with the next message.
+ * It stops with an error if the stream fails or the framing of a message is
+ * invalid.
+ */
+pub fn run<A, R, W>(adapter: &mut A, reader: R, writer: W) -> Result<(), CodecError>
+where
+    A: DebugAdapter + ?Sized,
+    R: BufRead + Send + 'static,
+    W: Write + Send + 'static,
+{
+    let client = Client {
+        sender: MessageSender::new(Box::new(writer)),
+        session: Arc::new(Mutex::new(Session::new())),
+        positions: adapter.positions(),
+        path_format: adapter.path_format(),
+        path_mappings: Arc::new(Mutex::new(PathMappings::new())),
+        cancellations: Arc::new(Mutex::new(Cancellations::default())),
+        request_seq: None,
+    };
+    let (sender, requests) = mpsc::channel();
+    let reader_client = client.clone();
+    let router = client.sender.clone();
+    thread::spawn(move || {
+        for received in Incoming::new(reader) {
+            match received {
+                Ok(Received::Message(ProtocolMessage {
+                    message: MessageKind::Response(response),
+                    ..
+                }))
+                | Ok(Received::InvalidResponse(response)) => router.receive_response(response),
+                // Answered right away, so that it does not wait for the
+                // handler of the request it cancels.
+                Ok(Received::Message(ProtocolMessage {
+                    seq,
+                    message: MessageKind::Request(RequestCommand::Cancel(arguments)),
+                })) => {
+                    let response = reader_client.cancel(seq, arguments);
+                    if router.send(MessageKind::Response(response)).is_err() {
+                        break;
+                    }
+                }
+                Ok(Received::Message(ProtocolMessage {
+                    seq,
+                    message: MessageKind::Request(command),
+                })) => {
+                    lock(&reader_client.cancellations)
+                        .requests
+                        .insert(seq, false);
+                    if sender.send(Ok((seq, command))).is_err() {
+                        break;
+                    }
+                }
+                // Clients do not send events.
+                Ok(Received::Message(_)) => {}
+                Ok(Received::InvalidRequest(response)) => {
+                    if router.send(MessageKind::Response(response)).is_err() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    let _ = sender.send(Err(err));
+                    break;
+                }
+            }
+        }
+        // Wake up handlers still waiting for a response.
+        router.close();
+    });
+
+    for request in requests {
+        let (seq, mut command) = request?;
+        let disconnect = matches!(command, RequestCommand::Disconnect(_));
+        let handle = Client {
+            request_seq: Some(seq),
+            ..client.clone()
+        };
+        let accepted = if handle.is_cancelled() {
+            Err(RequestError::cancelled())
+        } else {
+            accept(&mut lock(&client.session), &command)
+        };
+        let response = match accepted {
+            Ok(()) => {
+                client.incoming(&mut command);
+                let mut response = dispatch(adapter, &handle, seq, command);
+                client.outgoing(&mut response);
+                response
+            }
+            Err(err) => err.into_response(seq, command.command().to_string()),
+        };
+        lock(&client.session).response(&response);
+        lock(&client.cancellations).requests.remove(&seq);
+        let initialized = adapter.send_initialized()
+            && response.is_success()
+            && response.command() == "initialize";
+        client.sender.send(MessageKind::Response(response))?;
+        if initialized {
+            client.send_event(EventBody::Initialized)?;
+        }
+        if disconnect {
+            break;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,510 @@
+/*!
+
+Decides which requests, arguments and events are allowed given the
+capabilities the client and the adapter exchanged in 'initialize'.
+
+The adapter may use [`Negotiated::check_request`] to reject requests it did
+not advertise, and the client may use [`Negotiated::supports`] to hide
+features the adapter lacks.
+
+*/
+
+use std::error::Error;
+use std::fmt;
+
+use crate::events::EventBody;
+use crate::requests::{EvalContext, InitializeRequestArguments, RequestCommand};
+use crate::types::Capabilities;
+
+/**
+ * A feature one side of the connection announces in 'initialize'. The
+ * variants are named after the flags in [`Capabilities`], followed by those in
+ * [`InitializeRequestArguments`] that describe the client.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Capability {
+    ConfigurationDoneRequest,
+    FunctionBreakpoints,
+    ConditionalBreakpoints,
+    HitConditionalBreakpoints,
+    EvaluateForHovers,
+    StepBack,
+    SetVariable,
+    RestartFrame,
+    GotoTargetsRequest,
+    StepInTargetsRequest,
+    CompletionsRequest,
+    ModulesRequest,
+    RestartRequest,
+    ExceptionOptions,
+    ValueFormattingOptions,
+    ExceptionInfoRequest,
+    TerminateDebuggee,
+    SuspendDebuggee,
+    DelayedStackTraceLoading,
+    LoadedSourcesRequest,
+    LogPoints,
+    TerminateThreadsRequest,
+    SetExpression,
+    TerminateRequest,
+    DataBreakpoints,
+    ReadMemoryRequest,
+    WriteMemoryRequest,
+    DisassembleRequest,
+    CancelRequest,
+    BreakpointLocationsRequest,
+    ClipboardContext,
+    SteppingGranularity,
+    InstructionBreakpoints,
+    ExceptionFilterOptions,
+    SingleThreadExecutionRequests,
+    DataBreakpointBytes,
+
+    /**
+     * ANSI escape sequences in output and variable values. Both sides have to
+     * support it.
+     */
+    AnsiStyling,
+
+    VariableType,
+    VariablePaging,
+    RunInTerminalRequest,
+    MemoryReferences,
+    ProgressReporting,
+    InvalidatedEvent,
+    MemoryEvent,
+    ArgsCanBeInterpretedByShell,
+    StartDebuggingRequest,
+}
+
+impl Capability {
+    /**
+     * The name of the flag in [`Capabilities`] or
+     * [`InitializeRequestArguments`], as it appears on the wire.
+     */
+    pub fn name(self) -> &'static str {
+        match self {
+            Capability::ConfigurationDoneRequest => "supportsConfigurationDoneRequest",
+            Capability::FunctionBreakpoints => "supportsFunctionBreakpoints",
+            Capability::ConditionalBreakpoints => "supportsConditionalBreakpoints",
+            Capability::HitConditionalBreakpoints => "supportsHitConditionalBreakpoints",
+            Capability::EvaluateForHovers => "supportsEvaluateForHovers",
+            Capability::StepBack => "supportsStepBack",
+            Capability::SetVariable => "supportsSetVariable",
+            Capability::RestartFrame => "supportsRestartFrame",
+            Capability::GotoTargetsRequest => "supportsGotoTargetsRequest",
+            Capability::StepInTargetsRequest => "supportsStepInTargetsRequest",
+            Capability::CompletionsRequest => "supportsCompletionsRequest",
+            Capability::ModulesRequest => "supportsModulesRequest",
+            Capability::RestartRequest => "supportsRestartRequest",
+            Capability::ExceptionOptions => "supportsExceptionOptions",
+            Capability::ValueFormattingOptions => "supportsValueFormattingOptions",
+            Capability::ExceptionInfoRequest => "supportsExceptionInfoRequest",
+            Capability::TerminateDebuggee => "supportTerminateDebuggee",
+            Capability::SuspendDebuggee => "supportSuspendDebuggee",
+            Capability::DelayedStackTraceLoading => "supportsDelayedStackTraceLoading",
+            Capability::LoadedSourcesRequest => "supportsLoadedSourcesRequest",
+            Capability::LogPoints => "supportsLogPoints",
+            Capability::TerminateThreadsRequest => "supportsTerminateThreadsRequest",
+            Capability::SetExpression => "supportsSetExpression",
+            Capability::TerminateRequest => "supportsTerminateRequest",
+            Capability::DataBreakpoints => "supportsDataBreakpoints",
+            Capability::ReadMemoryRequest => "supportsReadMemoryRequest",
+            Capability::WriteMemoryRequest => "supportsWriteMemoryRequest",
+            Capability::DisassembleRequest => "supportsDisassembleRequest",
+            Capability::CancelRequest => "supportsCancelRequest",
+            Capability::BreakpointLocationsRequest => "supportsBreakpointLocationsRequest",
+            Capability::ClipboardContext => "supportsClipboardContext",
+            Capability::SteppingGranularity => "supportsSteppingGranularity",
+            Capability::InstructionBreakpoints => "supportsInstructionBreakpoints",
+            Capability::ExceptionFilterOptions => "supportsExceptionFilterOptions",
+            Capability::SingleThreadExecutionRequests => "supportsSingleThreadExecutionRequests",
+            Capability::DataBreakpointBytes => "supportsDataBreakpointBytes",
+            Capability::AnsiStyling => "supportsANSIStyling",
+            Capability::VariableType => "supportsVariableType",
+            Capability::VariablePaging => "supportsVariablePaging",
+            Capability::RunInTerminalRequest => "supportsRunInTerminalRequest",
+            Capability::MemoryReferences => "supportsMemoryReferences",
+            Capability::ProgressReporting => "supportsProgressReporting",
+            Capability::InvalidatedEvent => "supportsInvalidatedEvent",
+            Capability::MemoryEvent => "supportsMemoryEvent",
+            Capability::ArgsCanBeInterpretedByShell => "supportsArgsCanBeInterpretedByShell",
+            Capability::StartDebuggingRequest => "supportsStartDebuggingRequest",
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/**
+ * A request or event that needs a capability the other side did not announce.
+ */
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Unsupported {
+    /**
+     * The command of the request, or the type of the event.
+     */
+    pub message: String,
+
+    /**
+     * The argument that needs the capability, or `None` if the whole message
+     * does.
+     */
+    pub argument: Option<&'static str>,
+
+    pub capability: Capability,
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.argument {
+            Some(argument) => write!(
+                f,
+                "the '{}' argument of '{}' needs '{}'",
+                argument, self.message, self.capability
+            ),
+            None => write!(f, "'{}' needs '{}'", self.message, self.capability),
+        }
+    }
+}
+
+impl Error for Unsupported {}
+
+type Requirement = (Option<&'static str>, Capability);
+
+fn breakpoint_requirements(
+    requirements: &mut Vec<Requirement>,
+    condition: bool,
+    hit_condition: bool,
+) {
+    if condition {
+        requirements.push((Some("condition"), Capability::ConditionalBreakpoints));
+    }
+    if hit_condition {
+        requirements.push((Some("hitCondition"), Capability::HitConditionalBreakpoints));
+    }
+}
+
+fn stepping_requirements(
+    requirements: &mut Vec<Requirement>,
+    single_thread: Option<bool>,
+    granularity: bool,
+) {
+    if single_thread == Some(true) {
+        requirements.push((
+            Some("singleThread"),
+            Capability::SingleThreadExecutionRequests,
+        ));
+    }
+    if granularity {
+        requirements.push((Some("granularity"), Capability::SteppingGranularity));
+    }
+}
+
+/**
+ * The capabilities a request needs, together with the argument that needs
+ * each of them.
+ */
+fn request_requirements(command: &RequestCommand) -> Vec<Requirement> {
+    let mut requirements = Vec::new();
+    let whole = match command {
+        RequestCommand::Cancel(_) => Some(Capability::CancelRequest),
+        RequestCommand::ConfigurationDone => Some(Capability::ConfigurationDoneRequest),
+        RequestCommand::Restart(_) => Some(Capability::RestartRequest),
+        RequestCommand::Disconnect(arguments) => {
+            if arguments.terminate_debuggee == Some(true) {
+                requirements.push((Some("terminateDebuggee"), Capability::TerminateDebuggee));
+            }
+            if arguments.suspend_debuggee == Some(true) {
+                requirements.push((Some("suspendDebuggee"), Capability::SuspendDebuggee));
+            }
+            None
+        }
+        RequestCommand::Terminate(_) => Some(Capability::TerminateRequest),
+        RequestCommand::BreakpointLocations(_) => Some(Capability::BreakpointLocationsRequest),
+        RequestCommand::SetBreakpoints(arguments) => {
+            for breakpoint in arguments.breakpoints.iter().flatten() {
+                breakpoint_requirements(
+                    &mut requirements,
+                    breakpoint.condition.is_some(),
+                    breakpoint.hit_condition.is_some(),
+                );
+                if breakpoint.log_message.is_some() {
+                    requirements.push((Some("logMessage"), Capability::LogPoints));
+                }
+            }
+            None
+        }
+        RequestCommand::SetFunctionBreakpoints(arguments) => {
+            for breakpoint in &arguments.breakpoints {
+                breakpoint_requirements(
+                    &mut requirements,
+                    breakpoint.condition.is_some(),
+                    breakpoint.hit_condition.is_some(),
+                );
+            }
+            Some(Capability::FunctionBreakpoints)
+        }
+        RequestCommand::SetExceptionBreakpoints(arguments) => {
+            if arguments.filter_options.is_some() {
+                requirements.push((Some("filterOptions"), Capability::ExceptionFilterOptions));
+            }
+            if arguments.exception_options.is_some() {
+                requirements.push((Some("exceptionOptions"), Capability::ExceptionOptions));
+            }
+            None
+        }
+        RequestCommand::DataBreakpointInfo(arguments) => {
+            if arguments.bytes.is_some() {
+                requirements.push((Some("bytes"), Capability::DataBreakpointBytes));
+            }
+            if arguments.as_address == Some(true) {
+                requirements.push((Some("asAddress"), Capability::DataBreakpointBytes));
+            }
+            Some(Capability::DataBreakpoints)
+        }
+        RequestCommand::SetDataBreakpoints(_) => Some(Capability::DataBreakpoints),
+        RequestCommand::SetInstructionBreakpoints(_) => Some(Capability::InstructionBreakpoints),
+        RequestCommand::Continue(arguments) => {
+            stepping_requirements(&mut requirements, arguments.single_thread, false);
+            None
+        }
+        RequestCommand::Next(arguments) => {
+            stepping_requirements(
+                &mut requirements,
+                arguments.single_thread,
+                arguments.granularity.is_some(),
+            );
+            None
+        }
+        RequestCommand::StepIn(arguments) => {
+            stepping_requirements(
+                &mut requirements,
+                arguments.single_thread,
+                arguments.granularity.is_some(),
+            );
+            None
+        }
+        RequestCommand::StepOut(arguments) => {
+            stepping_requirements(
+                &mut requirements,
+                arguments.single_thread,
+                arguments.granularity.is_some(),
+            );
+            None
+        }
+        RequestCommand::StepBack(arguments) => {
+            stepping_requirements(
+                &mut requirements,
+                arguments.single_thread,
+                arguments.granularity.is_some(),
+            );
+            Some(Capability::StepBack)
+        }
+        RequestCommand::ReverseContinue(arguments) => {
+            stepping_requirements(&mut requirements, arguments.single_thread, false);
+            Some(Capability::StepBack)
+        }
+        RequestCommand::RestartFrame(_) => Some(Capability::RestartFrame),
+        // The targets of 'goto' come from 'gotoTargets'.
+        RequestCommand::Goto(_) | RequestCommand::GotoTargets(_) => {
+            Some(Capability::GotoTargetsRequest)
+        }
+        RequestCommand::StackTrace(arguments) => {
+            if arguments.format.is_some() {
+                requirements.push((Some("format"), Capability::ValueFormattingOptions));
+            }
+            None
+        }
+        RequestCommand::Variables(arguments) => {
+            if arguments.format.is_some() {
+                requirements.push((Some("format"), Capability::ValueFormattingOptions));
+            }
+            None
+        }
+        RequestCommand::SetVariable(arguments) => {
+            if arguments.format.is_some() {
+                requirements.push((Some("format"), Capability::ValueFormattingOptions));
+            }
+            Some(Capability::SetVariable)
+        }
+        RequestCommand::TerminateThreads(_) => Some(Capability::TerminateThreadsRequest),
+        RequestCommand::Modules(_) => Some(Capability::ModulesRequest),
+        RequestCommand::LoadedSources => Some(Capability::LoadedSourcesRequest),
+        RequestCommand::Evaluate(arguments) => {
+            if arguments.format.is_some() {
+                requirements.push((Some("format"), Capability::ValueFormattingOptions));
+            }
+            match arguments.context {
+                Some(EvalContext::Hover) => {
+                    requirements.push((Some("context"), Capability::EvaluateForHovers))
+                }
+                Some(EvalContext::Clipboard) => {
+                    requirements.push((Some("context"), Capability::ClipboardContext))
+                }
+                _ => {}
+            }
+            None
+        }
+        RequestCommand::SetExpression(arguments) => {
+            if arguments.format.is_some() {
+                requirements.push((Some("format"), Capability::ValueFormattingOptions));
+            }
+            Some(Capability::SetExpression)
+        }
+        RequestCommand::StepInTargets(_) => Some(Capability::StepInTargetsRequest),
+        RequestCommand::Completions(_) => Some(Capability::CompletionsRequest),
+        RequestCommand::ExceptionInfo(_) => Some(Capability::ExceptionInfoRequest),
+        RequestCommand::ReadMemory(_) => Some(Capability::ReadMemoryRequest),
+        RequestCommand::WriteMemory(_) => Some(Capability::WriteMemoryRequest),
+        RequestCommand::Disassemble(_) => Some(Capability::DisassembleRequest),
+        RequestCommand::RunInTerminal(arguments) => {
+            if arguments.args_can_be_interpreted_by_shell == Some(true) {
+                requirements.push((
+                    Some("argsCanBeInterpretedByShell"),
+                    Capability::ArgsCanBeInterpretedByShell,
+                ));
+            }
+            Some(Capability::RunInTerminalRequest)
+        }
+        RequestCommand::StartDebugging(_) => Some(Capability::StartDebuggingRequest),
+        RequestCommand::Initialize(_)
+        | RequestCommand::Launch(_)
+        | RequestCommand::Attach(_)
+        | RequestCommand::Pause(_)
+        | RequestCommand::Scopes(_)
+        | RequestCommand::Source(_)
+        | RequestCommand::Threads
+        | RequestCommand::Locations(_)
+        | RequestCommand::Unknown { .. } => None,
+    };
+    if let Some(capability) = whole {
+        requirements.insert(0, (None, capability));
+    }
+    requirements
+}
+
+/**
+ * The capabilities of both sides of a session: the client announces its own
+ * in the 'initialize' request and the adapter answers with its capabilities.
+ */
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Negotiated {
+    pub client: InitializeRequestArguments,
+    pub adapter: Capabilities,
+}
+
+impl Negotiated {
+    pub fn new(client: InitializeRequestArguments, adapter: Capabilities) -> Self {
+        Negotiated { client, adapter }
+    }
+
+    /**
+     * Returns true if the side the capability belongs to announced it. Flags
+     * that were not sent count as false.
+     */
+    pub fn supports(&self, capability: Capability) -> bool {
+        let adapter = &self.adapter;
+        let client = &self.client;
+        let flag = match capability {
+            Capability::ConfigurationDoneRequest => adapter.supports_configuration_done_request,
+            Capability::FunctionBreakpoints => adapter.supports_function_breakpoints,
+            Capability::ConditionalBreakpoints => adapter.supports_conditional_breakpoints,
+            Capability::HitConditionalBreakpoints => adapter.supports_hit_conditional_breakpoints,
+            Capability::EvaluateForHovers => adapter.supports_evaluate_for_hovers,
+            Capability::StepBack => adapter.supports_step_back,
+            Capability::SetVariable => adapter.supports_set_variable,
+            Capability::RestartFrame => adapter.supports_restart_frame,
+            Capability::GotoTargetsRequest => adapter.supports_goto_targets_request,
+            Capability::StepInTargetsRequest => adapter.supports_step_in_targets_request,
+            Capability::CompletionsRequest => adapter.supports_completions_request,
+            Capability::ModulesRequest => adapter.supports_modules_request,
+            Capability::RestartRequest => adapter.supports_restart_request,
+            Capability::ExceptionOptions => adapter.supports_exception_options,
+            Capability::ValueFormattingOptions => adapter.supports_value_formatting_options,
+            Capability::ExceptionInfoRequest => adapter.supports_exception_info_request,
+            Capability::TerminateDebuggee => adapter.support_terminate_debuggee,
+            Capability::SuspendDebuggee => adapter.support_suspend_debuggee,
+            Capability::DelayedStackTraceLoading => adapter.supports_delayed_stack_trace_loading,
+            Capability::LoadedSourcesRequest => adapter.supports_loaded_sources_request,
+            Capability::LogPoints => adapter.supports_log_points,
+            Capability::TerminateThreadsRequest => adapter.supports_terminate_threads_request,
+            Capability::SetExpression => adapter.supports_set_expression,
+            Capability::TerminateRequest => adapter.supports_terminate_request,
+            Capability::DataBreakpoints => adapter.supports_data_breakpoints,
+            Capability::ReadMemoryRequest => adapter.supports_read_memory_request,
+            Capability::WriteMemoryRequest => adapter.supports_write_memory_request,
+            Capability::DisassembleRequest => adapter.supports_disassemble_request,
+            Capability::CancelRequest => adapter.supports_cancel_request,
+            Capability::BreakpointLocationsRequest => adapter.supports_breakpoint_locations_request,
+            Capability::ClipboardContext => adapter.supports_clipboard_context,
+            Capability::SteppingGranularity => adapter.supports_stepping_granularity,
+            Capability::InstructionBreakpoints => adapter.supports_instruction_breakpoints,
+            Capability::ExceptionFilterOptions => adapter.supports_exception_filter_options,
+            Capability::SingleThreadExecutionRequests => {
+                adapter.supports_single_thread_execution_requests
+            }
+            Capability::DataBreakpointBytes => adapter.supports_data_breakpoint_bytes,
+            Capability::AnsiStyling => adapter
+                .supports_ansi_styling
+                .and(client.supports_ansi_styling),
+            Capability::VariableType => client.supports_variable_type,
+            Capability::VariablePaging => client.supports_variable_paging,
+            Capability::RunInTerminalRequest => client.supports_run_in_terminal_request,
+            Capability::MemoryReferences => client.supports_memory_references,
+            Capability::ProgressReporting => client.supports_progress_reporting,
+            Capability::InvalidatedEvent => client.supports_invalidated_event,
+            Capability::MemoryEvent => client.supports_memory_event,
+            Capability::ArgsCanBeInterpretedByShell => {
+                client.supports_args_can_be_interpreted_by_shell
+            }
+            Capability::StartDebuggingRequest => client.supports_start_debugging_request,
+        };
+        flag.unwrap_or(false)
+    }
+
+    /**
+     * Checks that the receiver of a request announced the capabilities it and
+     * its arguments need. This covers reverse requests, which need the
+     * capabilities of the client.
+     */
+    pub fn check_request(&self, command: &RequestCommand) -> Result<(), Unsupported> {
+        for (argument, capability) in request_requirements(command) {
+            if !self.supports(capability) {
+                return Err(Unsupported {
+                    message: command.command().to_string(),
+                    argument,
+                    capability,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /**
+     * Checks that the client announced support for an event.
+     */
+    pub fn check_event(&self, event: &EventBody) -> Result<(), Unsupported> {
+        let capability = match event {
+            EventBody::ProgressStart(_)
+            | EventBody::ProgressUpdate(_)
+            | EventBody::ProgressEnd(_) => Capability::ProgressReporting,
+            EventBody::Invalidated(_) => Capability::InvalidatedEvent,
+            EventBody::Memory(_) => Capability::MemoryEvent,
+            _ => return Ok(()),
+        };
+        if self.supports(capability) {
+            Ok(())
+        } else {
+            Err(Unsupported {
+                message: event.event().to_string(),
+                argument: None,
+                capability,
+            })
+        }
+    }
+}
@@ -0,0 +1,487 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::adapter::RequestError;
+use crate::capabilities::Unsupported;
+use crate::codec::CodecError;
+pub use crate::connection::PendingResponse;
+use crate::connection::{lock, Incoming, MessageSender, Received};
+use crate::events::EventBody;
+use crate::requests::{Request, RequestCommand};
+use crate::responses::{ErrorResponse, Response, ResponseBody, ResponseMessage};
+use crate::session::{Phase, Session, Violation};
+use crate::{MessageKind, ProtocolMessage};
+
+#[derive(Debug)]
+pub enum ClientError {
+    /**
+     * Sending the request failed.
+     */
+    Codec(CodecError),
+
+    /**
+     * The adapter answered with 'success' set to false.
+     */
+    Failed {
+        message: Option<ResponseMessage>,
+        response: Box<ErrorResponse>,
+    },
+
+    /**
+     * The body of the response does not belong to the request, or could not be
+     * parsed.
+     */
+    UnexpectedBody(Box<ResponseBody>),
+
+    /**
+     * The request is not allowed in the current phase of the session, so it
+     * was not sent.
+     */
+    OutOfOrder(Violation),
+
+    /**
+     * The request or one of its arguments needs a capability the adapter did
+     * not announce, so it was not sent.
+     */
+    Unsupported(Unsupported),
+
+    /**
+     * No response arrived within the timeout.
+     */
+    Timeout,
+
+    /**
+     * The adapter closed the connection before responding.
+     */
+    Disconnected,
+}
+
+impl ClientError {
+    /**
+     * Returns true if the adapter reports the request as cancelled by a
+     * 'cancel' request.
+     */
+    pub fn is_cancelled(&self) -> bool {
+        matches!(
+            self,
+            ClientError::Failed {
+                message: Some(ResponseMessage::Cancelled),
+                ..
+            }
+        )
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Codec(err) => err.fmt(f),
+            ClientError::Failed { message, response } => {
+                write!(f, "'{}' request failed", response.command)?;
+                match (message, &response.error) {
+                    (_, Some(error)) => write!(f, ": {}", error.format),
+                    (Some(ResponseMessage::Error(message)), None) => write!(f, ": {}", message),
+                    (Some(ResponseMessage::Cancelled), None) => f.write_str(": cancelled"),
+                    (Some(ResponseMessage::NotStopped), None) => f.write_str(": not stopped"),
+                    (None, None) => Ok(()),
+                }
+            }
+            ClientError::UnexpectedBody(body) => {
+                write!(f, "unexpected response body for '{}'", body.command())
+            }
+            ClientError::OutOfOrder(violation) => violation.fmt(f),
+            ClientError::Unsupported(unsupported) => unsupported.fmt(f),
+            ClientError::Timeout => f.write_str("timed out waiting for a response"),
+            ClientError::Disconnected => f.write_str("the adapter disconnected"),
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Codec(err) => Some(err),
+            ClientError::OutOfOrder(violation) => Some(violation),
+            ClientError::Unsupported(unsupported) => Some(unsupported),
+            _ => None,
+        }
+    }
+}
+
+impl From<CodecError> for ClientError {
+    fn from(err: CodecError) -> Self {
+        ClientError::Codec(err)
+    }
+}
+
+type EventHandler = Box<dyn FnMut(EventBody) + Send>;
+type ReverseRequestHandler =
+    Box<dyn FnMut(RequestCommand) -> Result<ResponseBody, RequestError> + Send>;
+
+/**
+ * Configures a [`DebugClient`] before connecting it to an adapter.
+ */
+#[derive(Default)]
+pub struct DebugClientBuilder {
+    timeout: Option<Duration>,
+    on_event: Option<EventHandler>,
+    on_reverse_request: Option<ReverseRequestHandler>,
+}
+
+impl fmt::Debug for DebugClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebugClientBuilder")
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DebugClientBuilder {
+    pub fn new() -> Self {
+        DebugClientBuilder::default()
+    }
+
+    /**
+     * Sets how long [`DebugClient::request`] waits for a response. By default
+     * it waits forever.
+     */
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /**
+     * Calls `on_event` for each event instead of queueing it for
+     * [`DebugClient::events`]. It runs on the thread reading messages, so a
+     * slow callback delays responses.
+     */
+    pub fn with_event_handler(mut self, on_event: impl FnMut(EventBody) + Send + 'static) -> Self {
+        self.on_event = Some(Box::new(on_event));
+        self
+    }
+
+    /**
+     * Answers reverse requests, such as 'runInTerminal', with `on_request`.
+     * Without a handler they fail as unsupported. It runs on its own thread,
+     * one request at a time, so it may wait for the response to a request of
+     * its own.
+     */
+    pub fn with_reverse_request_handler(
+        mut self,
+        on_request: impl FnMut(RequestCommand) -> Result<ResponseBody, RequestError> + Send + 'static,
+    ) -> Self {
+        self.on_reverse_request = Some(Box::new(on_request));
+        self
+    }
+
+    /**
+     * Spawns an adapter and talks to it over its stdin and stdout.
+     */
+    pub fn spawn(self, command: &mut Command) -> io::Result<DebugClient> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+        let mut client = self.build(BufReader::new(stdout), stdin);
+        client.child = Some(child);
+        Ok(client)
+    }
+
+    /**
+     * Connects to an adapter listening on a TCP socket.
+     */
+    pub fn connect(self, addr: impl ToSocketAddrs) -> io::Result<DebugClient> {
+        let stream = TcpStream::connect(addr)?;
+        let reader = BufReader::new(stream.try_clone()?);
+        let mut client = self.build(reader, stream.try_clone()?);
+        client.stream = Some(stream);
+        Ok(client)
+    }
+
+    /**
+     * Talks to an adapter over an existing transport.
+     */
+    pub fn build<R, W>(self, reader: R, writer: W) -> DebugClient
+    where
+        R: BufRead + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let sender = MessageSender::new(Box::new(writer));
+        let (event_sender, events) = mpsc::channel();
+        let mut on_event = self.on_event.unwrap_or_else(|| {
+            Box::new(move |event| {
+                // Nobody may be listening, which is fine.
+                let _ = event_sender.send(event);
+            })
+        });
+        let on_reverse_request = self.on_reverse_request;
+        let router = sender.clone();
+        let (reverse_sender, reverse_requests) = mpsc::channel();
+        thread::spawn(move || {
+            answer_reverse_requests(on_reverse_request, reverse_requests, router)
+        });
+        let router = sender.clone();
+        let session = Arc::new(Mutex::new(Session::new()));
+        let observer = session.clone();
+        thread::spawn(move || {
+            for received in Incoming::new(reader) {
+                match received {
+                    Ok(Received::Message(ProtocolMessage {
+                        message: MessageKind::Response(response),
+                        ..
+                    }))
+                    | Ok(Received::InvalidResponse(response)) => {
+                        lock(&observer).response(&response);
+                        router.receive_response(response);
+                    }
+                    Ok(Received::Message(ProtocolMessage {
+                        message: MessageKind::Event(event),
+                        ..
+                    })) => {
+                        if let Some(body) = event.body {
+                            // Out of order events are still passed on, the
+                            // adapter knows best what happened to the debuggee.
+                            let _ = lock(&observer).event(&body);
+                            on_event(body);
+                        }
+                    }
+                    Ok(Received::Message(ProtocolMessage {
+                        seq,
+                        message: MessageKind::Request(command),
+                    })) => {
+                        if reverse_sender.send(Ok((seq, command))).is_err() {
+                            break;
+                        }
+                    }
+                    Ok(Received::InvalidRequest(response)) => {
+                        // Never write on the reader thread, the adapter may be
+                        // blocked writing to us.
+                        if reverse_sender.send(Err(response)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+            router.close();
+        });
+        DebugClient {
+            sender,
+            session,
+            events,
+            timeout: self.timeout,
+            child: None,
+            stream: None,
+        }
+    }
+}
+
+/**
+ * Answers the reverse requests from the adapter until the connection closes.
+ * Each request comes with its sequence number, or as the error response to send
+ * back if it is not valid.
+ */
+fn answer_reverse_requests(
+    mut on_request: Option<ReverseRequestHandler>,
+    requests: Receiver<Result<(i64, RequestCommand), Response>>,
+    router: MessageSender,
+) {
+    for request in requests {
+        let (seq, command) = match request {
+            Ok(request) => request,
+            Err(response) => {
+                if router.send(MessageKind::Response(response)).is_err() {
+                    break;
+                }
+                continue;
+            }
+        };
+        let name = command.command().to_string();
+        let result = match on_request.as_mut() {
+            Some(on_request) => on_request(command),
+            None => Err(RequestError::unsupported(&name)),
+        };
+        let response = match result {
+            Ok(body) => Response::success(seq, body),
+            Err(err) => err.into_response(seq, name),
+        };
+        if router.send(MessageKind::Response(response)).is_err() {
+            break;
+        }
+    }
+}
+
+/**
+ * A client of a debug adapter. It sends typed requests, matches the responses
+ * to them and collects the events the adapter sends.
+ *
+ * Messages are read on a separate thread, so requests may be sent from several
+ * threads at once.
+ *
+ * Requests that are out of order, such as 'stackTrace' before 'launch', fail
+ * with [`ClientError::OutOfOrder`] without being sent, see [`Session`]. So do
+ * requests that need a capability the adapter did not announce, with
+ * [`ClientError::Unsupported`].
+ */
+#[derive(Debug)]
+pub struct DebugClient {
+    sender: MessageSender,
+    session: Arc<Mutex<Session>>,
+    events: Receiver<EventBody>,
+    timeout: Option<Duration>,
+    child: Option<Child>,
+    /**
+     * The socket, if the client connected over TCP. Dropping the writer does not
+     * close it, as the reader holds a clone.
+     */
+    stream: Option<TcpStream>,
+}
+
+impl DebugClient {
+    pub fn builder() -> DebugClientBuilder {
+        DebugClientBuilder::new()
+    }
+
+    /**
+     * Spawns an adapter with the default configuration, see
+     * [`DebugClientBuilder::spawn`].
+     */
+    pub fn spawn(command: &mut Command) -> io::Result<DebugClient> {
+        DebugClientBuilder::new().spawn(command)
+    }
+
+    /**
+     * Connects to an adapter with the default configuration, see
+     * [`DebugClientBuilder::connect`].
+     */
+    pub fn connect(addr: impl ToSocketAddrs) -> io::Result<DebugClient> {
+        DebugClientBuilder::new().connect(addr)
+    }
+
+    /**
+     * Talks to an adapter over an existing transport with the default
+     * configuration.
+     */
+    pub fn new<R, W>(reader: R, writer: W) -> DebugClient
+    where
+        R: BufRead + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        DebugClientBuilder::new().build(reader, writer)
+    }
+
+    /**
+     * The adapter process, if the client spawned it.
+     */
+    pub fn child(&mut self) -> Option<&mut Child> {
+        self.child.as_mut()
+    }
+
+    /**
+     * The stage the session is in.
+     */
+    pub fn phase(&self) -> Phase {
+        lock(&self.session).phase()
+    }
+
+    /**
+     * A snapshot of the session, including the capabilities the adapter
+     * returned from 'initialize'.
+     */
+    pub fn session(&self) -> Session {
+        lock(&self.session).clone()
+    }
+
+    /**
+     * The events sent by the adapter, in order. Nothing is queued if the client
+     * was built with an event handler.
+     */
+    pub fn events(&self) -> &Receiver<EventBody> {
+        &self.events
+    }
+
+    /**
+     * Sends a request and waits for the body of its response, up to the
+     * configured timeout.
+     */
+    pub fn request<R: Request>(&self, arguments: R) -> Result<R::Response, ClientError> {
+        self.request_with_timeout(arguments, self.timeout)
+    }
+
+    /**
+     * Sends a request and waits for the body of its response, up to `timeout`
+     * if it is set.
+     */
+    pub fn request_with_timeout<R: Request>(
+        &self,
+        arguments: R,
+        timeout: Option<Duration>,
+    ) -> Result<R::Response, ClientError> {
+        let pending = self.send_request(arguments)?;
+        let response = match timeout {
+            Some(timeout) => match pending.wait_timeout(timeout) {
+                Ok(response) => response,
+                Err(RecvTimeoutError::Timeout) => {
+                    self.sender.forget(pending.seq());
+                    return Err(ClientError::Timeout);
+                }
+                Err(RecvTimeoutError::Disconnected) => return Err(ClientError::Disconnected),
+            },
+            None => pending.wait().ok_or(ClientError::Disconnected)?,
+        };
+        match response.result {
+            Ok(body) => R::from_response_body(body).map_err(ClientError::UnexpectedBody),
+            Err(error) => Err(ClientError::Failed {
+                message: response.message,
+                response: Box::new(error),
+            }),
+        }
+    }
+
+    /**
+     * Sends a request without waiting for its response.
+     */
+    pub fn send_request<R: Request>(&self, arguments: R) -> Result<PendingResponse, ClientError> {
+        // Only hold the session while checking the request, not while writing
+        // it, as the reader thread needs the session to make progress.
+        self.sender.send_request_if(arguments.into(), |command| {
+            let mut session = lock(&self.session);
+            session
+                .check_request(command)
+                .map_err(ClientError::OutOfOrder)?;
+            if let Some(negotiated) = session.negotiated() {
+                negotiated
+                    .check_request(command)
+                    .map_err(ClientError::Unsupported)?;
+            }
+            session.request(command).map_err(ClientError::OutOfOrder)
+        })
+    }
+}
+
+impl Drop for DebugClient {
+    fn drop(&mut self) {
+        // The reader thread holds on to the sender, so close the stream
+        // explicitly to let the adapter know the client is gone.
+        self.sender.shutdown();
+        if let Some(stream) = &self.stream {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+        if let Some(child) = self.child.as_mut() {
+            // The adapter should have exited after 'disconnect'; make sure.
+            if let Ok(None) = child.try_wait() {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+        }
+    }
+}
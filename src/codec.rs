@@ -0,0 +1,414 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Read, Write};
+
+use crate::ProtocolMessage;
+
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
+/**
+ * The default upper bound on the size of a single message body, in bytes.
+ */
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+
+/**
+ * The upper bound on the length of a single header line, in bytes.
+ */
+pub const MAX_HEADER_LINE_LENGTH: usize = 1024;
+
+#[derive(Debug)]
+pub enum CodecError {
+    /**
+     * The underlying stream failed.
+     */
+    Io(io::Error),
+
+    /**
+     * A header line was not of the form 'name: value', was not valid UTF-8 or
+     * was too long.
+     */
+    InvalidHeader(String),
+
+    /**
+     * The header part of a message ended without a 'Content-Length' header.
+     */
+    MissingContentLength,
+
+    /**
+     * The value of the 'Content-Length' header is not a valid length.
+     */
+    InvalidContentLength(String),
+
+    /**
+     * The message body is larger than the configured maximum.
+     */
+    MessageTooLarge { length: usize, max: usize },
+
+    /**
+     * The message body is not a valid protocol message.
+     */
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::Io(err) => write!(f, "I/O error: {}", err),
+            CodecError::InvalidHeader(line) => write!(f, "invalid header: {:?}", line),
+            CodecError::MissingContentLength => write!(f, "missing Content-Length header"),
+            CodecError::InvalidContentLength(value) => {
+                write!(f, "invalid Content-Length: {:?}", value)
+            }
+            CodecError::MessageTooLarge { length, max } => write!(
+                f,
+                "message of {} bytes exceeds the maximum of {} bytes",
+                length, max
+            ),
+            CodecError::Json(err) => write!(f, "invalid message: {}", err),
+        }
+    }
+}
+
+impl Error for CodecError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CodecError::Io(err) => Some(err),
+            CodecError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CodecError {
+    fn from(err: io::Error) -> Self {
+        CodecError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for CodecError {
+    fn from(err: serde_json::Error) -> Self {
+        CodecError::Json(err)
+    }
+}
+
+/**
+ * Parses a single header line, without its line terminator. Returns the
+ * content length if this is the 'Content-Length' header. Other headers are
+ * ignored.
+ */
+pub(crate) fn parse_header(line: &[u8]) -> Result<Option<usize>, CodecError> {
+    let line = std::str::from_utf8(line)
+        .map_err(|_| CodecError::InvalidHeader(String::from_utf8_lossy(line).into_owned()))?;
+    let (name, value) = match line.find(':') {
+        Some(colon) => (&line[..colon], line[colon + 1..].trim()),
+        None => return Err(CodecError::InvalidHeader(line.to_string())),
+    };
+    if !name.trim().eq_ignore_ascii_case("Content-Length") {
+        return Ok(None);
+    }
+    value
+        .parse()
+        .map(Some)
+        .map_err(|_| CodecError::InvalidContentLength(value.to_string()))
+}
+
+/**
+ * Checks a content length against the maximum message size.
+ */
+pub(crate) fn check_length(length: usize, max: usize) -> Result<usize, CodecError> {
+    if length > max {
+        Err(CodecError::MessageTooLarge { length, max })
+    } else {
+        Ok(length)
+    }
+}
+
+/**
+ * Reads 'Content-Length' framed protocol messages from a stream.
+ */
+#[derive(Debug)]
+pub struct MessageReader<R> {
+    reader: R,
+    max_message_size: usize,
+}
+
+impl<R: BufRead> MessageReader<R> {
+    pub fn new(reader: R) -> Self {
+        MessageReader {
+            reader,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+
+    /**
+     * Sets the largest message body, in bytes, this reader accepts.
+     */
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /**
+     * Reads the next message. Returns `None` if the stream ended cleanly before
+     * the start of a message.
+     */
+    pub fn read_message(&mut self) -> Result<Option<ProtocolMessage>, CodecError> {
+        match self.read_body()? {
+            Some(body) => Ok(Some(serde_json::from_slice(&body)?)),
+            None => Ok(None),
+        }
+    }
+
+    /**
+     * Reads the body of the next message without parsing it.
+     */
+    pub(crate) fn read_body(&mut self) -> Result<Option<Vec<u8>>, CodecError> {
+        let length = match self.read_headers()? {
+            Some(length) => check_length(length, self.max_message_size)?,
+            None => return Ok(None),
+        };
+        let mut body = vec![0; length];
+        self.reader.read_exact(&mut body)?;
+        Ok(Some(body))
+    }
+
+    fn read_headers(&mut self) -> Result<Option<usize>, CodecError> {
+        let mut content_length = None;
+        let mut first = true;
+        loop {
+            let mut line = Vec::new();
+            let read = (&mut self.reader)
+                .take(MAX_HEADER_LINE_LENGTH as u64 + 1)
+                .read_until(b'\n', &mut line)?;
+            if read == 0 {
+                if first {
+                    return Ok(None);
+                }
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+            first = false;
+            if line.last() != Some(&b'\n') {
+                if line.len() > MAX_HEADER_LINE_LENGTH {
+                    line.truncate(MAX_HEADER_LINE_LENGTH);
+                    return Err(CodecError::InvalidHeader(
+                        String::from_utf8_lossy(&line).into_owned(),
+                    ));
+                }
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+            line.pop();
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            if line.is_empty() {
+                return content_length
+                    .map(Some)
+                    .ok_or(CodecError::MissingContentLength);
+            }
+            if let Some(length) = parse_header(&line)? {
+                content_length = Some(length);
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for MessageReader<R> {
+    type Item = Result<ProtocolMessage, CodecError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_message().transpose()
+    }
+}
+
+/**
+ * Writes 'Content-Length' framed protocol messages to a stream.
+ */
+#[derive(Debug)]
+pub struct MessageWriter<W> {
+    writer: W,
+}
+
+impl<W: Write> MessageWriter<W> {
+    pub fn new(writer: W) -> Self {
+        MessageWriter { writer }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /**
+     * Writes a message, including its header, and flushes the stream.
+     */
+    pub fn write_message(&mut self, message: &ProtocolMessage) -> Result<(), CodecError> {
+        let body = serde_json::to_vec(message)?;
+        write!(self.writer, "Content-Length: {}\r\n\r\n", body.len())?;
+        self.writer.write_all(&body)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Read};
+
+    use super::*;
+    use crate::events::{Event, EventBody};
+    use crate::MessageKind;
+
+    const BODY: &str = r#"{"seq":1,"type":"event","event":"initialized"}"#;
+
+    fn frame(headers: &str, body: &str) -> String {
+        format!("{}\r\n{}", headers, body)
+    }
+
+    fn initialized(seq: i64) -> ProtocolMessage {
+        ProtocolMessage {
+            seq,
+            message: MessageKind::Event(Event {
+                body: Some(EventBody::Initialized),
+            }),
+        }
+    }
+
+    fn read_all(input: &str) -> Vec<Result<ProtocolMessage, CodecError>> {
+        MessageReader::new(input.as_bytes()).collect()
+    }
+
+    /**
+     * Returns one byte per read.
+     */
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((&byte, rest)), Some(first)) => {
+                    *first = byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn ignores_other_headers() {
+        let input = frame(
+            &format!(
+                "Content-Type: application/vscode-jsonrpc; charset=utf-8\r\nContent-Length: {}\r\nX-Other:\r\n",
+                BODY.len()
+            ),
+            BODY,
+        );
+        let messages = read_all(&input);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].as_ref().unwrap(), &initialized(1));
+    }
+
+    #[test]
+    fn content_length_is_case_insensitive() {
+        let input = frame(&format!("content-LENGTH:{}\n", BODY.len()), BODY);
+        assert_eq!(read_all(&input)[0].as_ref().unwrap(), &initialized(1));
+    }
+
+    #[test]
+    fn rejects_missing_and_invalid_lengths() {
+        let input = frame("Content-Type: text\r\n", BODY);
+        let mut reader = MessageReader::new(input.as_bytes());
+        assert!(matches!(
+            reader.read_message(),
+            Err(CodecError::MissingContentLength)
+        ));
+        for length in ["", "-1", "ten", "1.5"] {
+            let input = frame(&format!("Content-Length: {}\r\n", length), BODY);
+            match MessageReader::new(input.as_bytes()).read_message() {
+                Err(CodecError::InvalidContentLength(value)) => assert_eq!(value, length),
+                other => panic!("{:?}: {:?}", length, other),
+            }
+        }
+        let input = frame("Content-Length\r\n", BODY);
+        let mut reader = MessageReader::new(input.as_bytes());
+        assert!(matches!(
+            reader.read_message(),
+            Err(CodecError::InvalidHeader(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_messages_above_the_maximum() {
+        let input = frame(&format!("Content-Length: {}\r\n", BODY.len()), BODY);
+        let mut reader = MessageReader::new(input.as_bytes()).with_max_message_size(10);
+        assert!(matches!(
+            reader.read_message(),
+            Err(CodecError::MessageTooLarge { max: 10, .. })
+        ));
+    }
+
+    #[test]
+    fn reads_frames_split_across_reads() {
+        let mut writer = MessageWriter::new(Vec::new());
+        for seq in 1..=3 {
+            writer.write_message(&initialized(seq)).unwrap();
+        }
+        let input = writer.into_inner();
+        let reader = BufReader::with_capacity(1, Trickle(&input));
+        let messages: Vec<ProtocolMessage> = MessageReader::new(reader)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(messages, [initialized(1), initialized(2), initialized(3)]);
+    }
+
+    #[test]
+    fn truncated_messages_are_unexpected_eof() {
+        let headers = format!("Content-Length: {}\r\n", BODY.len());
+        for input in [
+            frame(&headers, &BODY[..10]),
+            headers.clone(),
+            headers.trim_end().to_string(),
+        ] {
+            match MessageReader::new(input.as_bytes()).read_message() {
+                Err(CodecError::Io(err)) => assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof),
+                other => panic!("{:?}: {:?}", input, other),
+            }
+        }
+    }
+
+    #[test]
+    fn stops_cleanly_between_frames() {
+        assert!(read_all("").is_empty());
+        let input = frame(&format!("Content-Length: {}\r\n", BODY.len()), BODY);
+        let mut reader = MessageReader::new(input.as_bytes());
+        assert_eq!(reader.read_message().unwrap(), Some(initialized(1)));
+        assert!(reader.read_message().unwrap().is_none());
+        assert!(reader.read_message().unwrap().is_none());
+    }
+
+    #[test]
+    fn invalid_bodies_leave_the_stream_at_the_next_frame() {
+        let headers = |body: &str| format!("Content-Length: {}\r\n", body.len());
+        let input = frame(&headers("{}"), "{}") + &frame(&headers(BODY), BODY);
+        let mut reader = MessageReader::new(input.as_bytes());
+        assert!(matches!(reader.read_message(), Err(CodecError::Json(_))));
+        assert_eq!(reader.read_message().unwrap(), Some(initialized(1)));
+    }
+}
@@ -0,0 +1,132 @@
+use bytes::{BufMut, BytesMut};
+use tokio::io::{AsyncRead, AsyncWrite, ReadHalf, WriteHalf};
+use tokio_util::codec::{Decoder, Encoder, FramedRead, FramedWrite};
+
+use super::{
+    check_length, parse_header, CodecError, DEFAULT_MAX_MESSAGE_SIZE, MAX_HEADER_LINE_LENGTH,
+};
+use crate::ProtocolMessage;
+
+/**
+ * A stream of protocol messages read from an `AsyncRead`.
+ */
+pub type MessageStream<R> = FramedRead<R, MessageCodec>;
+
+/**
+ * A sink of protocol messages written to an `AsyncWrite`.
+ */
+pub type MessageSink<W> = FramedWrite<W, MessageCodec>;
+
+/**
+ * Decodes and encodes 'Content-Length' framed protocol messages.
+ */
+#[derive(Debug, Clone)]
+pub struct MessageCodec {
+    max_message_size: usize,
+    content_length: Option<usize>,
+    body_length: Option<usize>,
+}
+
+impl MessageCodec {
+    pub fn new() -> Self {
+        MessageCodec {
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            content_length: None,
+            body_length: None,
+        }
+    }
+
+    /**
+     * Sets the largest message body, in bytes, this codec accepts.
+     */
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+}
+
+impl Default for MessageCodec {
+    fn default() -> Self {
+        MessageCodec::new()
+    }
+}
+
+impl Decoder for MessageCodec {
+    type Item = ProtocolMessage;
+    type Error = CodecError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<ProtocolMessage>, CodecError> {
+        loop {
+            if let Some(length) = self.body_length {
+                if src.len() < length {
+                    return Ok(None);
+                }
+                let body = src.split_to(length);
+                self.body_length = None;
+                return Ok(Some(serde_json::from_slice(&body)?));
+            }
+
+            let newline = match src.iter().position(|b| *b == b'\n') {
+                Some(newline) if newline <= MAX_HEADER_LINE_LENGTH => newline,
+                None if src.len() <= MAX_HEADER_LINE_LENGTH => return Ok(None),
+                _ => {
+                    let line = &src[..MAX_HEADER_LINE_LENGTH];
+                    return Err(CodecError::InvalidHeader(
+                        String::from_utf8_lossy(line).into_owned(),
+                    ));
+                }
+            };
+            let line = src.split_to(newline + 1);
+            let line = line[..newline]
+                .strip_suffix(b"\r")
+                .unwrap_or(&line[..newline]);
+            if line.is_empty() {
+                let length = self
+                    .content_length
+                    .take()
+                    .ok_or(CodecError::MissingContentLength)?;
+                let length = check_length(length, self.max_message_size)?;
+                src.reserve(length.saturating_sub(src.len()));
+                self.body_length = Some(length);
+            } else if let Some(length) = parse_header(line)? {
+                self.content_length = Some(length);
+            }
+        }
+    }
+}
+
+impl Encoder<ProtocolMessage> for MessageCodec {
+    type Error = CodecError;
+
+    fn encode(&mut self, message: ProtocolMessage, dst: &mut BytesMut) -> Result<(), CodecError> {
+        Encoder::<&ProtocolMessage>::encode(self, &message, dst)
+    }
+}
+
+impl Encoder<&ProtocolMessage> for MessageCodec {
+    type Error = CodecError;
+
+    fn encode(&mut self, message: &ProtocolMessage, dst: &mut BytesMut) -> Result<(), CodecError> {
+        let body = serde_json::to_vec(message)?;
+        let header = format!("Content-Length: {}\r\n\r\n", body.len());
+        dst.reserve(header.len() + body.len());
+        dst.put_slice(header.as_bytes());
+        dst.put_slice(&body);
+        Ok(())
+    }
+}
+
+/**
+ * Splits a bidirectional stream, such as a socket, into independent halves for
+ * reading and writing protocol messages.
+ */
+pub fn split<T>(io: T) -> (MessageStream<ReadHalf<T>>, MessageSink<WriteHalf<T>>)
+where
+    T: AsyncRead + AsyncWrite,
+{
+    let (reader, writer) = tokio::io::split(io);
+    (
+        FramedRead::new(reader, MessageCodec::new()),
+        FramedWrite::new(writer, MessageCodec::new()),
+    )
+}
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::codec::{CodecError, MessageReader, MessageWriter};
+use crate::requests::RequestCommand;
+use crate::responses::{ErrorResponse, Response, ResponseMessage};
+use crate::{MessageKind, ProtocolMessage};
+
+fn closed() -> CodecError {
+    io::Error::new(io::ErrorKind::NotConnected, "the connection is closed").into()
+}
+
+type Writer = MessageWriter<Box<dyn Write + Send>>;
+
+type PendingRequests = HashMap<i64, Sender<Response>>;
+
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/**
+ * The sending half of a connection, shared by the adapter and the client. It
+ * allocates sequence numbers and keeps track of the requests waiting for a
+ * response.
+ */
+#[derive(Clone)]
+pub(crate) struct MessageSender {
+    /**
+     * The writer, or `None` once this side closed the stream.
+     */
+    writer: Arc<Mutex<Option<Writer>>>,
+    seq: Arc<AtomicI64>,
+    /**
+     * The requests waiting for a response, or `None` once the other side
+     * closed the stream.
+     */
+    pending: Arc<Mutex<Option<PendingRequests>>>,
+}
+
+impl fmt::Debug for MessageSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageSender")
+            .field("seq", &self.seq)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MessageSender {
+    pub(crate) fn new(writer: Box<dyn Write + Send>) -> Self {
+        MessageSender {
+            writer: Arc::new(Mutex::new(Some(MessageWriter::new(writer)))),
+            seq: Arc::new(AtomicI64::new(1)),
+            pending: Arc::new(Mutex::new(Some(HashMap::new()))),
+        }
+    }
+
+    /**
+     * Sends a message, allocating its sequence number under the same lock as
+     * the write, so that sequence numbers are increasing on the wire.
+     */
+    pub(crate) fn send(&self, message: MessageKind) -> Result<(), CodecError> {
+        let mut writer = lock(&self.writer);
+        let writer = writer.as_mut().ok_or_else(closed)?;
+        let seq = self.seq.fetch_add(1, Ordering::SeqCst);
+        writer.write_message(&ProtocolMessage { seq, message })
+    }
+
+    /**
+     * Sends a request and registers it to receive its response.
+     */
+    pub(crate) fn send_request(
+        &self,
+        command: RequestCommand,
+    ) -> Result<PendingResponse, CodecError> {
+        self.send_request_if(command, |_| Ok(()))
+    }
+
+    /**
+     * Sends a request if `check` accepts it. The check runs under the writer
+     * lock, so requests are checked in the order they are sent.
+     */
+    pub(crate) fn send_request_if<E: From<CodecError>>(
+        &self,
+        command: RequestCommand,
+        check: impl FnOnce(&RequestCommand) -> Result<(), E>,
+    ) -> Result<PendingResponse, E> {
+        let (sender, receiver) = mpsc::channel();
+        let mut writer = lock(&self.writer);
+        let writer = writer.as_mut().ok_or_else(closed)?;
+        check(&command)?;
+        let seq = self.seq.fetch_add(1, Ordering::SeqCst);
+        // Register before writing, as the response may arrive right away.
+        if let Some(pending) = lock(&self.pending).as_mut() {
+            pending.insert(seq, sender);
+        }
+        let message = ProtocolMessage {
+            seq,
+            message: MessageKind::Request(command),
+        };
+        if let Err(err) = writer.write_message(&message) {
+            self.forget(seq);
+            return Err(err.into());
+        }
+        Ok(PendingResponse { seq, receiver })
+    }
+
+    /**
+     * Stops waiting for the response to a request.
+     */
+    pub(crate) fn forget(&self, seq: i64) {
+        if let Some(pending) = lock(&self.pending).as_mut() {
+            pending.remove(&seq);
+        }
+    }
+
+    /**
+     * Hands a response to the request waiting for it. Responses to unknown
+     * requests are dropped.
+     */
+    pub(crate) fn receive_response(&self, response: Response) {
+        let sender = lock(&self.pending)
+            .as_mut()
+            .and_then(|pending| pending.remove(&response.request_seq));
+        if let Some(sender) = sender {
+            // The request may have been given up on, which is fine.
+            let _ = sender.send(response);
+        }
+    }
+
+    /**
+     * Marks the connection as closed by the other side, waking up the requests
+     * still waiting for a response.
+     */
+    pub(crate) fn close(&self) {
+        lock(&self.pending).take();
+    }
+
+    /**
+     * Closes the stream from this side by dropping the writer. Sending fails
+     * from then on.
+     */
+    pub(crate) fn shutdown(&self) {
+        lock(&self.writer).take();
+    }
+}
+
+/**
+ * The response to a request that has not arrived yet.
+ */
+#[derive(Debug)]
+pub struct PendingResponse {
+    seq: i64,
+    receiver: Receiver<Response>,
+}
+
+impl PendingResponse {
+    /**
+     * The sequence number of the request.
+     */
+    pub fn seq(&self) -> i64 {
+        self.seq
+    }
+
+    /**
+     * Blocks until the response arrives. Returns `None` if the other side
+     * disconnected first.
+     */
+    pub fn wait(self) -> Option<Response> {
+        self.receiver.recv().ok()
+    }
+
+    pub(crate) fn wait_timeout(&self, timeout: Duration) -> Result<Response, RecvTimeoutError> {
+        self.receiver.recv_timeout(timeout)
+    }
+}
+
+/**
+ * A message received from the other side.
+ */
+#[derive(Debug)]
+pub(crate) enum Received {
+    Message(ProtocolMessage),
+
+    /**
+     * The error response to a request that is not valid, such as a known
+     * command with invalid arguments. It is to be sent back.
+     */
+    InvalidRequest(Response),
+
+    /**
+     * An error response in place of a response that is not valid. It is to be
+     * handed to the request waiting for it.
+     */
+    InvalidResponse(Response),
+}
+
+/**
+ * The part of a request or response needed to fail it, when the message as a
+ * whole does not parse.
+ */
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase", tag = "type")]
+enum Envelope {
+    Request { seq: i64, command: String },
+    Response { request_seq: i64, command: String },
+}
+
+/**
+ * Reads the messages from the other side. One message that is not valid does
+ * not end the connection: an invalid request or response turns into an error
+ * response, and other invalid messages are skipped. The stream ends on an I/O
+ * error or on invalid framing, after which the position of the next message is
+ * unknown.
+ */
+#[derive(Debug)]
+pub(crate) struct Incoming<R> {
+    reader: MessageReader<R>,
+}
+
+impl<R: BufRead> Incoming<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Incoming {
+            reader: MessageReader::new(reader),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Incoming<R> {
+    type Item = Result<Received, CodecError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let body = match self.reader.read_body() {
+                Ok(Some(body)) => body,
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            };
+            match serde_json::from_slice(&body) {
+                Ok(message) => return Some(Ok(Received::Message(message))),
+                Err(err) => {
+                    if let Some(received) = invalid(&body, err) {
+                        return Some(Ok(received));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/**
+ * Fails a request or response that does not parse as a whole. Returns `None`
+ * for other messages, as nobody waits for an answer to them.
+ */
+fn invalid(body: &[u8], err: serde_json::Error) -> Option<Received> {
+    let fail = |request_seq, command, reason| {
+        Response::error(request_seq, ErrorResponse::new(command))
+            .message(ResponseMessage::Error(reason))
+    };
+    match serde_json::from_slice(body).ok()? {
+        Envelope::Request { seq, command } => Some(Received::InvalidRequest(fail(
+            seq,
+            command,
+            format!("invalid arguments: {}", err),
+        ))),
+        Envelope::Response {
+            request_seq,
+            command,
+        } => Some(Received::InvalidResponse(fail(
+            request_seq,
+            command,
+            format!("invalid response: {}", err),
+        ))),
+    }
+}
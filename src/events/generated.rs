@@ -0,0 +1,1113 @@
+// The definitions in this module are maintained by `cargo xtask codegen`, which
+// regenerates them from the Debug Adapter Protocol JSON schema. Do not edit
+// them by hand.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::types::{
+    Breakpoint, BreakpointId, Capabilities, FrameId, InvalidatedAreas, MemoryReference, Module,
+    ProcessId, ProgressId, Source, ThreadId, VariablesReference,
+};
+use crate::{parse_content, split_message};
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase", tag = "event", content = "body")]
+pub enum EventBody {
+    Initialized,
+    Stopped(StoppedEvent),
+    Continued(ContinuedEvent),
+    Exited(ExitedEvent),
+    Terminated(TerminatedEvent),
+    Thread(ThreadEvent),
+    Output(OutputEvent),
+    Breakpoint(BreakpointEvent),
+    Module(ModuleEvent),
+    LoadedSource(LoadedSourceEvent),
+    Process(ProcessEvent),
+    Capabilities(CapabilitiesEvent),
+    ProgressStart(ProgressStartEvent),
+    ProgressUpdate(ProgressUpdateEvent),
+    ProgressEnd(ProgressEndEvent),
+    Invalidated(InvalidatedEvent),
+    Memory(MemoryEvent),
+
+    /**
+     * An event not known to this crate. The raw body is preserved as is.
+     */
+    #[serde(untagged)]
+    Unknown {
+        event: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        body: Option<Value>,
+    },
+}
+
+impl<'de> Deserialize<'de> for EventBody {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (event, body) = split_message(deserializer, "event", "body")?;
+        Ok(match event.as_str() {
+            "initialized" => EventBody::Initialized,
+            "stopped" => EventBody::Stopped(parse_content(body)?),
+            "continued" => EventBody::Continued(parse_content(body)?),
+            "exited" => EventBody::Exited(parse_content(body)?),
+            "terminated" => EventBody::Terminated(parse_content(body)?),
+            "thread" => EventBody::Thread(parse_content(body)?),
+            "output" => EventBody::Output(parse_content(body)?),
+            "breakpoint" => EventBody::Breakpoint(parse_content(body)?),
+            "module" => EventBody::Module(parse_content(body)?),
+            "loadedSource" => EventBody::LoadedSource(parse_content(body)?),
+            "process" => EventBody::Process(parse_content(body)?),
+            "capabilities" => EventBody::Capabilities(parse_content(body)?),
+            "progressStart" => EventBody::ProgressStart(parse_content(body)?),
+            "progressUpdate" => EventBody::ProgressUpdate(parse_content(body)?),
+            "progressEnd" => EventBody::ProgressEnd(parse_content(body)?),
+            "invalidated" => EventBody::Invalidated(parse_content(body)?),
+            "memory" => EventBody::Memory(parse_content(body)?),
+            _ => EventBody::Unknown { event, body },
+        })
+    }
+}
+
+impl EventBody {
+    /**
+     * The type of event.
+     */
+    pub fn event(&self) -> &str {
+        match self {
+            EventBody::Initialized => "initialized",
+            EventBody::Stopped(_) => "stopped",
+            EventBody::Continued(_) => "continued",
+            EventBody::Exited(_) => "exited",
+            EventBody::Terminated(_) => "terminated",
+            EventBody::Thread(_) => "thread",
+            EventBody::Output(_) => "output",
+            EventBody::Breakpoint(_) => "breakpoint",
+            EventBody::Module(_) => "module",
+            EventBody::LoadedSource(_) => "loadedSource",
+            EventBody::Process(_) => "process",
+            EventBody::Capabilities(_) => "capabilities",
+            EventBody::ProgressStart(_) => "progressStart",
+            EventBody::ProgressUpdate(_) => "progressUpdate",
+            EventBody::ProgressEnd(_) => "progressEnd",
+            EventBody::Invalidated(_) => "invalidated",
+            EventBody::Memory(_) => "memory",
+            EventBody::Unknown { event, .. } => event,
+        }
+    }
+}
+
+impl From<StoppedEvent> for EventBody {
+    fn from(body: StoppedEvent) -> Self {
+        EventBody::Stopped(body)
+    }
+}
+
+impl From<ContinuedEvent> for EventBody {
+    fn from(body: ContinuedEvent) -> Self {
+        EventBody::Continued(body)
+    }
+}
+
+impl From<ExitedEvent> for EventBody {
+    fn from(body: ExitedEvent) -> Self {
+        EventBody::Exited(body)
+    }
+}
+
+impl From<TerminatedEvent> for EventBody {
+    fn from(body: TerminatedEvent) -> Self {
+        EventBody::Terminated(body)
+    }
+}
+
+impl From<ThreadEvent> for EventBody {
+    fn from(body: ThreadEvent) -> Self {
+        EventBody::Thread(body)
+    }
+}
+
+impl From<OutputEvent> for EventBody {
+    fn from(body: OutputEvent) -> Self {
+        EventBody::Output(body)
+    }
+}
+
+impl From<BreakpointEvent> for EventBody {
+    fn from(body: BreakpointEvent) -> Self {
+        EventBody::Breakpoint(body)
+    }
+}
+
+impl From<ModuleEvent> for EventBody {
+    fn from(body: ModuleEvent) -> Self {
+        EventBody::Module(body)
+    }
+}
+
+impl From<LoadedSourceEvent> for EventBody {
+    fn from(body: LoadedSourceEvent) -> Self {
+        EventBody::LoadedSource(body)
+    }
+}
+
+impl From<ProcessEvent> for EventBody {
+    fn from(body: ProcessEvent) -> Self {
+        EventBody::Process(body)
+    }
+}
+
+impl From<CapabilitiesEvent> for EventBody {
+    fn from(body: CapabilitiesEvent) -> Self {
+        EventBody::Capabilities(body)
+    }
+}
+
+impl From<ProgressStartEvent> for EventBody {
+    fn from(body: ProgressStartEvent) -> Self {
+        EventBody::ProgressStart(body)
+    }
+}
+
+impl From<ProgressUpdateEvent> for EventBody {
+    fn from(body: ProgressUpdateEvent) -> Self {
+        EventBody::ProgressUpdate(body)
+    }
+}
+
+impl From<ProgressEndEvent> for EventBody {
+    fn from(body: ProgressEndEvent) -> Self {
+        EventBody::ProgressEnd(body)
+    }
+}
+
+impl From<InvalidatedEvent> for EventBody {
+    fn from(body: InvalidatedEvent) -> Self {
+        EventBody::Invalidated(body)
+    }
+}
+
+impl From<MemoryEvent> for EventBody {
+    fn from(body: MemoryEvent) -> Self {
+        EventBody::Memory(body)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StoppedReason {
+    Step,
+    Breakpoint,
+    Exception,
+    Pause,
+    Entry,
+    Goto,
+    #[serde(rename = "function breakpoint")]
+    FunctionBreakpoint,
+    #[serde(rename = "data breakpoint")]
+    DataBreakpoint,
+    #[serde(rename = "instruction breakpoint")]
+    InstructionBreakpoint,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * The event indicates that the execution of the debuggee has stopped due to
+ * some condition.
+ * This can be caused by a breakpoint previously set, a stepping request has
+ * completed, by executing a debugger statement etc.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoppedEvent {
+    /**
+     * The reason for the event.
+     * For backward compatibility this string is shown in the UI if the
+     * 'description' attribute is missing (but it must not be translated).
+     * Values: 'step', 'breakpoint', 'exception', 'pause', 'entry', 'goto',
+     * 'function breakpoint', 'data breakpoint', 'instruction breakpoint', etc.
+     */
+    pub reason: StoppedReason,
+
+    /**
+     * The full reason for the event, e.g. 'Paused on exception'. This string is
+     * shown in the UI as is and can be translated.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /**
+     * The thread which was stopped.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<ThreadId>,
+
+    /**
+     * A value of true hints to the client that this event should not change the
+     * focus.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_focus_hint: Option<bool>,
+
+    /**
+     * Additional information. E.g. if reason is 'exception', text contains the
+     * exception name. This string is shown in the UI.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /**
+     * If 'allThreadsStopped' is true, a debug adapter can announce that all
+     * threads have stopped.
+     * - The client should use this information to enable that all threads can
+     *   be expanded to access their stacktraces.
+     * - If the attribute is missing or false, only the thread with the given
+     *   'threadId' can be expanded.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_threads_stopped: Option<bool>,
+
+    /**
+     * Ids of the breakpoints that triggered the event. In most cases there is
+     * only a single breakpoint but here are some examples for multiple
+     * breakpoints:
+     * - Different types of breakpoints map to the same location.
+     * - Multiple source breakpoints get collapsed to the same instruction by
+     *   the compiler/runtime.
+     * - Multiple function breakpoints with different function names map to the
+     *   same location.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hit_breakpoint_ids: Option<Vec<BreakpointId>>,
+}
+
+impl StoppedEvent {
+    pub fn new(reason: impl Into<StoppedReason>) -> Self {
+        StoppedEvent {
+            reason: reason.into(),
+            description: None,
+            thread_id: None,
+            preserve_focus_hint: None,
+            text: None,
+            all_threads_stopped: None,
+            hit_breakpoint_ids: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn thread_id(mut self, thread_id: impl Into<ThreadId>) -> Self {
+        self.thread_id = Some(thread_id.into());
+        self
+    }
+
+    pub fn preserve_focus_hint(mut self, preserve_focus_hint: impl Into<bool>) -> Self {
+        self.preserve_focus_hint = Some(preserve_focus_hint.into());
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn all_threads_stopped(mut self, all_threads_stopped: impl Into<bool>) -> Self {
+        self.all_threads_stopped = Some(all_threads_stopped.into());
+        self
+    }
+
+    pub fn hit_breakpoint_ids(mut self, hit_breakpoint_ids: impl Into<Vec<BreakpointId>>) -> Self {
+        self.hit_breakpoint_ids = Some(hit_breakpoint_ids.into());
+        self
+    }
+}
+
+/**
+ * The event indicates that the execution of the debuggee has continued.
+ * Please note: a debug adapter is not expected to send this event in response
+ * to a request that implies that execution continues, e.g. 'launch' or
+ * 'continue'.
+ * It is only necessary to send a 'continued' event if there was no previous
+ * request that implied this.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinuedEvent {
+    /**
+     * The thread which was continued.
+     */
+    pub thread_id: ThreadId,
+
+    /**
+     * If 'allThreadsContinued' is true, a debug adapter can announce that all
+     * threads have continued.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_threads_continued: Option<bool>,
+}
+
+impl ContinuedEvent {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        ContinuedEvent {
+            thread_id: thread_id.into(),
+            all_threads_continued: None,
+        }
+    }
+
+    pub fn all_threads_continued(mut self, all_threads_continued: impl Into<bool>) -> Self {
+        self.all_threads_continued = Some(all_threads_continued.into());
+        self
+    }
+}
+
+/**
+ * The event indicates that the debuggee has exited and returns its exit code.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExitedEvent {
+    /**
+     * The exit code returned from the debuggee.
+     */
+    pub exit_code: i64,
+}
+
+impl ExitedEvent {
+    pub fn new(exit_code: impl Into<i64>) -> Self {
+        ExitedEvent {
+            exit_code: exit_code.into(),
+        }
+    }
+}
+
+/**
+ * The event indicates that debugging of the debuggee has terminated. This does
+ * **not** mean that the debuggee itself has exited.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TerminatedEvent {
+    /**
+     * A debug adapter may set 'restart' to true (or to an arbitrary object) to
+     * request that the client restarts the session.
+     * The value is not interpreted by the client and passed unmodified as an
+     * attribute '__restart' to the 'launch' and 'attach' requests.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart: Option<Value>,
+}
+
+impl TerminatedEvent {
+    pub fn restart(mut self, restart: impl Into<Value>) -> Self {
+        self.restart = Some(restart.into());
+        self
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Reason {
+    Started,
+    Exited,
+    Changed,
+    New,
+    Removed,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * The event indicates that a thread has started or exited.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadEvent {
+    /**
+     * The reason for the event.
+     * Values: 'started', 'exited', etc.
+     */
+    pub reason: Reason,
+
+    /**
+     * The identifier of the thread.
+     */
+    pub thread_id: ThreadId,
+}
+
+impl ThreadEvent {
+    pub fn new(reason: impl Into<Reason>, thread_id: impl Into<ThreadId>) -> Self {
+        ThreadEvent {
+            reason: reason.into(),
+            thread_id: thread_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Category {
+    Console,
+    Important,
+    Stdout,
+    Stderr,
+    Telemetry,
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Group {
+    Start,
+    StartCollapsed,
+    End,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * The event indicates that the target has produced some output.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputEvent {
+    /**
+     * The output category. If not specified or if the category is not
+     * understood by the client, 'console' is assumed.
+     * Values:
+     * 'console': Show the output in the client's default message UI, e.g. a
+     * 'debug console'. This category should only be used for informational
+     * output from the debugger (as opposed to the debuggee).
+     * 'important': A hint for the client to show the output in the client's UI
+     * for important and highly visible information, e.g. as a popup
+     * notification. This category should only be used for important messages
+     * from the debugger (as opposed to the debuggee). Since this category value
+     * is a hint, clients might ignore the hint and assume the 'console'
+     * category.
+     * 'stdout': Show the output as normal program output from the debuggee.
+     * 'stderr': Show the output as error program output from the debuggee.
+     * 'telemetry': Send the output to telemetry instead of showing it to the
+     * user.
+     * etc.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<Category>,
+
+    /**
+     * The output to report.
+     */
+    pub output: String,
+
+    /**
+     * Support for keeping an output log organized by grouping related messages.
+     * Values:
+     * 'start': Start a new group in expanded mode. Subsequent output events are
+     * members of the group and should be shown indented.
+     * The 'output' attribute becomes the name of the group and is not indented.
+     * 'startCollapsed': Start a new group in collapsed mode. Subsequent output
+     * events are members of the group and should be shown indented (as soon as
+     * the group is expanded).
+     * The 'output' attribute becomes the name of the group and is not indented.
+     * 'end': End the current group and decrease the indentation of subsequent
+     * output events.
+     * A non-empty 'output' attribute is shown as the unindented end of the
+     * group.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<Group>,
+
+    /**
+     * If an attribute 'variablesReference' exists and its value is > 0, the
+     * output contains objects which can be retrieved by passing
+     * 'variablesReference' to the 'variables' request as long as execution
+     * remains suspended. See 'Lifetime of Object References' in the Overview
+     * section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables_reference: Option<VariablesReference>,
+
+    /**
+     * The source location where the output was produced.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+
+    /**
+     * The source location's line where the output was produced.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<i64>,
+
+    /**
+     * The position in 'line' where the output was produced. It is measured in
+     * UTF-16 code units and the client capability 'columnsStartAt1' determines
+     * whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * Additional data to report. For the 'telemetry' category the data is sent
+     * to telemetry, for the other categories the data is shown in JSON format.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+
+    /**
+     * A reference that allows the client to request the location where the new
+     * value is declared. For example, if the logged value is function pointer,
+     * the adapter may be able to look up the function's location. This should
+     * be present only if the adapter is likely to be able to resolve the
+     * location.
+     *
+     * This reference shares the same lifetime as the 'variablesReference'. See
+     * 'Lifetime of Object References' in the Overview section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_reference: Option<i64>,
+}
+
+impl OutputEvent {
+    pub fn new(output: impl Into<String>) -> Self {
+        OutputEvent {
+            category: None,
+            output: output.into(),
+            group: None,
+            variables_reference: None,
+            source: None,
+            line: None,
+            column: None,
+            data: None,
+            location_reference: None,
+        }
+    }
+
+    pub fn category(mut self, category: impl Into<Category>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    pub fn group(mut self, group: impl Into<Group>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    pub fn variables_reference(
+        mut self,
+        variables_reference: impl Into<VariablesReference>,
+    ) -> Self {
+        self.variables_reference = Some(variables_reference.into());
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<Source>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn line(mut self, line: impl Into<i64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn data(mut self, data: impl Into<Value>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    pub fn location_reference(mut self, location_reference: impl Into<i64>) -> Self {
+        self.location_reference = Some(location_reference.into());
+        self
+    }
+}
+
+/**
+ * The event indicates that some information about a breakpoint has changed.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakpointEvent {
+    /**
+     * The reason for the event.
+     * Values: 'changed', 'new', 'removed', etc.
+     */
+    pub reason: Reason,
+
+    /**
+     * The 'id' attribute is used to find the target breakpoint, the other
+     * attributes are used as the new values.
+     */
+    pub breakpoint: Breakpoint,
+}
+
+impl BreakpointEvent {
+    pub fn new(reason: impl Into<Reason>, breakpoint: impl Into<Breakpoint>) -> Self {
+        BreakpointEvent {
+            reason: reason.into(),
+            breakpoint: breakpoint.into(),
+        }
+    }
+}
+
+/**
+ * The event indicates that some information about a module has changed.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleEvent {
+    /**
+     * The reason for the event.
+     * Values: 'new', 'changed', 'removed'
+     */
+    pub reason: Reason,
+
+    /**
+     * The new, changed, or removed module. In case of 'removed' only the module
+     * id is used.
+     */
+    pub module: Module,
+}
+
+impl ModuleEvent {
+    pub fn new(reason: impl Into<Reason>, module: impl Into<Module>) -> Self {
+        ModuleEvent {
+            reason: reason.into(),
+            module: module.into(),
+        }
+    }
+}
+
+/**
+ * The event indicates that some source has been added, changed, or removed from
+ * the set of all loaded sources.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadedSourceEvent {
+    /**
+     * The reason for the event.
+     * Values: 'new', 'changed', 'removed'
+     */
+    pub reason: Reason,
+
+    /**
+     * The new, changed, or removed source.
+     */
+    pub source: Source,
+}
+
+impl LoadedSourceEvent {
+    pub fn new(reason: impl Into<Reason>, source: impl Into<Source>) -> Self {
+        LoadedSourceEvent {
+            reason: reason.into(),
+            source: source.into(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StartMethod {
+    Launch,
+    Attach,
+    AttachForSuspendedLaunch,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * The event indicates that the debugger has begun debugging a new process.
+ * Either one that it has launched, or one that it has attached to.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessEvent {
+    /**
+     * The logical name of the process. This is usually the full path to
+     * process's executable file. Example: /home/example/myproj/program.js.
+     */
+    pub name: String,
+
+    /**
+     * The system process id of the debugged process. This property is missing
+     * for non-system processes.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_process_id: Option<ProcessId>,
+
+    /**
+     * If true, the process is running on the same computer as the debug
+     * adapter.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_local_process: Option<bool>,
+
+    /**
+     * Describes how the debug engine started debugging this process.
+     * Values:
+     * 'launch': Process was launched under the debugger.
+     * 'attach': Debugger attached to an existing process.
+     * 'attachForSuspendedLaunch': A project launcher component has launched a
+     * new process in a suspended state and then asked the debugger to attach.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_method: Option<StartMethod>,
+
+    /**
+     * The size of a pointer or address for this process, in bits. This value
+     * may be used by clients when formatting addresses for display.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pointer_size: Option<i64>,
+}
+
+impl ProcessEvent {
+    pub fn new(name: impl Into<String>) -> Self {
+        ProcessEvent {
+            name: name.into(),
+            system_process_id: None,
+            is_local_process: None,
+            start_method: None,
+            pointer_size: None,
+        }
+    }
+
+    pub fn system_process_id(mut self, system_process_id: impl Into<ProcessId>) -> Self {
+        self.system_process_id = Some(system_process_id.into());
+        self
+    }
+
+    pub fn is_local_process(mut self, is_local_process: impl Into<bool>) -> Self {
+        self.is_local_process = Some(is_local_process.into());
+        self
+    }
+
+    pub fn start_method(mut self, start_method: impl Into<StartMethod>) -> Self {
+        self.start_method = Some(start_method.into());
+        self
+    }
+
+    pub fn pointer_size(mut self, pointer_size: impl Into<i64>) -> Self {
+        self.pointer_size = Some(pointer_size.into());
+        self
+    }
+}
+
+/**
+ * The event indicates that one or more capabilities have changed.
+ * Since the capabilities are dependent on the client and its UI, it might not
+ * be possible to change that at random times (or too late).
+ * Consequently this event has a hint characteristic: a client can only be
+ * expected to make a 'best effort' in honoring individual capabilities but
+ * there are no guarantees.
+ * Only changed capabilities need to be included, all other capabilities keep
+ * their values.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapabilitiesEvent {
+    /**
+     * The set of updated capabilities.
+     */
+    pub capabilities: Capabilities,
+}
+
+impl CapabilitiesEvent {
+    pub fn new(capabilities: impl Into<Capabilities>) -> Self {
+        CapabilitiesEvent {
+            capabilities: capabilities.into(),
+        }
+    }
+}
+
+/**
+ * The event signals that a long running operation is about to start and
+ * provides additional information for the client to set up a corresponding
+ * progress and cancellation UI.
+ * The client is free to delay the showing of the UI in order to reduce flicker.
+ * This event should only be sent if the corresponding capability
+ * 'supportsProgressReporting' is true.
+ */
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressStartEvent {
+    /**
+     * An ID that can be used in subsequent 'progressUpdate' and 'progressEnd'
+     * events to make them refer to the same progress reporting.
+     * IDs must be unique within a debug session.
+     */
+    pub progress_id: ProgressId,
+
+    /**
+     * Short title of the progress reporting. Shown in the UI to describe the
+     * long running operation.
+     */
+    pub title: String,
+
+    /**
+     * The request ID that this progress report is related to. If specified a
+     * debug adapter is expected to emit progress events for the long running
+     * request until the request has been either completed or cancelled.
+     * If the request ID is omitted, the progress report is assumed to be
+     * related to some general activity of the debug adapter.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<i64>,
+
+    /**
+     * If true, the request that reports progress may be cancelled with a
+     * 'cancel' request.
+     * So this property basically controls whether the client should use UX that
+     * supports cancellation.
+     * Clients that don't support cancellation are allowed to ignore the
+     * setting.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellable: Option<bool>,
+
+    /**
+     * More detailed progress message.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /**
+     * Progress percentage to display (value range: 0 to 100). If omitted no
+     * percentage is shown.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<f64>,
+}
+
+impl ProgressStartEvent {
+    pub fn new(progress_id: impl Into<ProgressId>, title: impl Into<String>) -> Self {
+        ProgressStartEvent {
+            progress_id: progress_id.into(),
+            title: title.into(),
+            request_id: None,
+            cancellable: None,
+            message: None,
+            percentage: None,
+        }
+    }
+
+    pub fn request_id(mut self, request_id: impl Into<i64>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    pub fn cancellable(mut self, cancellable: impl Into<bool>) -> Self {
+        self.cancellable = Some(cancellable.into());
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn percentage(mut self, percentage: impl Into<f64>) -> Self {
+        self.percentage = Some(percentage.into());
+        self
+    }
+}
+
+/**
+ * The event signals that the progress reporting needs to be updated with a new
+ * message and/or percentage.
+ * The client does not have to update the UI immediately, but the clients needs
+ * to keep track of the message and/or percentage values.
+ * This event should only be sent if the corresponding capability
+ * 'supportsProgressReporting' is true.
+ */
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressUpdateEvent {
+    /**
+     * The ID that was introduced in the initial 'progressStart' event.
+     */
+    pub progress_id: ProgressId,
+
+    /**
+     * More detailed progress message. If omitted, the previous message (if any)
+     * is used.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /**
+     * Progress percentage to display (value range: 0 to 100). If omitted no
+     * percentage is shown.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<f64>,
+}
+
+impl ProgressUpdateEvent {
+    pub fn new(progress_id: impl Into<ProgressId>) -> Self {
+        ProgressUpdateEvent {
+            progress_id: progress_id.into(),
+            message: None,
+            percentage: None,
+        }
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn percentage(mut self, percentage: impl Into<f64>) -> Self {
+        self.percentage = Some(percentage.into());
+        self
+    }
+}
+
+/**
+ * The event signals the end of the progress reporting with a final message.
+ * This event should only be sent if the corresponding capability
+ * 'supportsProgressReporting' is true.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressEndEvent {
+    /**
+     * The ID that was introduced in the initial 'ProgressStartEvent'.
+     */
+    pub progress_id: ProgressId,
+
+    /**
+     * More detailed progress message. If omitted, the previous message (if any)
+     * is used.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ProgressEndEvent {
+    pub fn new(progress_id: impl Into<ProgressId>) -> Self {
+        ProgressEndEvent {
+            progress_id: progress_id.into(),
+            message: None,
+        }
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/**
+ * This event signals that some state in the debug adapter has changed and
+ * requires that the client needs to re-render the data snapshot previously
+ * requested.
+ * Debug adapters do not have to emit this event for runtime changes like
+ * stopped or thread events because in that case the client refetches the new
+ * state anyway. But the event can be used for example to refresh the UI after
+ * rendering formatting has changed in the debug adapter.
+ * This event should only be sent if the corresponding capability
+ * 'supportsInvalidatedEvent' is true.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidatedEvent {
+    /**
+     * Set of logical areas that got invalidated. This property has a hint
+     * characteristic: a client can only be expected to make a 'best effort' in
+     * honoring the areas but there are no guarantees. If this property is
+     * missing, empty, or if values are not understood, the client should assume
+     * a single value 'all'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub areas: Option<Vec<InvalidatedAreas>>,
+
+    /**
+     * If specified, the client only needs to refetch data related to this
+     * thread.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<ThreadId>,
+
+    /**
+     * If specified, the client only needs to refetch data related to this stack
+     * frame (and the 'threadId' is ignored).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack_frame_id: Option<FrameId>,
+}
+
+impl InvalidatedEvent {
+    pub fn areas(mut self, areas: impl Into<Vec<InvalidatedAreas>>) -> Self {
+        self.areas = Some(areas.into());
+        self
+    }
+
+    pub fn thread_id(mut self, thread_id: impl Into<ThreadId>) -> Self {
+        self.thread_id = Some(thread_id.into());
+        self
+    }
+
+    pub fn stack_frame_id(mut self, stack_frame_id: impl Into<FrameId>) -> Self {
+        self.stack_frame_id = Some(stack_frame_id.into());
+        self
+    }
+}
+
+/**
+ * This event indicates that some memory range has been updated. It should only
+ * be sent if the corresponding capability 'supportsMemoryEvent' is true.
+ * Clients typically react to the event by re-issuing a 'readMemory' request if
+ * they show the memory identified by the 'memoryReference' and if the updated
+ * memory range overlaps the displayed range. Clients should not make
+ * assumptions how individual memory references relate to each other, so they
+ * should not assume that they are part of a single continuous address range and
+ * might overlap.
+ * Debug adapters can use this event to indicate that the contents of a memory
+ * range has changed due to some other request like 'setVariable' or
+ * 'setExpression'. Debug adapters are not expected to emit this event for each
+ * and every memory change of a running program, because that information is
+ * typically not available from debuggers and it would flood clients with too
+ * many events.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryEvent {
+    /**
+     * Memory reference of a memory range that has been updated.
+     */
+    pub memory_reference: MemoryReference,
+
+    /**
+     * Starting offset in bytes where memory has been updated. Can be negative.
+     */
+    pub offset: i64,
+
+    /**
+     * Number of bytes updated.
+     */
+    pub count: i64,
+}
+
+impl MemoryEvent {
+    pub fn new(
+        memory_reference: impl Into<MemoryReference>,
+        offset: impl Into<i64>,
+        count: impl Into<i64>,
+    ) -> Self {
+        MemoryEvent {
+            memory_reference: memory_reference.into(),
+            offset: offset.into(),
+            count: count.into(),
+        }
+    }
+}
@@ -0,0 +1,262 @@
+/*!
+
+Allocates the IDs an adapter hands out for its own objects: stack frames,
+variable containers and sources.
+
+The protocol requires these IDs to be positive and no larger than 2^31-1, and
+frame IDs and variables references are only valid while the debuggee is
+stopped. A [`Registry`] keeps one [`Handles`] table per kind of ID, resolves the
+IDs in the 'scopes', 'variables' and 'source' arguments, and drops the per-stop
+handles as soon as the debuggee resumes.
+
+*/
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::events::EventBody;
+use crate::requests::{RequestCommand, ScopesArguments, SourceArguments, VariablesArguments};
+use crate::types::{FrameId, SourceReference, VariablesReference};
+
+/**
+ * An ID that can be allocated by [`Handles`].
+ */
+pub trait Handle: Copy {
+    /**
+     * Wraps a raw value, which is always between 1 and 2^31-1.
+     */
+    fn from_raw(raw: i64) -> Self;
+
+    fn raw(self) -> i64;
+}
+
+impl Handle for FrameId {
+    fn from_raw(raw: i64) -> Self {
+        FrameId(raw)
+    }
+
+    fn raw(self) -> i64 {
+        self.0
+    }
+}
+
+impl Handle for VariablesReference {
+    fn from_raw(raw: i64) -> Self {
+        VariablesReference::new(raw).expect("handles stay below 2^31")
+    }
+
+    fn raw(self) -> i64 {
+        self.get()
+    }
+}
+
+impl Handle for SourceReference {
+    fn from_raw(raw: i64) -> Self {
+        SourceReference::new(raw).expect("handles stay below 2^31")
+    }
+
+    fn raw(self) -> i64 {
+        self.get()
+    }
+}
+
+/**
+ * The largest handle, 2^31-1.
+ */
+const MAX: i64 = i32::MAX as i64;
+
+/**
+ * Maps IDs of type `H` to values of type `T`.
+ *
+ * IDs start at 1, so 0 never refers to an entry, and keep counting up after
+ * [`Handles::clear`]: a client holding on to a stale ID gets nothing rather
+ * than some unrelated object. After 2^31-1 the count wraps around, skipping the
+ * IDs still in use.
+ */
+#[derive(Debug, Clone)]
+pub struct Handles<H, T> {
+    next: i64,
+    values: HashMap<i64, T>,
+    handle: PhantomData<H>,
+}
+
+impl<H: Handle, T> Handles<H, T> {
+    pub fn new() -> Self {
+        Handles {
+            next: 1,
+            values: HashMap::new(),
+            handle: PhantomData,
+        }
+    }
+
+    /**
+     * Stores the value and returns its new ID.
+     *
+     * # Panics
+     *
+     * Panics if all 2^31-1 IDs are in use.
+     */
+    pub fn insert(&mut self, value: T) -> H {
+        assert!(
+            (self.values.len() as i64) < MAX,
+            "all 2^31-1 handles are in use"
+        );
+        while self.values.contains_key(&self.next) {
+            self.advance();
+        }
+        let raw = self.next;
+        self.advance();
+        self.values.insert(raw, value);
+        H::from_raw(raw)
+    }
+
+    fn advance(&mut self) {
+        self.next = if self.next == MAX { 1 } else { self.next + 1 };
+    }
+
+    pub fn get(&self, handle: H) -> Option<&T> {
+        self.values.get(&handle.raw())
+    }
+
+    pub fn get_mut(&mut self, handle: H) -> Option<&mut T> {
+        self.values.get_mut(&handle.raw())
+    }
+
+    pub fn remove(&mut self, handle: H) -> Option<T> {
+        self.values.remove(&handle.raw())
+    }
+
+    pub fn contains(&self, handle: H) -> bool {
+        self.values.contains_key(&handle.raw())
+    }
+
+    /**
+     * Drops all entries. IDs handed out before stay invalid.
+     */
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<H: Handle, T> Default for Handles<H, T> {
+    fn default() -> Self {
+        Handles::new()
+    }
+}
+
+/**
+ * The handles of an adapter: its stack frames `F`, variable containers `V` and
+ * sources `S`.
+ *
+ * Frames and variables are only valid until the debuggee resumes. Call
+ * [`Registry::request`] with every request the adapter receives, and
+ * [`Registry::event`] with every event it sends, or call
+ * [`Registry::invalidate`] directly from the handlers that resume execution.
+ * Sources stay valid for the whole session.
+ */
+#[derive(Debug, Clone)]
+pub struct Registry<F, V, S> {
+    pub frames: Handles<FrameId, F>,
+    pub variables: Handles<VariablesReference, V>,
+    pub sources: Handles<SourceReference, S>,
+}
+
+impl<F, V, S> Registry<F, V, S> {
+    pub fn new() -> Self {
+        Registry {
+            frames: Handles::new(),
+            variables: Handles::new(),
+            sources: Handles::new(),
+        }
+    }
+
+    /**
+     * Drops the frames and variables, which the client may no longer use once
+     * the debuggee resumes.
+     */
+    pub fn invalidate(&mut self) {
+        self.frames.clear();
+        self.variables.clear();
+    }
+
+    /**
+     * Invalidates the per-stop handles if the request resumes the debuggee.
+     */
+    pub fn request(&mut self, request: &RequestCommand) {
+        if resumes(request) {
+            self.invalidate();
+        }
+    }
+
+    /**
+     * Invalidates the per-stop handles if the event announces that the
+     * debuggee continued.
+     */
+    pub fn event(&mut self, event: &EventBody) {
+        if let EventBody::Continued(_) = event {
+            self.invalidate();
+        }
+    }
+
+    /**
+     * The frame whose scopes are requested.
+     */
+    pub fn scopes(&self, arguments: &ScopesArguments) -> Option<&F> {
+        self.frames.get(arguments.frame_id)
+    }
+
+    /**
+     * The container whose children are requested.
+     */
+    pub fn variables(&self, arguments: &VariablesArguments) -> Option<&V> {
+        self.variables.get(arguments.variables_reference)
+    }
+
+    /**
+     * The source whose contents are requested. The reference in
+     * [`SourceArguments::source`] takes precedence over the deprecated
+     * [`SourceArguments::source_reference`].
+     */
+    pub fn source(&self, arguments: &SourceArguments) -> Option<&S> {
+        let reference = arguments
+            .source
+            .as_ref()
+            .and_then(|source| source.source_reference)
+            .filter(|reference| reference.get() != 0)
+            .unwrap_or(arguments.source_reference);
+        self.sources.get(reference)
+    }
+}
+
+impl<F, V, S> Default for Registry<F, V, S> {
+    fn default() -> Self {
+        Registry::new()
+    }
+}
+
+/**
+ * Whether the request lets the debuggee run, invalidating frame IDs and
+ * variables references.
+ */
+pub fn resumes(request: &RequestCommand) -> bool {
+    matches!(
+        request,
+        RequestCommand::Continue(_)
+            | RequestCommand::Next(_)
+            | RequestCommand::StepIn(_)
+            | RequestCommand::StepOut(_)
+            | RequestCommand::StepBack(_)
+            | RequestCommand::ReverseContinue(_)
+            | RequestCommand::Restart(_)
+            | RequestCommand::RestartFrame(_)
+            | RequestCommand::Goto(_)
+    )
+}
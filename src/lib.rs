@@ -4,36 +4,94 @@ Debug Adapter Protocol types for Rust.
 
 Based on: <https://microsoft.github.io/debug-adapter-protocol/specification>
 
+Implements version 1.70 of the protocol, see [`DAP_VERSION`].
+
 */
 
 use events::Event;
 use requests::RequestCommand;
 use responses::Response;
-use serde::{Deserialize, Serialize};
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
 
+pub mod adapter;
+pub mod capabilities;
+pub mod client;
+pub mod codec;
+mod connection;
 pub mod events;
+pub mod handles;
+pub mod memory;
+pub mod paths;
+pub mod positions;
 pub mod requests;
 pub mod responses;
+pub mod session;
 pub mod types;
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+/**
+ * The version of the Debug Adapter Protocol specification these types follow.
+ */
+pub const DAP_VERSION: &str = "1.70";
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum NumberOrString {
-    Number(i32),
+    Number(i64),
     String(String),
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+impl fmt::Display for NumberOrString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumberOrString::Number(number) => number.fmt(f),
+            NumberOrString::String(string) => string.fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
 pub struct ProtocolMessage {
     pub seq: i64,
     #[serde(flatten)]
     pub message: MessageKind,
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase", tag = "type")]
 pub enum MessageKind {
     Request(RequestCommand),
     Response(Response),
     Event(Event),
 }
+
+/**
+ * Splits a request, response or event into its tag, such as the command, and
+ * its payload. A null payload counts as missing.
+ */
+pub(crate) fn split_message<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    tag: &'static str,
+    content: &str,
+) -> Result<(String, Option<Value>), D::Error> {
+    let mut fields = Map::deserialize(deserializer)?;
+    let name = match fields.remove(tag) {
+        Some(Value::String(name)) => name,
+        Some(_) => return Err(D::Error::custom(format!("'{}' is not a string", tag))),
+        None => return Err(D::Error::missing_field(tag)),
+    };
+    Ok((
+        name,
+        fields.remove(content).filter(|value| !value.is_null()),
+    ))
+}
+
+/**
+ * Parses the payload of a known request, response or event. A missing payload
+ * parses as an empty object, for the messages whose payload is optional.
+ */
+pub(crate) fn parse_content<T: DeserializeOwned, E: Error>(content: Option<Value>) -> Result<T, E> {
+    serde_json::from_value(content.unwrap_or_else(|| Value::Object(Map::new()))).map_err(E::custom)
+}
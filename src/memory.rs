@@ -0,0 +1,276 @@
+/*!
+
+Resolves the addresses the memory and disassembly requests refer to.
+
+These requests locate memory with an opaque [`MemoryReference`] and a signed
+byte offset. Adapters that use addresses as memory references get the address
+with the offset applied from the `address` method of each of them.
+
+With the `base64` feature, the bytes read and written are also available as
+bytes rather than as the base64 strings sent on the wire, see
+`ReadMemoryResponse::read` and `WriteMemoryArguments::bytes_written`.
+
+*/
+
+#[cfg(feature = "base64")]
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+#[cfg(feature = "base64")]
+use base64::engine::general_purpose::STANDARD;
+#[cfg(feature = "base64")]
+use base64::Engine;
+
+use crate::events::MemoryEvent;
+use crate::requests::{DisassembleArguments, ReadMemoryArguments, WriteMemoryArguments};
+#[cfg(feature = "base64")]
+use crate::responses::{ReadMemoryResponse, WriteMemoryResponse};
+use crate::types::{Address, AddressError, InstructionBreakpoint, MemoryReference};
+
+fn resolve(reference: &MemoryReference, offset: Option<i64>) -> Result<Address, AddressError> {
+    reference.address()?.offset(offset.unwrap_or(0))
+}
+
+impl ReadMemoryArguments {
+    /**
+     * The address of the first byte to read.
+     */
+    pub fn address(&self) -> Result<Address, AddressError> {
+        resolve(&self.memory_reference, self.offset)
+    }
+}
+
+impl WriteMemoryArguments {
+    /**
+     * The address of the first byte to write.
+     */
+    pub fn address(&self) -> Result<Address, AddressError> {
+        resolve(&self.memory_reference, self.offset)
+    }
+}
+
+impl DisassembleArguments {
+    /**
+     * The address to disassemble from, before applying the instruction offset.
+     */
+    pub fn address(&self) -> Result<Address, AddressError> {
+        resolve(&self.memory_reference, self.offset)
+    }
+}
+
+impl InstructionBreakpoint {
+    /**
+     * The address of the breakpoint.
+     */
+    pub fn address(&self) -> Result<Address, AddressError> {
+        resolve(&self.instruction_reference, self.offset)
+    }
+}
+
+impl MemoryEvent {
+    /**
+     * The address of the first byte that changed.
+     */
+    pub fn address(&self) -> Result<Address, AddressError> {
+        resolve(&self.memory_reference, Some(self.offset))
+    }
+}
+
+/**
+ * Data read from or written to memory that does not match the request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum MemoryError {
+    /**
+     * The data is not valid base64.
+     */
+    InvalidData(String),
+
+    /**
+     * More bytes were returned than the request asked for.
+     */
+    TooMany { requested: i64, returned: i64 },
+
+    /**
+     * More bytes were written than the request gave.
+     */
+    TooManyWritten { written: i64, length: i64 },
+
+    /**
+     * Only part of the data could be written, but the request did not set
+     * 'allowPartial'.
+     */
+    PartialWrite { written: i64, length: i64 },
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryError::InvalidData(err) => write!(f, "invalid base64 data: {}", err),
+            MemoryError::TooMany {
+                requested,
+                returned,
+            } => write!(
+                f,
+                "{} bytes requested, but {} returned",
+                requested, returned
+            ),
+            MemoryError::TooManyWritten { written, length } => {
+                write!(f, "{} bytes written, but only {} given", written, length)
+            }
+            MemoryError::PartialWrite { written, length } => write!(
+                f,
+                "only {} of {} bytes could be written, and partial writes are not allowed",
+                written, length
+            ),
+        }
+    }
+}
+
+impl Error for MemoryError {}
+
+#[cfg(feature = "base64")]
+fn decode(data: &str) -> Result<Vec<u8>, MemoryError> {
+    STANDARD
+        .decode(data)
+        .map_err(|err| MemoryError::InvalidData(err.to_string()))
+}
+
+#[cfg(feature = "base64")]
+fn length(bytes: &[u8]) -> i64 {
+    i64::try_from(bytes.len()).unwrap_or(i64::MAX)
+}
+
+/**
+ * The bytes returned for a 'readMemory' request.
+ */
+#[cfg(feature = "base64")]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MemoryRead {
+    /**
+     * The address of the first byte.
+     */
+    pub address: Address,
+
+    pub bytes: Vec<u8>,
+
+    /**
+     * The number of bytes after the last one read that could not be read, and
+     * should be skipped before reading on.
+     */
+    pub unreadable_bytes: i64,
+
+    /**
+     * Whether the read stopped at the end of readable memory: it returned fewer
+     * bytes than requested, and none of them were unreadable.
+     */
+    pub end_of_memory: bool,
+}
+
+#[cfg(feature = "base64")]
+impl MemoryRead {
+    /**
+     * The address to continue reading from, past the unreadable bytes.
+     */
+    pub fn next_address(&self) -> Option<Address> {
+        self.address
+            .checked_add(length(&self.bytes))?
+            .checked_add(self.unreadable_bytes)
+    }
+}
+
+#[cfg(feature = "base64")]
+impl ReadMemoryResponse {
+    /**
+     * Sets the data to the bytes read, encoded as base64.
+     */
+    pub fn bytes(self, bytes: &[u8]) -> Self {
+        self.data(STANDARD.encode(bytes))
+    }
+
+    /**
+     * Decodes the data. A response without data holds no bytes.
+     */
+    pub fn decode_data(&self) -> Result<Vec<u8>, MemoryError> {
+        match &self.data {
+            Some(data) => decode(data),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /**
+     * Decodes the response to the request, checking that it does not return
+     * more bytes, readable or not, than requested.
+     */
+    pub fn read(&self, arguments: &ReadMemoryArguments) -> Result<MemoryRead, MemoryError> {
+        let bytes = self.decode_data()?;
+        let unreadable_bytes = self.unreadable_bytes.unwrap_or(0).max(0);
+        let returned = length(&bytes).saturating_add(unreadable_bytes);
+        if returned > arguments.count {
+            return Err(MemoryError::TooMany {
+                requested: arguments.count,
+                returned,
+            });
+        }
+        Ok(MemoryRead {
+            address: self.address,
+            end_of_memory: length(&bytes) < arguments.count && unreadable_bytes == 0,
+            bytes,
+            unreadable_bytes,
+        })
+    }
+}
+
+#[cfg(feature = "base64")]
+impl WriteMemoryArguments {
+    /**
+     * Writes the bytes, encoded as base64, to the referenced memory.
+     */
+    pub fn with_bytes(memory_reference: impl Into<MemoryReference>, bytes: &[u8]) -> Self {
+        WriteMemoryArguments::new(memory_reference, STANDARD.encode(bytes))
+    }
+
+    pub fn decode_data(&self) -> Result<Vec<u8>, MemoryError> {
+        decode(&self.data)
+    }
+
+    /**
+     * The response after writing the first `written` bytes of the data. Writing
+     * fewer bytes than given fails, unless the request allows partial writes.
+     */
+    pub fn respond(&self, written: i64) -> Result<WriteMemoryResponse, MemoryError> {
+        let length = length(&self.decode_data()?);
+        if written > length {
+            return Err(MemoryError::TooManyWritten { written, length });
+        }
+        if self.allow_partial != Some(true) {
+            return if written == length {
+                Ok(WriteMemoryResponse::default())
+            } else {
+                Err(MemoryError::PartialWrite { written, length })
+            };
+        }
+        let response = WriteMemoryResponse::default().bytes_written(written);
+        Ok(match self.offset {
+            Some(offset) => response.offset(offset),
+            None => response,
+        })
+    }
+
+    /**
+     * The number of bytes the response says were written, which is all of them
+     * unless the request allowed partial writes.
+     */
+    pub fn bytes_written(&self, response: &WriteMemoryResponse) -> Result<i64, MemoryError> {
+        let length = length(&self.decode_data()?);
+        match response.bytes_written {
+            Some(written) if written > length => {
+                Err(MemoryError::TooManyWritten { written, length })
+            }
+            Some(written) if self.allow_partial == Some(true) => Ok(written.max(0)),
+            Some(written) if written < length => Err(MemoryError::PartialWrite { written, length }),
+            _ => Ok(length),
+        }
+    }
+}
@@ -0,0 +1,443 @@
+/*!
+
+Converts the paths of sources between the formats of the client and the
+adapter.
+
+The client announces in 'initialize' whether paths are native paths or
+'file://' URIs, see [`InitializeRequestArguments::path_format`]. The path of
+every [`Source`] in requests, responses and events follows that format. An
+adapter that works with another format returns it from
+[`DebugAdapter::path_format`](crate::adapter::DebugAdapter::path_format), and
+the runner converts the paths of every message on the way in and out.
+
+Paths are converted as they are: a remote client on Windows still sends paths
+such as 'C:/project/main.rs'. Relative paths have no URI and are left as they
+are.
+
+When the debuggee runs on another machine, such as in a container, the paths it
+reports do not exist where the client runs. [`PathMappings`] rewrite them
+between the two, see
+[`Client::set_path_mappings`](crate::adapter::Client::set_path_mappings).
+
+*/
+
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+use crate::events::{Event, EventBody};
+use crate::requests::{InitializeRequestArguments, PathFormat, RequestCommand};
+use crate::responses::{Response, ResponseBody};
+use crate::types::{Breakpoint, DisassembledInstruction, Scope, Source, StackFrame};
+use crate::{MessageKind, ProtocolMessage};
+
+/**
+ * A path that cannot be converted to the other format.
+ */
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum PathError {
+    /**
+     * A URI that does not refer to a local file, or that is not properly
+     * percent-encoded.
+     */
+    InvalidUri(String),
+
+    /**
+     * A relative path, which has no 'file://' URI.
+     */
+    RelativePath(String),
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::InvalidUri(uri) => write!(f, "'{}' is not a file URI", uri),
+            PathError::RelativePath(path) => write!(f, "'{}' is not an absolute path", path),
+        }
+    }
+}
+
+impl Error for PathError {}
+
+/**
+ * The path format the client announced, [`PathFormat::Path`] if it did not say.
+ */
+pub fn client_format(arguments: &InitializeRequestArguments) -> PathFormat {
+    arguments.path_format.clone().unwrap_or(PathFormat::Path)
+}
+
+/**
+ * Whether the path starts with a Windows drive letter, like 'C:'.
+ */
+fn has_drive_letter(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+/**
+ * Turns an absolute path into a 'file://' URI, percent-encoding all but the
+ * unreserved characters. Windows paths, with a drive letter or a server, have
+ * their backslashes turned into slashes, and the colon of the drive letter is
+ * kept, as in 'file:///C:/project/main.rs'.
+ */
+pub fn path_to_uri(path: &str) -> Result<String, PathError> {
+    let relative = || PathError::RelativePath(path.to_string());
+    let normalized = if has_drive_letter(path) || path.starts_with("\\\\") {
+        path.replace('\\', "/")
+    } else {
+        path.to_string()
+    };
+    let (mut uri, rest) = if has_drive_letter(&normalized) {
+        // 'C:main.rs' is relative to the current directory of drive C.
+        if normalized.as_bytes().get(2) != Some(&b'/') {
+            return Err(relative());
+        }
+        let (drive, rest) = normalized.split_at(2);
+        (format!("file:///{}", drive), rest)
+    } else if let Some(share) = normalized.strip_prefix("//") {
+        // A UNC path, whose server becomes the authority.
+        let (server, rest) = share.split_at(share.find('/').unwrap_or(share.len()));
+        (format!("file://{}", server), rest)
+    } else if normalized.starts_with('/') {
+        (String::from("file://"), normalized.as_str())
+    } else {
+        return Err(relative());
+    };
+    for byte in rest.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                uri.push(char::from(byte))
+            }
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    Ok(uri)
+}
+
+/**
+ * Turns a 'file://' URI into a path. A drive letter loses its leading slash,
+ * and a server other than 'localhost' becomes a UNC path.
+ */
+pub fn uri_to_path(uri: &str) -> Result<String, PathError> {
+    let invalid = || PathError::InvalidUri(uri.to_string());
+    let rest = match uri.get(.."file://".len()) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("file://") => &uri["file://".len()..],
+        _ => return Err(invalid()),
+    };
+    let (server, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let path = decode(path).ok_or_else(invalid)?;
+    if !server.is_empty() && !server.eq_ignore_ascii_case("localhost") {
+        return Ok(format!("//{}{}", server, path));
+    }
+    match path.strip_prefix('/') {
+        Some(windows) if has_drive_letter(windows) => Ok(windows.to_string()),
+        _ => Ok(path),
+    }
+}
+
+fn decode(encoded: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut rest = encoded.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/**
+ * Converts a path from one format to another. Paths in formats this crate does
+ * not know are left as they are.
+ */
+pub fn convert(path: &str, from: &PathFormat, to: &PathFormat) -> Result<String, PathError> {
+    match (from, to) {
+        (PathFormat::Path, PathFormat::Uri) => path_to_uri(path),
+        (PathFormat::Uri, PathFormat::Path) => uri_to_path(path),
+        _ => Ok(path.to_string()),
+    }
+}
+
+/**
+ * Maps the paths under a directory on the machine of the debuggee to a
+ * directory on the machine of the client.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathMapping {
+    pub remote_root: String,
+    pub local_root: String,
+}
+
+/**
+ * Rules mapping remote paths to local paths and back. When several roots
+ * contain a path, the longest one wins. Roots only match whole path
+ * components, so '/app' contains '/app/main.rs' but not '/application'.
+ *
+ * The paths are in the format the handlers work with.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct PathMappings {
+    mappings: Vec<PathMapping>,
+}
+
+impl PathMappings {
+    pub fn new() -> Self {
+        PathMappings::default()
+    }
+
+    /**
+     * Adds a mapping from a remote root to a local root.
+     */
+    pub fn map(mut self, remote_root: impl Into<String>, local_root: impl Into<String>) -> Self {
+        self.push(PathMapping {
+            remote_root: remote_root.into(),
+            local_root: local_root.into(),
+        });
+        self
+    }
+
+    pub fn push(&mut self, mapping: PathMapping) {
+        self.mappings.push(mapping);
+    }
+
+    pub fn mappings(&self) -> &[PathMapping] {
+        &self.mappings
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+    }
+
+    /**
+     * The local path of a remote path, or `None` if no remote root contains it.
+     */
+    pub fn local_path(&self, path: &str) -> Option<String> {
+        self.rewrite(path, |m| (&m.remote_root, &m.local_root))
+    }
+
+    /**
+     * The remote path of a local path, or `None` if no local root contains it.
+     */
+    pub fn remote_path(&self, path: &str) -> Option<String> {
+        self.rewrite(path, |m| (&m.local_root, &m.remote_root))
+    }
+
+    /**
+     * Replaces the longest of the `from` roots that contains the path with the
+     * matching `to` root.
+     */
+    fn rewrite(&self, path: &str, roots: impl Fn(&PathMapping) -> (&str, &str)) -> Option<String> {
+        let (_, to, rest) = self
+            .mappings
+            .iter()
+            .filter_map(|mapping| {
+                let (from, to) = roots(mapping);
+                let from = from.trim_end_matches('/');
+                Some((from.len(), to, strip_root(path, from)?))
+            })
+            .max_by_key(|(length, _, _)| *length)?;
+        let mapped = format!("{}{}", to.trim_end_matches('/'), rest);
+        Some(if mapped.is_empty() {
+            "/".to_string()
+        } else {
+            mapped
+        })
+    }
+
+    /**
+     * Rewrites the paths of all sources from remote to local, as for the
+     * responses and events sent to the client. Paths outside of the remote
+     * roots are left as they are.
+     */
+    pub fn map_to_local<M: Sources + ?Sized>(&self, message: &mut M) {
+        self.map_sources(message, PathMappings::local_path);
+    }
+
+    /**
+     * Rewrites the paths of all sources from local to remote, as for the
+     * requests received from the client. Paths outside of the local roots are
+     * left as they are.
+     */
+    pub fn map_to_remote<M: Sources + ?Sized>(&self, message: &mut M) {
+        self.map_sources(message, PathMappings::remote_path);
+    }
+
+    fn map_sources<M: Sources + ?Sized>(
+        &self,
+        message: &mut M,
+        map: fn(&Self, &str) -> Option<String>,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+        message.for_each_source(&mut |source| {
+            if let Some(path) = &mut source.path {
+                if let Some(mapped) = map(self, path) {
+                    *path = mapped;
+                }
+            }
+        });
+    }
+}
+
+/**
+ * The rest of the path after the root, if the root, without a trailing slash,
+ * contains the path.
+ */
+fn strip_root<'p>(path: &'p str, root: &str) -> Option<&'p str> {
+    let rest = path.strip_prefix(root)?;
+    if rest.is_empty() || rest.starts_with('/') {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+/**
+ * Anything that holds sources.
+ */
+pub trait Sources {
+    /**
+     * Calls the function with every source, including nested ones.
+     */
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source));
+
+    /**
+     * Converts the path of every source from one format to another. Paths that
+     * cannot be converted, like URIs of unsaved files or relative paths, are
+     * left as they are.
+     */
+    fn convert_paths(&mut self, from: &PathFormat, to: &PathFormat) {
+        if from == to {
+            return;
+        }
+        self.for_each_source(&mut |source| {
+            if let Some(path) = &mut source.path {
+                if let Ok(converted) = convert(path, from, to) {
+                    *path = converted;
+                }
+            }
+        });
+    }
+}
+
+impl Sources for Source {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        f(self);
+        self.sources.for_each_source(f);
+    }
+}
+
+impl<T: Sources> Sources for Option<T> {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        if let Some(value) = self {
+            value.for_each_source(f);
+        }
+    }
+}
+
+impl<T: Sources> Sources for Vec<T> {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        for value in self {
+            value.for_each_source(f);
+        }
+    }
+}
+
+macro_rules! sources {
+    ($($name:ident { $($field:ident),* })*) => {
+        $(
+            impl Sources for $name {
+                fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+                    $(self.$field.for_each_source(f);)*
+                }
+            }
+        )*
+    };
+}
+
+sources! {
+    StackFrame { source }
+    Scope { source }
+    Breakpoint { source }
+    DisassembledInstruction { location }
+}
+
+impl Sources for RequestCommand {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        match self {
+            RequestCommand::BreakpointLocations(arguments) => arguments.source.for_each_source(f),
+            RequestCommand::SetBreakpoints(arguments) => arguments.source.for_each_source(f),
+            RequestCommand::Source(arguments) => arguments.source.for_each_source(f),
+            RequestCommand::Evaluate(arguments) => arguments.source.for_each_source(f),
+            RequestCommand::GotoTargets(arguments) => arguments.source.for_each_source(f),
+            _ => {}
+        }
+    }
+}
+
+impl Sources for ResponseBody {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        match self {
+            ResponseBody::SetBreakpoints(body) => body.breakpoints.for_each_source(f),
+            ResponseBody::SetFunctionBreakpoints(body) => body.breakpoints.for_each_source(f),
+            ResponseBody::SetExceptionBreakpoints(body) => body.breakpoints.for_each_source(f),
+            ResponseBody::SetDataBreakpoints(body) => body.breakpoints.for_each_source(f),
+            ResponseBody::SetInstructionBreakpoints(body) => body.breakpoints.for_each_source(f),
+            ResponseBody::StackTrace(body) => body.stack_frames.for_each_source(f),
+            ResponseBody::Scopes(body) => body.scopes.for_each_source(f),
+            ResponseBody::LoadedSources(body) => body.sources.for_each_source(f),
+            ResponseBody::Disassemble(body) => body.instructions.for_each_source(f),
+            ResponseBody::Locations(body) => body.source.for_each_source(f),
+            _ => {}
+        }
+    }
+}
+
+impl Sources for EventBody {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        match self {
+            EventBody::Output(body) => body.source.for_each_source(f),
+            EventBody::Breakpoint(body) => body.breakpoint.for_each_source(f),
+            EventBody::LoadedSource(body) => body.source.for_each_source(f),
+            _ => {}
+        }
+    }
+}
+
+impl Sources for Response {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        if let Ok(body) = &mut self.result {
+            body.for_each_source(f);
+        }
+    }
+}
+
+impl Sources for Event {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        self.body.for_each_source(f);
+    }
+}
+
+impl Sources for MessageKind {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        match self {
+            MessageKind::Request(command) => command.for_each_source(f),
+            MessageKind::Response(response) => response.for_each_source(f),
+            MessageKind::Event(event) => event.for_each_source(f),
+        }
+    }
+}
+
+impl Sources for ProtocolMessage {
+    fn for_each_source(&mut self, f: &mut dyn FnMut(&mut Source)) {
+        self.message.for_each_source(f);
+    }
+}
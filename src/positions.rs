@@ -0,0 +1,343 @@
+/*!
+
+Converts lines and columns between the conventions of the client and the
+adapter.
+
+The client announces in 'initialize' whether its lines and columns start at 0
+or 1, see [`InitializeRequestArguments::lines_start_at1`] and
+[`InitializeRequestArguments::columns_start_at1`]. All positions in requests,
+responses and events follow that convention. An adapter that works with other
+bases internally returns them from
+[`DebugAdapter::positions`](crate::adapter::DebugAdapter::positions), and the
+runner shifts the positions of every message on the way in and out.
+
+*/
+
+use crate::events::{Event, EventBody, OutputEvent};
+use crate::requests::{
+    BreakpointLocationsArguments, CompletionsArguments, EvaluateArguments, GotoTargetsArguments,
+    InitializeRequestArguments, RequestCommand, SetBreakpointsArguments,
+};
+use crate::responses::{LocationsResponse, Response, ResponseBody};
+use crate::types::{
+    Breakpoint, BreakpointLocation, CompletionItem, DisassembledInstruction, GotoTarget, Scope,
+    SourceBreakpoint, StackFrame, StepInTarget,
+};
+use crate::{MessageKind, ProtocolMessage};
+
+/**
+ * Whether lines and columns start at 0 or 1.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Bases {
+    pub lines_start_at1: bool,
+    pub columns_start_at1: bool,
+}
+
+impl Bases {
+    /**
+     * Lines and columns start at 0.
+     */
+    pub const ZERO: Bases = Bases {
+        lines_start_at1: false,
+        columns_start_at1: false,
+    };
+
+    /**
+     * Lines and columns start at 1, the default of the protocol.
+     */
+    pub const ONE: Bases = Bases {
+        lines_start_at1: true,
+        columns_start_at1: true,
+    };
+
+    /**
+     * The bases the client announced, 1 where it did not say.
+     */
+    pub fn of_client(arguments: &InitializeRequestArguments) -> Self {
+        Bases {
+            lines_start_at1: arguments.lines_start_at1.unwrap_or(true),
+            columns_start_at1: arguments.columns_start_at1.unwrap_or(true),
+        }
+    }
+}
+
+impl Default for Bases {
+    fn default() -> Self {
+        Bases::ONE
+    }
+}
+
+/**
+ * The amounts to add to lines and columns to convert them from one convention
+ * to another.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct Shift {
+    pub lines: i64,
+    pub columns: i64,
+}
+
+impl Shift {
+    pub fn new(from: Bases, to: Bases) -> Self {
+        Shift {
+            lines: i64::from(to.lines_start_at1) - i64::from(from.lines_start_at1),
+            columns: i64::from(to.columns_start_at1) - i64::from(from.columns_start_at1),
+        }
+    }
+
+    /**
+     * The shift converting back.
+     */
+    pub fn reverse(self) -> Self {
+        Shift {
+            lines: -self.lines,
+            columns: -self.columns,
+        }
+    }
+
+    pub fn is_none(self) -> bool {
+        self == Shift::default()
+    }
+}
+
+/**
+ * Anything that holds lines or columns.
+ */
+pub trait Positions {
+    /**
+     * Adds the shift to every line and column.
+     */
+    fn shift(&mut self, shift: Shift);
+}
+
+impl<T: Positions> Positions for Option<T> {
+    fn shift(&mut self, shift: Shift) {
+        if let Some(value) = self {
+            value.shift(shift);
+        }
+    }
+}
+
+impl<T: Positions> Positions for Vec<T> {
+    fn shift(&mut self, shift: Shift) {
+        for value in self {
+            value.shift(shift);
+        }
+    }
+}
+
+/**
+ * A single line or column, or a list of them.
+ */
+trait Coordinate {
+    fn offset(&mut self, delta: i64);
+}
+
+impl Coordinate for i64 {
+    fn offset(&mut self, delta: i64) {
+        *self += delta;
+    }
+}
+
+impl<T: Coordinate> Coordinate for Option<T> {
+    fn offset(&mut self, delta: i64) {
+        if let Some(value) = self {
+            value.offset(delta);
+        }
+    }
+}
+
+impl<T: Coordinate> Coordinate for Vec<T> {
+    fn offset(&mut self, delta: i64) {
+        for value in self {
+            value.offset(delta);
+        }
+    }
+}
+
+/**
+ * Implements [`Positions`] by shifting the given fields. With `source`, the
+ * positions are only shifted when the source is set, as they are 0 and to be
+ * ignored otherwise.
+ */
+macro_rules! positions {
+    ($($name:ident {
+        $(source: $source:ident,)?
+        lines: [$($line:ident),*],
+        columns: [$($column:ident),*],
+        nested: [$($nested:ident),*] $(,)?
+    })*) => {
+        $(
+            impl Positions for $name {
+                fn shift(&mut self, shift: Shift) {
+                    $(if self.$source.is_none() {
+                        return;
+                    })?
+                    $(self.$line.offset(shift.lines);)*
+                    $(self.$column.offset(shift.columns);)*
+                    $(self.$nested.shift(shift);)*
+                }
+            }
+        )*
+    };
+}
+
+positions! {
+    StackFrame {
+        source: source,
+        lines: [line, end_line],
+        columns: [column, end_column],
+        nested: [],
+    }
+    Scope {
+        source: source,
+        lines: [line, end_line],
+        columns: [column, end_column],
+        nested: [],
+    }
+    Breakpoint {
+        lines: [line, end_line],
+        columns: [column, end_column],
+        nested: [],
+    }
+    BreakpointLocation {
+        lines: [line, end_line],
+        columns: [column, end_column],
+        nested: [],
+    }
+    SourceBreakpoint {
+        lines: [line],
+        columns: [column],
+        nested: [],
+    }
+    StepInTarget {
+        lines: [line, end_line],
+        columns: [column, end_column],
+        nested: [],
+    }
+    GotoTarget {
+        lines: [line, end_line],
+        columns: [column, end_column],
+        nested: [],
+    }
+    CompletionItem {
+        lines: [],
+        columns: [start],
+        nested: [],
+    }
+    DisassembledInstruction {
+        lines: [line, end_line],
+        columns: [column, end_column],
+        nested: [],
+    }
+    BreakpointLocationsArguments {
+        lines: [line, end_line],
+        columns: [column, end_column],
+        nested: [],
+    }
+    SetBreakpointsArguments {
+        lines: [lines],
+        columns: [],
+        nested: [breakpoints],
+    }
+    EvaluateArguments {
+        lines: [line],
+        columns: [column],
+        nested: [],
+    }
+    GotoTargetsArguments {
+        lines: [line],
+        columns: [column],
+        nested: [],
+    }
+    CompletionsArguments {
+        lines: [line],
+        columns: [column],
+        nested: [],
+    }
+    LocationsResponse {
+        lines: [line, end_line],
+        columns: [column, end_column],
+        nested: [],
+    }
+    OutputEvent {
+        lines: [line],
+        columns: [column],
+        nested: [],
+    }
+}
+
+impl Positions for RequestCommand {
+    fn shift(&mut self, shift: Shift) {
+        match self {
+            RequestCommand::BreakpointLocations(arguments) => arguments.shift(shift),
+            RequestCommand::SetBreakpoints(arguments) => arguments.shift(shift),
+            RequestCommand::Evaluate(arguments) => arguments.shift(shift),
+            RequestCommand::GotoTargets(arguments) => arguments.shift(shift),
+            RequestCommand::Completions(arguments) => arguments.shift(shift),
+            _ => {}
+        }
+    }
+}
+
+impl Positions for ResponseBody {
+    fn shift(&mut self, shift: Shift) {
+        match self {
+            ResponseBody::BreakpointLocations(body) => body.breakpoints.shift(shift),
+            ResponseBody::SetBreakpoints(body) => body.breakpoints.shift(shift),
+            ResponseBody::SetFunctionBreakpoints(body) => body.breakpoints.shift(shift),
+            ResponseBody::SetExceptionBreakpoints(body) => body.breakpoints.shift(shift),
+            ResponseBody::SetDataBreakpoints(body) => body.breakpoints.shift(shift),
+            ResponseBody::SetInstructionBreakpoints(body) => body.breakpoints.shift(shift),
+            ResponseBody::StackTrace(body) => body.stack_frames.shift(shift),
+            ResponseBody::Scopes(body) => body.scopes.shift(shift),
+            ResponseBody::StepInTargets(body) => body.targets.shift(shift),
+            ResponseBody::GotoTargets(body) => body.targets.shift(shift),
+            ResponseBody::Completions(body) => body.targets.shift(shift),
+            ResponseBody::Disassemble(body) => body.instructions.shift(shift),
+            ResponseBody::Locations(body) => body.shift(shift),
+            _ => {}
+        }
+    }
+}
+
+impl Positions for EventBody {
+    fn shift(&mut self, shift: Shift) {
+        match self {
+            EventBody::Output(body) => body.shift(shift),
+            EventBody::Breakpoint(body) => body.breakpoint.shift(shift),
+            _ => {}
+        }
+    }
+}
+
+impl Positions for Response {
+    fn shift(&mut self, shift: Shift) {
+        if let Ok(body) = &mut self.result {
+            body.shift(shift);
+        }
+    }
+}
+
+impl Positions for Event {
+    fn shift(&mut self, shift: Shift) {
+        self.body.shift(shift);
+    }
+}
+
+impl Positions for MessageKind {
+    fn shift(&mut self, shift: Shift) {
+        match self {
+            MessageKind::Request(command) => command.shift(shift),
+            MessageKind::Response(response) => response.shift(shift),
+            MessageKind::Event(event) => event.shift(shift),
+        }
+    }
+}
+
+impl Positions for ProtocolMessage {
+    fn shift(&mut self, shift: Shift) {
+        self.message.shift(shift);
+    }
+}
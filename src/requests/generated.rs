@@ -0,0 +1,2269 @@
+// The definitions in this module are maintained by `cargo xtask codegen`, which
+// regenerates them from the Debug Adapter Protocol JSON schema. Do not edit
+// them by hand.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::{AttachRequestArguments, LaunchRequestArguments, Request};
+use crate::responses::{
+    BreakpointLocationsResponse, CompletionsResponse, ContinueResponse, DataBreakpointInfoResponse,
+    DisassembleResponse, EvaluateResponse, ExceptionInfoResponse, GotoTargetsResponse,
+    InitializeResponse, LoadedSourcesResponse, LocationsResponse, ModulesResponse,
+    ReadMemoryResponse, ResponseBody, RunInTerminalResponse, ScopesResponse,
+    SetBreakpointsResponse, SetDataBreakpointsResponse, SetExceptionBreakpointsResponse,
+    SetExpressionResponse, SetFunctionBreakpointsResponse, SetInstructionBreakpointsResponse,
+    SetVariableResponse, SourceResponse, StackTraceResponse, StepInTargetsResponse,
+    ThreadsResponse, VariablesResponse, WriteMemoryResponse,
+};
+use crate::types::{
+    DataBreakpoint, ExceptionFilterOptions, ExceptionOptions, FrameId, FunctionBreakpoint,
+    InstructionBreakpoint, MemoryReference, ProgressId, Source, SourceBreakpoint, SourceReference,
+    StackFrameFormat, SteppingGranularity, ThreadId, ValueFormat, VariablesReference,
+};
+use crate::{parse_content, split_message};
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase", tag = "command", content = "arguments")]
+pub enum RequestCommand {
+    Cancel(CancelArguments),
+    RunInTerminal(RunInTerminalRequestArguments),
+    StartDebugging(StartDebuggingRequestArguments),
+    Initialize(InitializeRequestArguments),
+    ConfigurationDone,
+    Launch(LaunchRequestArguments),
+    Attach(AttachRequestArguments),
+    Restart(RestartArguments),
+    Disconnect(DisconnectArguments),
+    Terminate(TerminateArguments),
+    BreakpointLocations(BreakpointLocationsArguments),
+    SetBreakpoints(SetBreakpointsArguments),
+    SetFunctionBreakpoints(SetFunctionBreakpointsArguments),
+    SetExceptionBreakpoints(SetExceptionBreakpointsArguments),
+    DataBreakpointInfo(DataBreakpointInfoArguments),
+    SetDataBreakpoints(SetDataBreakpointsArguments),
+    SetInstructionBreakpoints(SetInstructionBreakpointsArguments),
+    Continue(ContinueArguments),
+    Next(NextArguments),
+    StepIn(StepInArguments),
+    StepOut(StepOutArguments),
+    StepBack(StepBackArguments),
+    ReverseContinue(ReverseContinueArguments),
+    RestartFrame(RestartFrameArguments),
+    Goto(GotoArguments),
+    Pause(PauseArguments),
+    StackTrace(StackTraceArguments),
+    Scopes(ScopesArguments),
+    Variables(VariablesArguments),
+    SetVariable(SetVariableArguments),
+    Source(SourceArguments),
+    Threads,
+    TerminateThreads(TerminateThreadsArguments),
+    Modules(ModulesArguments),
+    LoadedSources,
+    Evaluate(EvaluateArguments),
+    SetExpression(SetExpressionArguments),
+    StepInTargets(StepInTargetsArguments),
+    GotoTargets(GotoTargetsArguments),
+    Completions(CompletionsArguments),
+    ExceptionInfo(ExceptionInfoArguments),
+    ReadMemory(ReadMemoryArguments),
+    WriteMemory(WriteMemoryArguments),
+    Disassemble(DisassembleArguments),
+    Locations(LocationsArguments),
+
+    /**
+     * A command not known to this crate. The raw arguments are preserved as is.
+     */
+    #[serde(untagged)]
+    Unknown {
+        command: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        arguments: Option<Value>,
+    },
+}
+
+impl<'de> Deserialize<'de> for RequestCommand {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (command, arguments) = split_message(deserializer, "command", "arguments")?;
+        Ok(match command.as_str() {
+            "cancel" => RequestCommand::Cancel(parse_content(arguments)?),
+            "runInTerminal" => RequestCommand::RunInTerminal(parse_content(arguments)?),
+            "startDebugging" => RequestCommand::StartDebugging(parse_content(arguments)?),
+            "initialize" => RequestCommand::Initialize(parse_content(arguments)?),
+            "configurationDone" => RequestCommand::ConfigurationDone,
+            "launch" => RequestCommand::Launch(parse_content(arguments)?),
+            "attach" => RequestCommand::Attach(parse_content(arguments)?),
+            "restart" => RequestCommand::Restart(parse_content(arguments)?),
+            "disconnect" => RequestCommand::Disconnect(parse_content(arguments)?),
+            "terminate" => RequestCommand::Terminate(parse_content(arguments)?),
+            "breakpointLocations" => RequestCommand::BreakpointLocations(parse_content(arguments)?),
+            "setBreakpoints" => RequestCommand::SetBreakpoints(parse_content(arguments)?),
+            "setFunctionBreakpoints" => {
+                RequestCommand::SetFunctionBreakpoints(parse_content(arguments)?)
+            }
+            "setExceptionBreakpoints" => {
+                RequestCommand::SetExceptionBreakpoints(parse_content(arguments)?)
+            }
+            "dataBreakpointInfo" => RequestCommand::DataBreakpointInfo(parse_content(arguments)?),
+            "setDataBreakpoints" => RequestCommand::SetDataBreakpoints(parse_content(arguments)?),
+            "setInstructionBreakpoints" => {
+                RequestCommand::SetInstructionBreakpoints(parse_content(arguments)?)
+            }
+            "continue" => RequestCommand::Continue(parse_content(arguments)?),
+            "next" => RequestCommand::Next(parse_content(arguments)?),
+            "stepIn" => RequestCommand::StepIn(parse_content(arguments)?),
+            "stepOut" => RequestCommand::StepOut(parse_content(arguments)?),
+            "stepBack" => RequestCommand::StepBack(parse_content(arguments)?),
+            "reverseContinue" => RequestCommand::ReverseContinue(parse_content(arguments)?),
+            "restartFrame" => RequestCommand::RestartFrame(parse_content(arguments)?),
+            "goto" => RequestCommand::Goto(parse_content(arguments)?),
+            "pause" => RequestCommand::Pause(parse_content(arguments)?),
+            "stackTrace" => RequestCommand::StackTrace(parse_content(arguments)?),
+            "scopes" => RequestCommand::Scopes(parse_content(arguments)?),
+            "variables" => RequestCommand::Variables(parse_content(arguments)?),
+            "setVariable" => RequestCommand::SetVariable(parse_content(arguments)?),
+            "source" => RequestCommand::Source(parse_content(arguments)?),
+            "threads" => RequestCommand::Threads,
+            "terminateThreads" => RequestCommand::TerminateThreads(parse_content(arguments)?),
+            "modules" => RequestCommand::Modules(parse_content(arguments)?),
+            "loadedSources" => RequestCommand::LoadedSources,
+            "evaluate" => RequestCommand::Evaluate(parse_content(arguments)?),
+            "setExpression" => RequestCommand::SetExpression(parse_content(arguments)?),
+            "stepInTargets" => RequestCommand::StepInTargets(parse_content(arguments)?),
+            "gotoTargets" => RequestCommand::GotoTargets(parse_content(arguments)?),
+            "completions" => RequestCommand::Completions(parse_content(arguments)?),
+            "exceptionInfo" => RequestCommand::ExceptionInfo(parse_content(arguments)?),
+            "readMemory" => RequestCommand::ReadMemory(parse_content(arguments)?),
+            "writeMemory" => RequestCommand::WriteMemory(parse_content(arguments)?),
+            "disassemble" => RequestCommand::Disassemble(parse_content(arguments)?),
+            "locations" => RequestCommand::Locations(parse_content(arguments)?),
+            _ => RequestCommand::Unknown { command, arguments },
+        })
+    }
+}
+
+impl RequestCommand {
+    /**
+     * The command to execute.
+     */
+    pub fn command(&self) -> &str {
+        match self {
+            RequestCommand::Cancel(_) => "cancel",
+            RequestCommand::RunInTerminal(_) => "runInTerminal",
+            RequestCommand::StartDebugging(_) => "startDebugging",
+            RequestCommand::Initialize(_) => "initialize",
+            RequestCommand::ConfigurationDone => "configurationDone",
+            RequestCommand::Launch(_) => "launch",
+            RequestCommand::Attach(_) => "attach",
+            RequestCommand::Restart(_) => "restart",
+            RequestCommand::Disconnect(_) => "disconnect",
+            RequestCommand::Terminate(_) => "terminate",
+            RequestCommand::BreakpointLocations(_) => "breakpointLocations",
+            RequestCommand::SetBreakpoints(_) => "setBreakpoints",
+            RequestCommand::SetFunctionBreakpoints(_) => "setFunctionBreakpoints",
+            RequestCommand::SetExceptionBreakpoints(_) => "setExceptionBreakpoints",
+            RequestCommand::DataBreakpointInfo(_) => "dataBreakpointInfo",
+            RequestCommand::SetDataBreakpoints(_) => "setDataBreakpoints",
+            RequestCommand::SetInstructionBreakpoints(_) => "setInstructionBreakpoints",
+            RequestCommand::Continue(_) => "continue",
+            RequestCommand::Next(_) => "next",
+            RequestCommand::StepIn(_) => "stepIn",
+            RequestCommand::StepOut(_) => "stepOut",
+            RequestCommand::StepBack(_) => "stepBack",
+            RequestCommand::ReverseContinue(_) => "reverseContinue",
+            RequestCommand::RestartFrame(_) => "restartFrame",
+            RequestCommand::Goto(_) => "goto",
+            RequestCommand::Pause(_) => "pause",
+            RequestCommand::StackTrace(_) => "stackTrace",
+            RequestCommand::Scopes(_) => "scopes",
+            RequestCommand::Variables(_) => "variables",
+            RequestCommand::SetVariable(_) => "setVariable",
+            RequestCommand::Source(_) => "source",
+            RequestCommand::Threads => "threads",
+            RequestCommand::TerminateThreads(_) => "terminateThreads",
+            RequestCommand::Modules(_) => "modules",
+            RequestCommand::LoadedSources => "loadedSources",
+            RequestCommand::Evaluate(_) => "evaluate",
+            RequestCommand::SetExpression(_) => "setExpression",
+            RequestCommand::StepInTargets(_) => "stepInTargets",
+            RequestCommand::GotoTargets(_) => "gotoTargets",
+            RequestCommand::Completions(_) => "completions",
+            RequestCommand::ExceptionInfo(_) => "exceptionInfo",
+            RequestCommand::ReadMemory(_) => "readMemory",
+            RequestCommand::WriteMemory(_) => "writeMemory",
+            RequestCommand::Disassemble(_) => "disassemble",
+            RequestCommand::Locations(_) => "locations",
+            RequestCommand::Unknown { command, .. } => command,
+        }
+    }
+}
+
+impl_request!(CancelArguments => Cancel, "cancel", ());
+impl_request!(RunInTerminalRequestArguments => RunInTerminal, "runInTerminal", RunInTerminalResponse);
+impl_request!(StartDebuggingRequestArguments => StartDebugging, "startDebugging", ());
+impl_request!(InitializeRequestArguments => Initialize, "initialize", InitializeResponse);
+impl_request!(ConfigurationDoneArguments => ConfigurationDone(), "configurationDone", ());
+impl_request!(LaunchRequestArguments => Launch, "launch", ());
+impl_request!(AttachRequestArguments => Attach, "attach", ());
+impl_request!(RestartArguments => Restart, "restart", ());
+impl_request!(DisconnectArguments => Disconnect, "disconnect", ());
+impl_request!(TerminateArguments => Terminate, "terminate", ());
+impl_request!(BreakpointLocationsArguments => BreakpointLocations, "breakpointLocations", BreakpointLocationsResponse);
+impl_request!(SetBreakpointsArguments => SetBreakpoints, "setBreakpoints", SetBreakpointsResponse);
+impl_request!(SetFunctionBreakpointsArguments => SetFunctionBreakpoints, "setFunctionBreakpoints", SetFunctionBreakpointsResponse);
+impl_request!(SetExceptionBreakpointsArguments => SetExceptionBreakpoints, "setExceptionBreakpoints", SetExceptionBreakpointsResponse);
+impl_request!(DataBreakpointInfoArguments => DataBreakpointInfo, "dataBreakpointInfo", DataBreakpointInfoResponse);
+impl_request!(SetDataBreakpointsArguments => SetDataBreakpoints, "setDataBreakpoints", SetDataBreakpointsResponse);
+impl_request!(SetInstructionBreakpointsArguments => SetInstructionBreakpoints, "setInstructionBreakpoints", SetInstructionBreakpointsResponse);
+impl_request!(ContinueArguments => Continue, "continue", ContinueResponse);
+impl_request!(NextArguments => Next, "next", ());
+impl_request!(StepInArguments => StepIn, "stepIn", ());
+impl_request!(StepOutArguments => StepOut, "stepOut", ());
+impl_request!(StepBackArguments => StepBack, "stepBack", ());
+impl_request!(ReverseContinueArguments => ReverseContinue, "reverseContinue", ());
+impl_request!(RestartFrameArguments => RestartFrame, "restartFrame", ());
+impl_request!(GotoArguments => Goto, "goto", ());
+impl_request!(PauseArguments => Pause, "pause", ());
+impl_request!(StackTraceArguments => StackTrace, "stackTrace", StackTraceResponse);
+impl_request!(ScopesArguments => Scopes, "scopes", ScopesResponse);
+impl_request!(VariablesArguments => Variables, "variables", VariablesResponse);
+impl_request!(SetVariableArguments => SetVariable, "setVariable", SetVariableResponse);
+impl_request!(SourceArguments => Source, "source", SourceResponse);
+impl_request!(ThreadsArguments => Threads(), "threads", ThreadsResponse);
+impl_request!(TerminateThreadsArguments => TerminateThreads, "terminateThreads", ());
+impl_request!(ModulesArguments => Modules, "modules", ModulesResponse);
+impl_request!(LoadedSourcesArguments => LoadedSources(), "loadedSources", LoadedSourcesResponse);
+impl_request!(EvaluateArguments => Evaluate, "evaluate", EvaluateResponse);
+impl_request!(SetExpressionArguments => SetExpression, "setExpression", SetExpressionResponse);
+impl_request!(StepInTargetsArguments => StepInTargets, "stepInTargets", StepInTargetsResponse);
+impl_request!(GotoTargetsArguments => GotoTargets, "gotoTargets", GotoTargetsResponse);
+impl_request!(CompletionsArguments => Completions, "completions", CompletionsResponse);
+impl_request!(ExceptionInfoArguments => ExceptionInfo, "exceptionInfo", ExceptionInfoResponse);
+impl_request!(ReadMemoryArguments => ReadMemory, "readMemory", ReadMemoryResponse);
+impl_request!(WriteMemoryArguments => WriteMemory, "writeMemory", WriteMemoryResponse);
+impl_request!(DisassembleArguments => Disassemble, "disassemble", DisassembleResponse);
+impl_request!(LocationsArguments => Locations, "locations", LocationsResponse);
+
+/**
+ * Arguments for 'cancel' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelArguments {
+    /**
+     * The ID (attribute 'seq') of the request to cancel. If missing no request
+     * is cancelled.
+     * Both a 'requestId' and a 'progressId' can be specified in one request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<i64>,
+
+    /**
+     * The ID (attribute 'progressId') of the progress to cancel. If missing no
+     * progress is cancelled.
+     * Both a 'requestId' and a 'progressId' can be specified in one request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_id: Option<ProgressId>,
+}
+
+impl CancelArguments {
+    pub fn request_id(mut self, request_id: impl Into<i64>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    pub fn progress_id(mut self, progress_id: impl Into<ProgressId>) -> Self {
+        self.progress_id = Some(progress_id.into());
+        self
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TerminalKind {
+    Integrated,
+    External,
+}
+
+/**
+ * Arguments for 'runInTerminal' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunInTerminalRequestArguments {
+    /**
+     * What kind of terminal to launch. Defaults to 'integrated' if not
+     * specified.
+     * Values: 'integrated', 'external'
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<TerminalKind>,
+
+    /**
+     * Title of the terminal.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /**
+     * Working directory for the command. For non-empty, valid paths this
+     * typically results in execution of a change directory command.
+     */
+    pub cwd: String,
+
+    /**
+     * List of arguments. The first argument is the command to run.
+     */
+    pub args: Vec<String>,
+
+    /**
+     * Environment key-value pairs that are added to or removed from the default
+     * environment.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<HashMap<String, Option<String>>>,
+
+    /**
+     * This property should only be set if the corresponding capability
+     * 'supportsArgsCanBeInterpretedByShell' is true. If the client uses an
+     * intermediary shell to launch the application, then the client must not
+     * attempt to escape characters with special meanings for the shell. The
+     * user is fully responsible for escaping as needed and that arguments using
+     * special characters may not be portable across shells.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args_can_be_interpreted_by_shell: Option<bool>,
+}
+
+impl RunInTerminalRequestArguments {
+    pub fn new(cwd: impl Into<String>, args: impl Into<Vec<String>>) -> Self {
+        RunInTerminalRequestArguments {
+            kind: None,
+            title: None,
+            cwd: cwd.into(),
+            args: args.into(),
+            env: None,
+            args_can_be_interpreted_by_shell: None,
+        }
+    }
+
+    pub fn kind(mut self, kind: impl Into<TerminalKind>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn env(mut self, env: impl Into<HashMap<String, Option<String>>>) -> Self {
+        self.env = Some(env.into());
+        self
+    }
+
+    pub fn args_can_be_interpreted_by_shell(
+        mut self,
+        args_can_be_interpreted_by_shell: impl Into<bool>,
+    ) -> Self {
+        self.args_can_be_interpreted_by_shell = Some(args_can_be_interpreted_by_shell.into());
+        self
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StartDebuggingRequestKind {
+    Launch,
+    Attach,
+}
+
+/**
+ * Arguments for 'startDebugging' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartDebuggingRequestArguments {
+    /**
+     * Arguments passed to the new debug session. The arguments must only
+     * contain properties understood by the 'launch' or 'attach' requests of the
+     * debug adapter and they must not contain any client-specific properties
+     * (e.g. 'type') or client-specific features (e.g. substitutable
+     * 'variables').
+     */
+    pub configuration: HashMap<String, Value>,
+
+    /**
+     * Indicates whether the new debug session should be started with a 'launch'
+     * or 'attach' request.
+     * Values: 'launch', 'attach'
+     */
+    pub request: StartDebuggingRequestKind,
+}
+
+impl StartDebuggingRequestArguments {
+    pub fn new(
+        configuration: impl Into<HashMap<String, Value>>,
+        request: impl Into<StartDebuggingRequestKind>,
+    ) -> Self {
+        StartDebuggingRequestArguments {
+            configuration: configuration.into(),
+            request: request.into(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PathFormat {
+    Path,
+    Uri,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * Arguments for 'initialize' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeRequestArguments {
+    /**
+     * The ID of the client using this adapter.
+     */
+    #[serde(rename = "clientID", skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /**
+     * The human-readable name of the client using this adapter.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_name: Option<String>,
+
+    /**
+     * The ID of the debug adapter.
+     */
+    #[serde(rename = "adapterID")]
+    pub adapter_id: String,
+
+    /**
+     * The ISO-639 locale of the client using this adapter, e.g. en-US or de-CH.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    /**
+     * If true all line numbers are 1-based (default).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines_start_at1: Option<bool>,
+
+    /**
+     * If true all column numbers are 1-based (default).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns_start_at1: Option<bool>,
+
+    /**
+     * Determines in what format paths are specified. The default is 'path',
+     * which is the native format.
+     * Values: 'path', 'uri', etc.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_format: Option<PathFormat>,
+
+    /**
+     * Client supports the 'type' attribute for variables.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_variable_type: Option<bool>,
+
+    /**
+     * Client supports the paging of variables.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_variable_paging: Option<bool>,
+
+    /**
+     * Client supports the 'runInTerminal' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_run_in_terminal_request: Option<bool>,
+
+    /**
+     * Client supports memory references.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_memory_references: Option<bool>,
+
+    /**
+     * Client supports progress reporting.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_progress_reporting: Option<bool>,
+
+    /**
+     * Client supports the 'invalidated' event.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_invalidated_event: Option<bool>,
+
+    /**
+     * Client supports the 'memory' event.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_memory_event: Option<bool>,
+
+    /**
+     * Client supports the 'argsCanBeInterpretedByShell' attribute on the
+     * 'runInTerminal' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_args_can_be_interpreted_by_shell: Option<bool>,
+
+    /**
+     * Client supports the 'startDebugging' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_start_debugging_request: Option<bool>,
+
+    /**
+     * The client will interpret ANSI escape sequences in the display of
+     * 'OutputEvent.output' and 'Variable.value' fields when
+     * 'Capabilities.supportsANSIStyling' is also enabled.
+     */
+    #[serde(
+        rename = "supportsANSIStyling",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub supports_ansi_styling: Option<bool>,
+}
+
+impl InitializeRequestArguments {
+    pub fn new(adapter_id: impl Into<String>) -> Self {
+        InitializeRequestArguments {
+            client_id: None,
+            client_name: None,
+            adapter_id: adapter_id.into(),
+            locale: None,
+            lines_start_at1: None,
+            columns_start_at1: None,
+            path_format: None,
+            supports_variable_type: None,
+            supports_variable_paging: None,
+            supports_run_in_terminal_request: None,
+            supports_memory_references: None,
+            supports_progress_reporting: None,
+            supports_invalidated_event: None,
+            supports_memory_event: None,
+            supports_args_can_be_interpreted_by_shell: None,
+            supports_start_debugging_request: None,
+            supports_ansi_styling: None,
+        }
+    }
+
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    pub fn client_name(mut self, client_name: impl Into<String>) -> Self {
+        self.client_name = Some(client_name.into());
+        self
+    }
+
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    pub fn lines_start_at1(mut self, lines_start_at1: impl Into<bool>) -> Self {
+        self.lines_start_at1 = Some(lines_start_at1.into());
+        self
+    }
+
+    pub fn columns_start_at1(mut self, columns_start_at1: impl Into<bool>) -> Self {
+        self.columns_start_at1 = Some(columns_start_at1.into());
+        self
+    }
+
+    pub fn path_format(mut self, path_format: impl Into<PathFormat>) -> Self {
+        self.path_format = Some(path_format.into());
+        self
+    }
+
+    pub fn supports_variable_type(mut self, supports_variable_type: impl Into<bool>) -> Self {
+        self.supports_variable_type = Some(supports_variable_type.into());
+        self
+    }
+
+    pub fn supports_variable_paging(mut self, supports_variable_paging: impl Into<bool>) -> Self {
+        self.supports_variable_paging = Some(supports_variable_paging.into());
+        self
+    }
+
+    pub fn supports_run_in_terminal_request(
+        mut self,
+        supports_run_in_terminal_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_run_in_terminal_request = Some(supports_run_in_terminal_request.into());
+        self
+    }
+
+    pub fn supports_memory_references(
+        mut self,
+        supports_memory_references: impl Into<bool>,
+    ) -> Self {
+        self.supports_memory_references = Some(supports_memory_references.into());
+        self
+    }
+
+    pub fn supports_progress_reporting(
+        mut self,
+        supports_progress_reporting: impl Into<bool>,
+    ) -> Self {
+        self.supports_progress_reporting = Some(supports_progress_reporting.into());
+        self
+    }
+
+    pub fn supports_invalidated_event(
+        mut self,
+        supports_invalidated_event: impl Into<bool>,
+    ) -> Self {
+        self.supports_invalidated_event = Some(supports_invalidated_event.into());
+        self
+    }
+
+    pub fn supports_memory_event(mut self, supports_memory_event: impl Into<bool>) -> Self {
+        self.supports_memory_event = Some(supports_memory_event.into());
+        self
+    }
+
+    pub fn supports_args_can_be_interpreted_by_shell(
+        mut self,
+        supports_args_can_be_interpreted_by_shell: impl Into<bool>,
+    ) -> Self {
+        self.supports_args_can_be_interpreted_by_shell =
+            Some(supports_args_can_be_interpreted_by_shell.into());
+        self
+    }
+
+    pub fn supports_start_debugging_request(
+        mut self,
+        supports_start_debugging_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_start_debugging_request = Some(supports_start_debugging_request.into());
+        self
+    }
+
+    pub fn supports_ansi_styling(mut self, supports_ansi_styling: impl Into<bool>) -> Self {
+        self.supports_ansi_styling = Some(supports_ansi_styling.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'configurationDone' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct ConfigurationDoneArguments {}
+
+/**
+ * Arguments for 'restart' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestartArguments {
+    /**
+     * The latest version of the 'launch' or 'attach' configuration.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<LaunchRequestArguments>,
+}
+
+impl RestartArguments {
+    pub fn arguments(mut self, arguments: impl Into<LaunchRequestArguments>) -> Self {
+        self.arguments = Some(arguments.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'disconnect' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisconnectArguments {
+    /**
+     * A value of true indicates that this 'disconnect' request is part of a
+     * restart sequence.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart: Option<bool>,
+
+    /**
+     * Indicates whether the debuggee should be terminated when the debugger is
+     * disconnected.
+     * If unspecified, the debug adapter is free to do whatever it thinks is
+     * best.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportTerminateDebuggee' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminate_debuggee: Option<bool>,
+
+    /**
+     * Indicates whether the debuggee should stay suspended when the debugger is
+     * disconnected.
+     * If unspecified, the debuggee should resume execution.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportSuspendDebuggee' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspend_debuggee: Option<bool>,
+}
+
+impl DisconnectArguments {
+    pub fn restart(mut self, restart: impl Into<bool>) -> Self {
+        self.restart = Some(restart.into());
+        self
+    }
+
+    pub fn terminate_debuggee(mut self, terminate_debuggee: impl Into<bool>) -> Self {
+        self.terminate_debuggee = Some(terminate_debuggee.into());
+        self
+    }
+
+    pub fn suspend_debuggee(mut self, suspend_debuggee: impl Into<bool>) -> Self {
+        self.suspend_debuggee = Some(suspend_debuggee.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'terminate' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TerminateArguments {
+    /**
+     * A value of true indicates that this 'terminate' request is part of a
+     * restart sequence.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart: Option<bool>,
+}
+
+impl TerminateArguments {
+    pub fn restart(mut self, restart: impl Into<bool>) -> Self {
+        self.restart = Some(restart.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'breakpointLocations' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakpointLocationsArguments {
+    /**
+     * The source location of the breakpoints; either 'source.path' or
+     * 'source.sourceReference' must be specified.
+     */
+    pub source: Source,
+
+    /**
+     * Start line of range to search possible breakpoint locations in. If only
+     * the line is specified, the request returns all possible locations in that
+     * line.
+     */
+    pub line: i64,
+
+    /**
+     * Start position within 'line' to search possible breakpoint locations in.
+     * It is measured in UTF-16 code units and the client capability
+     * 'columnsStartAt1' determines whether it is 0- or 1-based. If no column is
+     * given, the first position in the start line is assumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * End line of range to search possible breakpoint locations in. If no end
+     * line is given, then the end line is assumed to be the start line.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<i64>,
+
+    /**
+     * End position within 'endLine' to search possible breakpoint locations in.
+     * It is measured in UTF-16 code units and the client capability
+     * 'columnsStartAt1' determines whether it is 0- or 1-based. If no end
+     * column is given, the last position in the end line is assumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+}
+
+impl BreakpointLocationsArguments {
+    pub fn new(source: impl Into<Source>, line: impl Into<i64>) -> Self {
+        BreakpointLocationsArguments {
+            source: source.into(),
+            line: line.into(),
+            column: None,
+            end_line: None,
+            end_column: None,
+        }
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn end_line(mut self, end_line: impl Into<i64>) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    pub fn end_column(mut self, end_column: impl Into<i64>) -> Self {
+        self.end_column = Some(end_column.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'setBreakpoints' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetBreakpointsArguments {
+    /**
+     * The source location of the breakpoints; either 'source.path' or
+     * 'source.sourceReference' must be specified.
+     */
+    pub source: Source,
+
+    /**
+     * The code locations of the breakpoints.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breakpoints: Option<Vec<SourceBreakpoint>>,
+
+    /**
+     * Deprecated: The code locations of the breakpoints.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<Vec<i64>>,
+
+    /**
+     * A value of true indicates that the underlying source has been modified
+     * which results in new breakpoint locations.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_modified: Option<bool>,
+}
+
+impl SetBreakpointsArguments {
+    pub fn new(source: impl Into<Source>) -> Self {
+        SetBreakpointsArguments {
+            source: source.into(),
+            breakpoints: None,
+            lines: None,
+            source_modified: None,
+        }
+    }
+
+    pub fn breakpoints(mut self, breakpoints: impl Into<Vec<SourceBreakpoint>>) -> Self {
+        self.breakpoints = Some(breakpoints.into());
+        self
+    }
+
+    pub fn lines(mut self, lines: impl Into<Vec<i64>>) -> Self {
+        self.lines = Some(lines.into());
+        self
+    }
+
+    pub fn source_modified(mut self, source_modified: impl Into<bool>) -> Self {
+        self.source_modified = Some(source_modified.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'setFunctionBreakpoints' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetFunctionBreakpointsArguments {
+    /**
+     * The function names of the breakpoints.
+     */
+    pub breakpoints: Vec<FunctionBreakpoint>,
+}
+
+impl SetFunctionBreakpointsArguments {
+    pub fn new(breakpoints: impl Into<Vec<FunctionBreakpoint>>) -> Self {
+        SetFunctionBreakpointsArguments {
+            breakpoints: breakpoints.into(),
+        }
+    }
+}
+
+/**
+ * Arguments for 'setExceptionBreakpoints' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetExceptionBreakpointsArguments {
+    /**
+     * Set of exception filters specified by their ID. The set of all possible
+     * exception filters is defined by the 'exceptionBreakpointFilters'
+     * capability. The 'filter' and 'filterOptions' sets are additive.
+     */
+    pub filters: Vec<String>,
+
+    /**
+     * Set of exception filters and their options. The set of all possible
+     * exception filters is defined by the 'exceptionBreakpointFilters'
+     * capability. This attribute is only honored by a debug adapter if the
+     * corresponding capability 'supportsExceptionFilterOptions' is true. The
+     * 'filter' and 'filterOptions' sets are additive.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_options: Option<Vec<ExceptionFilterOptions>>,
+
+    /**
+     * Configuration options for selected exceptions.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsExceptionOptions' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exception_options: Option<Vec<ExceptionOptions>>,
+}
+
+impl SetExceptionBreakpointsArguments {
+    pub fn new(filters: impl Into<Vec<String>>) -> Self {
+        SetExceptionBreakpointsArguments {
+            filters: filters.into(),
+            filter_options: None,
+            exception_options: None,
+        }
+    }
+
+    pub fn filter_options(
+        mut self,
+        filter_options: impl Into<Vec<ExceptionFilterOptions>>,
+    ) -> Self {
+        self.filter_options = Some(filter_options.into());
+        self
+    }
+
+    pub fn exception_options(
+        mut self,
+        exception_options: impl Into<Vec<ExceptionOptions>>,
+    ) -> Self {
+        self.exception_options = Some(exception_options.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'dataBreakpointInfo' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataBreakpointInfoArguments {
+    /**
+     * Reference to the variable container if the data breakpoint is requested
+     * for a child of the container. The 'variablesReference' must have been
+     * obtained in the current suspended state. See 'Lifetime of Object
+     * References' in the Overview section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables_reference: Option<VariablesReference>,
+
+    /**
+     * The name of the variable's child to obtain data breakpoint information
+     * for.
+     * If 'variablesReference' isn't specified, this can be an expression, or an
+     * address if 'asAddress' is also true.
+     */
+    pub name: String,
+
+    /**
+     * When 'name' is an expression, evaluate it in the scope of this stack
+     * frame. If not specified, the expression is evaluated in the global scope.
+     * When 'variablesReference' is specified, this property has no effect.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frame_id: Option<FrameId>,
+
+    /**
+     * If specified, a debug adapter should return information for the range of
+     * memory extending 'bytes' number of bytes from the address or variable
+     * specified by 'name'. Breakpoints set using the resulting data ID should
+     * pause on data access anywhere within that range.
+     *
+     * Clients may set this property only if the 'supportsDataBreakpointBytes'
+     * capability is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<i64>,
+
+    /**
+     * If 'true', the 'name' is a memory address and the debugger should
+     * interpret it as a decimal value, or hex value if it is prefixed with
+     * '0x'.
+     *
+     * Clients may set this property only if the 'supportsDataBreakpointBytes'
+     * capability is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as_address: Option<bool>,
+
+    /**
+     * The mode of the desired breakpoint. If defined, this must be one of the
+     * 'breakpointModes' the debug adapter advertised in its 'Capabilities'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+impl DataBreakpointInfoArguments {
+    pub fn new(name: impl Into<String>) -> Self {
+        DataBreakpointInfoArguments {
+            variables_reference: None,
+            name: name.into(),
+            frame_id: None,
+            bytes: None,
+            as_address: None,
+            mode: None,
+        }
+    }
+
+    pub fn variables_reference(
+        mut self,
+        variables_reference: impl Into<VariablesReference>,
+    ) -> Self {
+        self.variables_reference = Some(variables_reference.into());
+        self
+    }
+
+    pub fn frame_id(mut self, frame_id: impl Into<FrameId>) -> Self {
+        self.frame_id = Some(frame_id.into());
+        self
+    }
+
+    pub fn bytes(mut self, bytes: impl Into<i64>) -> Self {
+        self.bytes = Some(bytes.into());
+        self
+    }
+
+    pub fn as_address(mut self, as_address: impl Into<bool>) -> Self {
+        self.as_address = Some(as_address.into());
+        self
+    }
+
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'setDataBreakpoints' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDataBreakpointsArguments {
+    /**
+     * The contents of this array replaces all existing data breakpoints. An
+     * empty array clears all data breakpoints.
+     */
+    pub breakpoints: Vec<DataBreakpoint>,
+}
+
+impl SetDataBreakpointsArguments {
+    pub fn new(breakpoints: impl Into<Vec<DataBreakpoint>>) -> Self {
+        SetDataBreakpointsArguments {
+            breakpoints: breakpoints.into(),
+        }
+    }
+}
+
+/**
+ * Arguments for 'setInstructionBreakpoints' request
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetInstructionBreakpointsArguments {
+    /**
+     * The instruction references of the breakpoints
+     */
+    pub breakpoints: Vec<InstructionBreakpoint>,
+}
+
+impl SetInstructionBreakpointsArguments {
+    pub fn new(breakpoints: impl Into<Vec<InstructionBreakpoint>>) -> Self {
+        SetInstructionBreakpointsArguments {
+            breakpoints: breakpoints.into(),
+        }
+    }
+}
+
+/**
+ * Arguments for 'continue' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinueArguments {
+    /**
+     * Specifies the active thread. If the debug adapter supports single thread
+     * execution (see 'supportsSingleThreadExecutionRequests') and the argument
+     * 'singleThread' is true, only the thread with this ID is resumed.
+     */
+    pub thread_id: ThreadId,
+
+    /**
+     * If this flag is true, execution is resumed only for the thread with given
+     * 'threadId'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_thread: Option<bool>,
+}
+
+impl ContinueArguments {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        ContinueArguments {
+            thread_id: thread_id.into(),
+            single_thread: None,
+        }
+    }
+
+    pub fn single_thread(mut self, single_thread: impl Into<bool>) -> Self {
+        self.single_thread = Some(single_thread.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'next' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NextArguments {
+    /**
+     * Specifies the thread for which to resume execution for one step (of the
+     * given granularity).
+     */
+    pub thread_id: ThreadId,
+
+    /**
+     * If this flag is true, all other suspended threads are not resumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_thread: Option<bool>,
+
+    /**
+     * Stepping granularity. If no granularity is specified, a granularity of
+     * 'statement' is assumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<SteppingGranularity>,
+}
+
+impl NextArguments {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        NextArguments {
+            thread_id: thread_id.into(),
+            single_thread: None,
+            granularity: None,
+        }
+    }
+
+    pub fn single_thread(mut self, single_thread: impl Into<bool>) -> Self {
+        self.single_thread = Some(single_thread.into());
+        self
+    }
+
+    pub fn granularity(mut self, granularity: impl Into<SteppingGranularity>) -> Self {
+        self.granularity = Some(granularity.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'stepIn' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepInArguments {
+    /**
+     * Specifies the thread for which to resume execution for one step-into (of
+     * the given granularity).
+     */
+    pub thread_id: ThreadId,
+
+    /**
+     * If this flag is true, all other suspended threads are not resumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_thread: Option<bool>,
+
+    /**
+     * Id of the target to step into.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<i64>,
+
+    /**
+     * Stepping granularity. If no granularity is specified, a granularity of
+     * 'statement' is assumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<SteppingGranularity>,
+}
+
+impl StepInArguments {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        StepInArguments {
+            thread_id: thread_id.into(),
+            single_thread: None,
+            target_id: None,
+            granularity: None,
+        }
+    }
+
+    pub fn single_thread(mut self, single_thread: impl Into<bool>) -> Self {
+        self.single_thread = Some(single_thread.into());
+        self
+    }
+
+    pub fn target_id(mut self, target_id: impl Into<i64>) -> Self {
+        self.target_id = Some(target_id.into());
+        self
+    }
+
+    pub fn granularity(mut self, granularity: impl Into<SteppingGranularity>) -> Self {
+        self.granularity = Some(granularity.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'stepOut' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepOutArguments {
+    /**
+     * Specifies the thread for which to resume execution for one step-out (of
+     * the given granularity).
+     */
+    pub thread_id: ThreadId,
+
+    /**
+     * If this flag is true, all other suspended threads are not resumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_thread: Option<bool>,
+
+    /**
+     * Stepping granularity. If no granularity is specified, a granularity of
+     * 'statement' is assumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<SteppingGranularity>,
+}
+
+impl StepOutArguments {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        StepOutArguments {
+            thread_id: thread_id.into(),
+            single_thread: None,
+            granularity: None,
+        }
+    }
+
+    pub fn single_thread(mut self, single_thread: impl Into<bool>) -> Self {
+        self.single_thread = Some(single_thread.into());
+        self
+    }
+
+    pub fn granularity(mut self, granularity: impl Into<SteppingGranularity>) -> Self {
+        self.granularity = Some(granularity.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'stepBack' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepBackArguments {
+    /**
+     * Specifies the thread for which to resume execution for one step backwards
+     * (of the given granularity).
+     */
+    pub thread_id: ThreadId,
+
+    /**
+     * If this flag is true, all other suspended threads are not resumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_thread: Option<bool>,
+
+    /**
+     * Stepping granularity to step. If no granularity is specified, a
+     * granularity of 'statement' is assumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<SteppingGranularity>,
+}
+
+impl StepBackArguments {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        StepBackArguments {
+            thread_id: thread_id.into(),
+            single_thread: None,
+            granularity: None,
+        }
+    }
+
+    pub fn single_thread(mut self, single_thread: impl Into<bool>) -> Self {
+        self.single_thread = Some(single_thread.into());
+        self
+    }
+
+    pub fn granularity(mut self, granularity: impl Into<SteppingGranularity>) -> Self {
+        self.granularity = Some(granularity.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'reverseContinue' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReverseContinueArguments {
+    /**
+     * Specifies the active thread. If the debug adapter supports single thread
+     * execution (see 'supportsSingleThreadExecutionRequests') and the
+     * 'singleThread' argument is true, only the thread with this ID is resumed.
+     */
+    pub thread_id: ThreadId,
+
+    /**
+     * If this flag is true, backward execution is resumed only for the thread
+     * with given 'threadId'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_thread: Option<bool>,
+}
+
+impl ReverseContinueArguments {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        ReverseContinueArguments {
+            thread_id: thread_id.into(),
+            single_thread: None,
+        }
+    }
+
+    pub fn single_thread(mut self, single_thread: impl Into<bool>) -> Self {
+        self.single_thread = Some(single_thread.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'restartFrame' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestartFrameArguments {
+    /**
+     * Restart the stack frame identified by 'frameId'. The 'frameId' must have
+     * been obtained in the current suspended state. See 'Lifetime of Object
+     * References' in the Overview section for details.
+     */
+    pub frame_id: FrameId,
+}
+
+impl RestartFrameArguments {
+    pub fn new(frame_id: impl Into<FrameId>) -> Self {
+        RestartFrameArguments {
+            frame_id: frame_id.into(),
+        }
+    }
+}
+
+/**
+ * Arguments for 'goto' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GotoArguments {
+    /**
+     * Set the goto target for this thread.
+     */
+    pub thread_id: ThreadId,
+
+    /**
+     * The location where the debuggee will continue to run.
+     */
+    pub target_id: i64,
+}
+
+impl GotoArguments {
+    pub fn new(thread_id: impl Into<ThreadId>, target_id: impl Into<i64>) -> Self {
+        GotoArguments {
+            thread_id: thread_id.into(),
+            target_id: target_id.into(),
+        }
+    }
+}
+
+/**
+ * Arguments for 'pause' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PauseArguments {
+    /**
+     * Pause execution for this thread.
+     */
+    pub thread_id: ThreadId,
+}
+
+impl PauseArguments {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        PauseArguments {
+            thread_id: thread_id.into(),
+        }
+    }
+}
+
+/**
+ * Arguments for 'stackTrace' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StackTraceArguments {
+    /**
+     * Retrieve the stacktrace for this thread.
+     */
+    pub thread_id: ThreadId,
+
+    /**
+     * The index of the first frame to return; if omitted frames start at 0.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_frame: Option<i64>,
+
+    /**
+     * The maximum number of frames to return. If levels is not specified or 0,
+     * all frames are returned.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub levels: Option<i64>,
+
+    /**
+     * Specifies details on how to format the stack frames.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsValueFormattingOptions' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<StackFrameFormat>,
+}
+
+impl StackTraceArguments {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        StackTraceArguments {
+            thread_id: thread_id.into(),
+            start_frame: None,
+            levels: None,
+            format: None,
+        }
+    }
+
+    pub fn start_frame(mut self, start_frame: impl Into<i64>) -> Self {
+        self.start_frame = Some(start_frame.into());
+        self
+    }
+
+    pub fn levels(mut self, levels: impl Into<i64>) -> Self {
+        self.levels = Some(levels.into());
+        self
+    }
+
+    pub fn format(mut self, format: impl Into<StackFrameFormat>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'scopes' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopesArguments {
+    /**
+     * Retrieve the scopes for the stack frame identified by 'frameId'. The
+     * 'frameId' must have been obtained in the current suspended state. See
+     * 'Lifetime of Object References' in the Overview section for details.
+     */
+    pub frame_id: FrameId,
+}
+
+impl ScopesArguments {
+    pub fn new(frame_id: impl Into<FrameId>) -> Self {
+        ScopesArguments {
+            frame_id: frame_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VariableFilter {
+    Indexed,
+    Named,
+}
+
+/**
+ * Arguments for 'variables' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariablesArguments {
+    /**
+     * The variable for which to retrieve its children. The 'variablesReference'
+     * must have been obtained in the current suspended state. See 'Lifetime of
+     * Object References' in the Overview section for details.
+     */
+    pub variables_reference: VariablesReference,
+
+    /**
+     * Filter to limit the child variables to either named or indexed. If
+     * omitted, both types are fetched.
+     * Values: 'indexed', 'named'
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<VariableFilter>,
+
+    /**
+     * The index of the first variable to return; if omitted children start at
+     * 0.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsVariablePaging' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<i64>,
+
+    /**
+     * The number of variables to return. If count is missing or 0, all
+     * variables are returned.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsVariablePaging' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+
+    /**
+     * Specifies details on how to format the Variable values.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsValueFormattingOptions' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<ValueFormat>,
+}
+
+impl VariablesArguments {
+    pub fn new(variables_reference: impl Into<VariablesReference>) -> Self {
+        VariablesArguments {
+            variables_reference: variables_reference.into(),
+            filter: None,
+            start: None,
+            count: None,
+            format: None,
+        }
+    }
+
+    pub fn filter(mut self, filter: impl Into<VariableFilter>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    pub fn start(mut self, start: impl Into<i64>) -> Self {
+        self.start = Some(start.into());
+        self
+    }
+
+    pub fn count(mut self, count: impl Into<i64>) -> Self {
+        self.count = Some(count.into());
+        self
+    }
+
+    pub fn format(mut self, format: impl Into<ValueFormat>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'setVariable' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetVariableArguments {
+    /**
+     * The reference of the variable container. The 'variablesReference' must
+     * have been obtained in the current suspended state. See 'Lifetime of
+     * Object References' in the Overview section for details.
+     */
+    pub variables_reference: VariablesReference,
+
+    /**
+     * The name of the variable in the container.
+     */
+    pub name: String,
+
+    /**
+     * The value of the variable.
+     */
+    pub value: String,
+
+    /**
+     * Specifies details on how to format the response value.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<ValueFormat>,
+}
+
+impl SetVariableArguments {
+    pub fn new(
+        variables_reference: impl Into<VariablesReference>,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        SetVariableArguments {
+            variables_reference: variables_reference.into(),
+            name: name.into(),
+            value: value.into(),
+            format: None,
+        }
+    }
+
+    pub fn format(mut self, format: impl Into<ValueFormat>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'source' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceArguments {
+    /**
+     * Specifies the source content to load. Either 'source.path' or
+     * 'source.sourceReference' must be specified.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+
+    /**
+     * The reference to the source. This is the same as
+     * 'source.sourceReference'.
+     * This is provided for backward compatibility since old clients do not
+     * understand the 'source' attribute.
+     */
+    pub source_reference: SourceReference,
+}
+
+impl SourceArguments {
+    pub fn new(source_reference: impl Into<SourceReference>) -> Self {
+        SourceArguments {
+            source: None,
+            source_reference: source_reference.into(),
+        }
+    }
+
+    pub fn source(mut self, source: impl Into<Source>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+}
+
+/**
+ * The 'threads' request has no arguments.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct ThreadsArguments {}
+
+/**
+ * Arguments for 'terminateThreads' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TerminateThreadsArguments {
+    /**
+     * Ids of threads to be terminated.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ids: Option<Vec<ThreadId>>,
+}
+
+impl TerminateThreadsArguments {
+    pub fn thread_ids(mut self, thread_ids: impl Into<Vec<ThreadId>>) -> Self {
+        self.thread_ids = Some(thread_ids.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'modules' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModulesArguments {
+    /**
+     * The index of the first module to return; if omitted modules start at 0.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_module: Option<i64>,
+
+    /**
+     * The number of modules to return. If 'moduleCount' is not specified or 0,
+     * all modules are returned.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_count: Option<i64>,
+}
+
+impl ModulesArguments {
+    pub fn start_module(mut self, start_module: impl Into<i64>) -> Self {
+        self.start_module = Some(start_module.into());
+        self
+    }
+
+    pub fn module_count(mut self, module_count: impl Into<i64>) -> Self {
+        self.module_count = Some(module_count.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'loadedSources' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct LoadedSourcesArguments {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EvalContext {
+    Watch,
+    Repl,
+    Hover,
+    Clipboard,
+    Variables,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * Arguments for 'evaluate' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluateArguments {
+    /**
+     * The expression to evaluate.
+     */
+    pub expression: String,
+
+    /**
+     * Evaluate the expression in the scope of this stack frame. If not
+     * specified, the expression is evaluated in the global scope.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frame_id: Option<FrameId>,
+
+    /**
+     * The contextual line where the expression should be evaluated. In the
+     * 'hover' context, this should be set to the start of the expression being
+     * hovered.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<i64>,
+
+    /**
+     * The contextual column where the expression should be evaluated. This may
+     * be provided if 'line' is also provided.
+     *
+     * It is measured in UTF-16 code units and the client capability
+     * 'columnsStartAt1' determines whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * The contextual source in which the 'line' is found. This must be provided
+     * if 'line' is provided.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+
+    /**
+     * The context in which the evaluate request is used.
+     * Values:
+     * 'watch': evaluate is called from a watch view context.
+     * 'repl': evaluate is called from a REPL context.
+     * 'hover': evaluate is called to generate the debug hover contents.
+     * This value should only be used if the corresponding capability
+     * 'supportsEvaluateForHovers' is true.
+     * 'clipboard': evaluate is called to generate clipboard contents.
+     * This value should only be used if the corresponding capability
+     * 'supportsClipboardContext' is true.
+     * 'variables': evaluate is called from a variables view context.
+     * etc.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<EvalContext>,
+
+    /**
+     * Specifies details on how to format the result.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsValueFormattingOptions' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<ValueFormat>,
+}
+
+impl EvaluateArguments {
+    pub fn new(expression: impl Into<String>) -> Self {
+        EvaluateArguments {
+            expression: expression.into(),
+            frame_id: None,
+            line: None,
+            column: None,
+            source: None,
+            context: None,
+            format: None,
+        }
+    }
+
+    pub fn frame_id(mut self, frame_id: impl Into<FrameId>) -> Self {
+        self.frame_id = Some(frame_id.into());
+        self
+    }
+
+    pub fn line(mut self, line: impl Into<i64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<Source>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn context(mut self, context: impl Into<EvalContext>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    pub fn format(mut self, format: impl Into<ValueFormat>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'setExpression' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetExpressionArguments {
+    /**
+     * The l-value expression to assign to.
+     */
+    pub expression: String,
+
+    /**
+     * The value expression to assign to the l-value expression.
+     */
+    pub value: String,
+
+    /**
+     * Evaluate the expressions in the scope of this stack frame. If not
+     * specified, the expressions are evaluated in the global scope.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frame_id: Option<FrameId>,
+
+    /**
+     * Specifies how the resulting value should be formatted.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<ValueFormat>,
+}
+
+impl SetExpressionArguments {
+    pub fn new(expression: impl Into<String>, value: impl Into<String>) -> Self {
+        SetExpressionArguments {
+            expression: expression.into(),
+            value: value.into(),
+            frame_id: None,
+            format: None,
+        }
+    }
+
+    pub fn frame_id(mut self, frame_id: impl Into<FrameId>) -> Self {
+        self.frame_id = Some(frame_id.into());
+        self
+    }
+
+    pub fn format(mut self, format: impl Into<ValueFormat>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'stepInTargets' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepInTargetsArguments {
+    /**
+     * The stack frame for which to retrieve the possible step-in targets.
+     */
+    pub frame_id: FrameId,
+}
+
+impl StepInTargetsArguments {
+    pub fn new(frame_id: impl Into<FrameId>) -> Self {
+        StepInTargetsArguments {
+            frame_id: frame_id.into(),
+        }
+    }
+}
+
+/**
+ * Arguments for 'gotoTargets' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GotoTargetsArguments {
+    /**
+     * The source location for which the goto targets are determined.
+     */
+    pub source: Source,
+
+    /**
+     * The line location for which the goto targets are determined.
+     */
+    pub line: i64,
+
+    /**
+     * The position within 'line' for which the goto targets are determined. It
+     * is measured in UTF-16 code units and the client capability
+     * 'columnsStartAt1' determines whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+}
+
+impl GotoTargetsArguments {
+    pub fn new(source: impl Into<Source>, line: impl Into<i64>) -> Self {
+        GotoTargetsArguments {
+            source: source.into(),
+            line: line.into(),
+            column: None,
+        }
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'completions' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionsArguments {
+    /**
+     * Returns completions in the scope of this stack frame. If not specified,
+     * the completions are returned for the global scope.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frame_id: Option<FrameId>,
+
+    /**
+     * One or more source lines. Typically this is the text users have typed
+     * into the debug console before they asked for completion.
+     */
+    pub text: String,
+
+    /**
+     * The position within 'text' for which to determine the completion
+     * proposals. It is measured in UTF-16 code units and the client capability
+     * 'columnsStartAt1' determines whether it is 0- or 1-based.
+     */
+    pub column: i64,
+
+    /**
+     * A line for which to determine the completion proposals. If missing the
+     * first line of the text is assumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<i64>,
+}
+
+impl CompletionsArguments {
+    pub fn new(text: impl Into<String>, column: impl Into<i64>) -> Self {
+        CompletionsArguments {
+            frame_id: None,
+            text: text.into(),
+            column: column.into(),
+            line: None,
+        }
+    }
+
+    pub fn frame_id(mut self, frame_id: impl Into<FrameId>) -> Self {
+        self.frame_id = Some(frame_id.into());
+        self
+    }
+
+    pub fn line(mut self, line: impl Into<i64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'exceptionInfo' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExceptionInfoArguments {
+    /**
+     * Thread for which exception information should be retrieved.
+     */
+    pub thread_id: ThreadId,
+}
+
+impl ExceptionInfoArguments {
+    pub fn new(thread_id: impl Into<ThreadId>) -> Self {
+        ExceptionInfoArguments {
+            thread_id: thread_id.into(),
+        }
+    }
+}
+
+/**
+ * Arguments for 'readMemory' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadMemoryArguments {
+    /**
+     * Memory reference to the base location from which data should be read.
+     */
+    pub memory_reference: MemoryReference,
+
+    /**
+     * Offset (in bytes) to be applied to the reference location before reading
+     * data. Can be negative.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+
+    /**
+     * Number of bytes to read at the specified location and offset.
+     */
+    pub count: i64,
+}
+
+impl ReadMemoryArguments {
+    pub fn new(memory_reference: impl Into<MemoryReference>, count: impl Into<i64>) -> Self {
+        ReadMemoryArguments {
+            memory_reference: memory_reference.into(),
+            offset: None,
+            count: count.into(),
+        }
+    }
+
+    pub fn offset(mut self, offset: impl Into<i64>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'writeMemory' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteMemoryArguments {
+    /**
+     * Memory reference to the base location to which data should be written.
+     */
+    pub memory_reference: MemoryReference,
+
+    /**
+     * Offset (in bytes) to be applied to the reference location before writing
+     * data. Can be negative.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+
+    /**
+     * Property to control partial writes. If true, the debug adapter should
+     * attempt to write memory even if the entire memory region is not writable.
+     * In such a case the debug adapter should stop after hitting the first byte
+     * of memory that cannot be written and return the number of bytes written
+     * in the response via the 'offset' and 'bytesWritten' properties.
+     * If false or missing, a debug adapter should attempt to verify the region
+     * is writable before writing, and fail the response if it is not.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_partial: Option<bool>,
+
+    /**
+     * Bytes to write, encoded using base64.
+     */
+    pub data: String,
+}
+
+impl WriteMemoryArguments {
+    pub fn new(memory_reference: impl Into<MemoryReference>, data: impl Into<String>) -> Self {
+        WriteMemoryArguments {
+            memory_reference: memory_reference.into(),
+            offset: None,
+            allow_partial: None,
+            data: data.into(),
+        }
+    }
+
+    pub fn offset(mut self, offset: impl Into<i64>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn allow_partial(mut self, allow_partial: impl Into<bool>) -> Self {
+        self.allow_partial = Some(allow_partial.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'disassemble' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisassembleArguments {
+    /**
+     * Memory reference to the base location containing the instructions to
+     * disassemble.
+     */
+    pub memory_reference: MemoryReference,
+
+    /**
+     * Offset (in bytes) to be applied to the reference location before
+     * disassembling. Can be negative.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+
+    /**
+     * Offset (in instructions) to be applied after the byte offset (if any)
+     * before disassembling. Can be negative.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instruction_offset: Option<i64>,
+
+    /**
+     * Number of instructions to disassemble starting at the specified location
+     * and offset.
+     * An adapter must return exactly this number of instructions - any
+     * unavailable instructions should be replaced with an
+     * implementation-defined 'invalid instruction' value.
+     */
+    pub instruction_count: i64,
+
+    /**
+     * If true, the adapter should attempt to resolve memory addresses and other
+     * values to symbolic names.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolve_symbols: Option<bool>,
+}
+
+impl DisassembleArguments {
+    pub fn new(
+        memory_reference: impl Into<MemoryReference>,
+        instruction_count: impl Into<i64>,
+    ) -> Self {
+        DisassembleArguments {
+            memory_reference: memory_reference.into(),
+            offset: None,
+            instruction_offset: None,
+            instruction_count: instruction_count.into(),
+            resolve_symbols: None,
+        }
+    }
+
+    pub fn offset(mut self, offset: impl Into<i64>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn instruction_offset(mut self, instruction_offset: impl Into<i64>) -> Self {
+        self.instruction_offset = Some(instruction_offset.into());
+        self
+    }
+
+    pub fn resolve_symbols(mut self, resolve_symbols: impl Into<bool>) -> Self {
+        self.resolve_symbols = Some(resolve_symbols.into());
+        self
+    }
+}
+
+/**
+ * Arguments for 'locations' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationsArguments {
+    /**
+     * Location reference to resolve.
+     */
+    pub location_reference: i64,
+}
+
+impl LocationsArguments {
+    pub fn new(location_reference: impl Into<i64>) -> Self {
+        LocationsArguments {
+            location_reference: location_reference.into(),
+        }
+    }
+}
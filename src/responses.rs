@@ -1,532 +1,256 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::convert::TryFrom;
 
-use crate::types::*;
+use crate::requests::Request;
+use crate::types::Message;
 
 #[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(try_from = "RawResponse")]
 pub struct Response {
     pub request_seq: i64,
-    pub success: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", flatten)]
-    pub body: Option<ResponseBody>,
+    pub message: Option<ResponseMessage>,
+    #[serde(flatten, serialize_with = "serialize_result")]
+    pub result: Result<ResponseBody, ErrorResponse>,
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase", tag = "command", content = "body")]
-pub enum ResponseBody {
-    Initialize(InitializeResponse),
-    ConfigurationDone,
-    Launch,
-    Attach,
-    Restart,
-    Disconnect,
-    Terminate,
-    BreakpointLocations(BreakpointLocationsResponse),
-    SetBreakpoints(SetBreakpointsResponse),
-    SetFunctionBreakpoints(SetFunctionBreakpointsResponse),
-    SetExceptionBreakpoints(SetExceptionBreakpointsResponse),
-    DataBreakpointInfo(DataBreakpointInfoResponse),
-    SetInstructionBreakpoints(SetInstructionBreakpointsResponse),
-    Continue(ContinueResponse),
-    Next,
-    StepIn,
-    StepOut,
-    StepBack,
-    ReverseContinue,
-    RestartFrame,
-    Goto,
-    Pause,
-    StackTrace(StackTraceResponse),
-    Scopes(ScopesResponse),
-    Variables(VariablesResponse),
-    SetVariable(SetVariableResponse),
-    Source(SourceResponse),
-    Threads(ThreadsResponse),
-    TerminateThreads,
-    Modules(ModulesResponse),
-    LoadedSources(LoadedSourcesResponse),
-    Evaluate(EvaluateResponse),
-    SetExpression(SetExpressionResponse),
-    StepInTargets(StepInTargetsResponse),
-    GotoTargets(GotoTargetsResponse),
-    Completions(CompletionsResponse),
-    ExceptionInfo(ExceptionInfoResponse),
-    ReadMemory(ReadMemoryResponse),
-    WriteMemory(WriteMemoryResponse),
-    Disassemble(DisassembleResponse),
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct InitializeResponse {
+impl Response {
     /**
-     * The capabilities of this debug adapter.
+     * A successful response to the request with the given sequence number.
      */
-    pub capabilities: Capabilities,
-}
+    pub fn success(request_seq: i64, body: impl Into<ResponseBody>) -> Self {
+        Response {
+            request_seq,
+            message: None,
+            result: Ok(body.into()),
+        }
+    }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct BreakpointLocationsResponse {
-    /**
-     * Sorted set of possible breakpoint locations.
-     */
-    pub breakpoints: Vec<BreakpointLocation>,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SetBreakpointsResponse {
-    /**
-     * Information about the breakpoints.
-     * The array elements are in the same order as the elements of the
-     * 'breakpoints' (or the deprecated 'lines') array in the arguments.
-     */
-    pub breakpoints: Vec<Breakpoint>,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SetFunctionBreakpointsResponse {
-    /**
-     * Information about the breakpoints. The array elements correspond to the
-     * elements of the 'breakpoints' array.
-     */
-    pub breakpoints: Vec<Breakpoint>,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SetExceptionBreakpointsResponse {
     /**
-     * Information about the exception breakpoints or filters.
-     * The breakpoints returned are in the same order as the elements of the
-     * 'filters', 'filterOptions', 'exceptionOptions' arrays in the arguments.
-     * If both 'filters' and 'filterOptions' are given, the returned array must
-     * start with 'filters' information first, followed by 'filterOptions'
-     * information.
+     * A failed response to the request with the given sequence number and
+     * command.
      */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub breakpoints: Option<Vec<Breakpoint>>,
-}
+    pub fn error(request_seq: i64, error: ErrorResponse) -> Self {
+        Response {
+            request_seq,
+            message: None,
+            result: Err(error),
+        }
+    }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct DataBreakpointInfoResponse {
     /**
-     * An identifier for the data on which a data breakpoint can be registered
-     * with the setDataBreakpoints request or null if no data breakpoint is
-     * available.
+     * Adds a short, machine readable reason, mostly for failed responses.
      */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_id: Option<String>,
+    pub fn message(mut self, message: ResponseMessage) -> Self {
+        self.message = Some(message);
+        self
+    }
 
     /**
-     * UI String that describes on what data the breakpoint is set on or why a
-     * data breakpoint is not available.
+     * Outcome of the request. This is the 'success' attribute on the wire.
      */
-    pub description: String,
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
 
     /**
-     * Optional attribute listing the available access types for a potential
-     * data breakpoint. A UI frontend could surface this information.
+     * The command requested, for both successful and failed responses.
      */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_types: Option<Vec<DataBreakpointAccessType>>,
+    pub fn command(&self) -> &str {
+        match &self.result {
+            Ok(body) => body.command(),
+            Err(error) => &error.command,
+        }
+    }
 
     /**
-     * Optional attribute indicating that a potential data breakpoint could be
-     * persisted across sessions.
+     * Extracts the body of a successful response to a request with arguments of
+     * type `R`. Returns `None` if the request failed or the response belongs to
+     * a different command.
      */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub can_persist: Option<bool>,
-}
+    pub fn into_body<R: Request>(self) -> Option<R::Response> {
+        R::from_response_body(self.result.ok()?).ok()
+    }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SetDataBreakpointsResponse {
     /**
-     * Information about the data breakpoints. The array elements correspond to
-     * the elements of the input argument 'breakpoints' array.
+     * Returns true if this is the response to a request that was cancelled by a
+     * 'cancel' request.
      */
-    pub breakpoints: Vec<Breakpoint>,
+    pub fn is_cancelled(&self) -> bool {
+        self.result.is_err() && self.message == Some(ResponseMessage::Cancelled)
+    }
 }
 
+/**
+ * The body of a failed response, along with the command that failed.
+ */
 #[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetInstructionBreakpointsResponse {
+pub struct ErrorResponse {
     /**
-     * Information about the breakpoints. The array elements correspond to the
-     * elements of the 'breakpoints' array.
+     * The command requested.
      */
-    pub breakpoints: Vec<Breakpoint>,
-}
+    pub command: String,
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ContinueResponse {
     /**
-     * The value true (or a missing property) signals to the client that all
-     * threads have been resumed. The value false must be returned if not all
-     * threads were resumed.
+     * An optional, structured error message.
      */
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub all_threads_continued: Option<bool>,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct StackTraceResponse {
-    /**
-     * The frames of the stackframe. If the array has length zero, there are no
-     * stackframes available.
-     * This means that there is no location information available.
-     */
-    pub stack_frames: Vec<StackFrame>,
+    pub error: Option<Message>,
 
     /**
-     * The total number of frames available in the stack. If omitted or if
-     * totalFrames is larger than the available frames, a client is expected to
-     * request frames until a request returns less frames than requested (which
-     * indicates the end of the stack). Returning monotonically increasing
-     * totalFrames values for subsequent requests can be used to enforce paging
-     * in the client.
+     * The body as received, if it holds anything but a structured error
+     * message. It is sent back unchanged.
      */
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_frames: Option<i32>,
+    pub body: Option<Value>,
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ScopesResponse {
-    /**
-     * The scopes of the stackframe. If the array has length zero, there are no
-     * scopes available.
-     */
-    pub scopes: Vec<Scope>,
+impl ErrorResponse {
+    pub fn new(command: impl Into<String>) -> Self {
+        ErrorResponse {
+            command: command.into(),
+            error: None,
+            body: None,
+        }
+    }
+
+    pub fn error(mut self, error: impl Into<Message>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct VariablesResponse {
-    /**
-     * All (or a range) of variables for the given variable reference.
-     */
-    pub variables: Vec<Variable>,
+#[derive(Serialize)]
+struct SuccessFields<'a> {
+    success: bool,
+    #[serde(flatten)]
+    body: &'a ResponseBody,
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SetVariableResponse {
-    /**
-     * The new value of the variable.
-     */
-    pub value: String,
-
-    /**
-     * The type of the new value. Typically shown in the UI when hovering over
-     * the value.
-     */
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub variable_type: Option<String>,
-
-    /**
-     * If variablesReference is > 0, the new value is structured and its
-     * children can be retrieved by passing variablesReference to the
-     * VariablesRequest.
-     * The value should be less than or equal to 2147483647 (2^31-1).
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub variables_reference: Option<i32>,
-
-    /**
-     * The number of named child variables.
-     * The client can use this optional information to present the variables in
-     * a paged UI and fetch them in chunks.
-     * The value should be less than or equal to 2147483647 (2^31-1).
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub named_variables: Option<i32>,
-
-    /**
-     * The number of indexed child variables.
-     * The client can use this optional information to present the variables in
-     * a paged UI and fetch them in chunks.
-     * The value should be less than or equal to 2147483647 (2^31-1).
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub indexed_variables: Option<i32>,
+#[derive(Serialize)]
+struct ErrorFields<'a> {
+    success: bool,
+    command: &'a str,
+    body: ErrorFieldsBody<'a>,
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SourceResponse {
-    /**
-     * Content of the source reference.
-     */
-    pub content: String,
-
-    /**
-     * Optional content type (mime type) of the source.
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mime_type: Option<String>,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ThreadsResponse {
-    /**
-     * All threads.
-     */
-    pub threads: Vec<Thread>,
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ErrorFieldsBody<'a> {
+    Error(ErrorBody<'a>),
+    Raw(&'a Value),
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ModulesResponse {
-    /**
-     * All modules or range of modules.
-     */
-    pub modules: Vec<Module>,
-
-    /**
-     * The total number of modules available.
-     */
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct ErrorBody<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_modules: Option<i32>,
+    error: Option<Cow<'a, Message>>,
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct LoadedSourcesResponse {
-    /**
-     * Set of loaded sources.
-     */
-    pub sources: Vec<Source>,
+fn serialize_result<S>(
+    result: &Result<ResponseBody, ErrorResponse>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match result {
+        Ok(body) => SuccessFields {
+            success: true,
+            body,
+        }
+        .serialize(serializer),
+        Err(error) => ErrorFields {
+            success: false,
+            command: &error.command,
+            body: match &error.body {
+                Some(body) => ErrorFieldsBody::Raw(body),
+                None => ErrorFieldsBody::Error(ErrorBody {
+                    error: error.error.as_ref().map(Cow::Borrowed),
+                }),
+            },
+        }
+        .serialize(serializer),
+    }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct EvaluateResponse {
-    /**
-     * The result of the evaluate request.
-     */
-    pub result: String,
-
-    /**
-     * The optional type of the evaluate result.
-     * This attribute should only be returned by a debug adapter if the client
-     * has passed the value true for the 'supportsVariableType' capability of
-     * the 'initialize' request.
-     */
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result_type: Option<String>,
-
-    /**
-     * Properties of a evaluate result that can be used to determine how to
-     * render the result in the UI.
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub presentation_hint: Option<VariablePresentationHint>,
-
-    /**
-     * If variablesReference is > 0, the evaluate result is structured and its
-     * children can be retrieved by passing variablesReference to the
-     * VariablesRequest.
-     * The value should be less than or equal to 2147483647 (2^31-1).
-     */
-    pub variables_reference: i32,
-
-    /**
-     * The number of named child variables.
-     * The client can use this optional information to present the variables in
-     * a paged UI and fetch them in chunks.
-     * The value should be less than or equal to 2147483647 (2^31-1).
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub named_variables: Option<i32>,
-
-    /**
-     * The number of indexed child variables.
-     * The client can use this optional information to present the variables in
-     * a paged UI and fetch them in chunks.
-     * The value should be less than or equal to 2147483647 (2^31-1).
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub indexed_variables: Option<i32>,
-
-    /**
-     * Optional memory reference to a location appropriate for this result.
-     * For pointer type eval results, this is generally a reference to the
-     * memory address contained in the pointer.
-     * This attribute should be returned by a debug adapter if the client has
-     * passed the value true for the 'supportsMemoryReferences' capability of
-     * the 'initialize' request.
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub memory_reference: Option<String>,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SetExpressionResponse {
-    /**
-     * The new value of the expression.
-     */
-    pub value: String,
-
-    /**
-     * The optional type of the value.
-     * This attribute should only be returned by a debug adapter if the client
-     * has passed the value true for the 'supportsVariableType' capability of
-     * the 'initialize' request.
-     */
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_type: Option<String>,
-
-    /**
-     * Properties of a value that can be used to determine how to render the
-     * result in the UI.
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub presentation_hint: Option<VariablePresentationHint>,
-
-    /**
-     * If variablesReference is > 0, the value is structured and its children
-     * can be retrieved by passing variablesReference to the VariablesRequest.
-     * The value should be less than or equal to 2147483647 (2^31-1).
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub variables_reference: Option<i32>,
-
-    /**
-     * The number of named child variables.
-     * The client can use this optional information to present the variables in
-     * a paged UI and fetch them in chunks.
-     * The value should be less than or equal to 2147483647 (2^31-1).
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub named_variables: Option<i32>,
-
-    /**
-     * The number of indexed child variables.
-     * The client can use this optional information to present the variables in
-     * a paged UI and fetch them in chunks.
-     * The value should be less than or equal to 2147483647 (2^31-1).
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub indexed_variables: Option<i32>,
+#[derive(Deserialize)]
+struct RawResponse {
+    request_seq: i64,
+    success: bool,
+    command: String,
+    message: Option<ResponseMessage>,
+    body: Option<Value>,
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct StepInTargetsResponse {
-    /**
-     * The possible stepIn targets of the specified source location.
-     */
-    pub targets: Vec<StepInTarget>,
+fn tagged_body(command: String, body: Option<Value>) -> Value {
+    let mut fields = Map::new();
+    fields.insert("command".to_string(), Value::String(command));
+    if let Some(body) = body {
+        fields.insert("body".to_string(), body);
+    }
+    Value::Object(fields)
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct GotoTargetsResponse {
-    /**
-     * The possible goto targets of the specified location.
-     */
-    pub targets: Vec<GotoTarget>,
+fn parse_body(command: String, body: Option<Value>) -> Result<ResponseBody, serde_json::Error> {
+    serde_json::from_value(tagged_body(command, body))
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct CompletionsResponse {
-    /**
-     * The possible completions for .
-     */
-    pub targets: Vec<CompletionItem>,
+impl TryFrom<RawResponse> for Response {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawResponse) -> Result<Self, Self::Error> {
+        let result = if raw.success {
+            Ok(parse_body(raw.command, raw.body)?)
+        } else {
+            // Keep a body that is not exactly a structured error as is, rather
+            // than failing the whole message or dropping part of it.
+            let (error, body) = match raw.body {
+                None | Some(Value::Null) => (None, None),
+                Some(Value::Object(fields)) if fields.is_empty() => (None, None),
+                Some(body) => match ErrorBody::deserialize(&body) {
+                    Ok(ErrorBody { error: Some(error) }) => (Some(error.into_owned()), None),
+                    _ => (None, Some(body)),
+                },
+            };
+            Err(ErrorResponse {
+                command: raw.command,
+                error,
+                body,
+            })
+        };
+        Ok(Response {
+            request_seq: raw.request_seq,
+            message: raw.message,
+            result,
+        })
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ExceptionInfoResponse {
-    /**
-     * ID of the exception that was thrown.
-     */
-    pub exception_id: String,
-
+pub enum ResponseMessage {
     /**
-     * Descriptive text for the exception provided by the debug adapter.
+     * The request was cancelled.
      */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    Cancelled,
 
     /**
-     * Mode that caused the exception notification to be raised.
+     * The request may be retried once the adapter is in a 'stopped' state.
      */
-    pub break_mode: ExceptionBreakMode,
+    NotStopped,
 
     /**
-     * Detailed information about the exception.
+     * Any other error message, shown to the user as is.
      */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub details: Option<ExceptionDetails>,
+    #[serde(untagged)]
+    Error(String),
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ReadMemoryResponse {
-    /**
-     * The address of the first byte of data returned.
-     * Treated as a hex value if prefixed with '0x', or as a decimal value
-     * otherwise.
-     */
-    pub address: String,
+mod generated;
 
-    /**
-     * The number of unreadable bytes encountered after the last successfully
-     * read byte.
-     * This can be used to determine the number of bytes that must be skipped
-     * before a subsequent 'readMemory' request will succeed.
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub unreadable_bytes: Option<i32>,
-
-    /**
-     * The bytes read from memory, encoded using base64.
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<String>,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct WriteMemoryResponse {
-    /**
-     * Optional property that should be returned when 'allowPartial' is true to
-     * indicate the offset of the first byte of data successfully written. Can
-     * be negative.
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub offset: Option<i32>,
-
-    /**
-     * Optional property that should be returned when 'allowPartial' is true to
-     * indicate the number of bytes starting from address that were successfully
-     * written.
-     */
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bytes_written: Option<i32>,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct DisassembleResponse {
-    /**
-     * The list of disassembled instructions.
-     */
-    pub instructions: Vec<DisassembledInstruction>,
-}
+pub use self::generated::*;
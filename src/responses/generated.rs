@@ -0,0 +1,1495 @@
+// The definitions in this module are maintained by `cargo xtask codegen`, which
+// regenerates them from the Debug Adapter Protocol JSON schema. Do not edit
+// them by hand.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::types::{
+    Address, Breakpoint, BreakpointLocation, Capabilities, CompletionItem,
+    DataBreakpointAccessType, DisassembledInstruction, ExceptionBreakMode, ExceptionDetails,
+    GotoTarget, MemoryReference, Module, ProcessId, Scope, Source, StackFrame, StepInTarget,
+    Thread, Variable, VariableCount, VariablePresentationHint, VariablesReference,
+};
+use crate::{parse_content, split_message};
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase", tag = "command", content = "body")]
+pub enum ResponseBody {
+    Cancel,
+    RunInTerminal(RunInTerminalResponse),
+    StartDebugging,
+    Initialize(InitializeResponse),
+    ConfigurationDone,
+    Launch,
+    Attach,
+    Restart,
+    Disconnect,
+    Terminate,
+    BreakpointLocations(BreakpointLocationsResponse),
+    SetBreakpoints(SetBreakpointsResponse),
+    SetFunctionBreakpoints(SetFunctionBreakpointsResponse),
+    SetExceptionBreakpoints(SetExceptionBreakpointsResponse),
+    DataBreakpointInfo(DataBreakpointInfoResponse),
+    SetDataBreakpoints(SetDataBreakpointsResponse),
+    SetInstructionBreakpoints(SetInstructionBreakpointsResponse),
+    Continue(ContinueResponse),
+    Next,
+    StepIn,
+    StepOut,
+    StepBack,
+    ReverseContinue,
+    RestartFrame,
+    Goto,
+    Pause,
+    StackTrace(StackTraceResponse),
+    Scopes(ScopesResponse),
+    Variables(VariablesResponse),
+    SetVariable(SetVariableResponse),
+    Source(SourceResponse),
+    Threads(ThreadsResponse),
+    TerminateThreads,
+    Modules(ModulesResponse),
+    LoadedSources(LoadedSourcesResponse),
+    Evaluate(EvaluateResponse),
+    SetExpression(SetExpressionResponse),
+    StepInTargets(StepInTargetsResponse),
+    GotoTargets(GotoTargetsResponse),
+    Completions(CompletionsResponse),
+    ExceptionInfo(ExceptionInfoResponse),
+    ReadMemory(ReadMemoryResponse),
+    WriteMemory(WriteMemoryResponse),
+    Disassemble(DisassembleResponse),
+    Locations(LocationsResponse),
+
+    /**
+     * A response to a command not known to this crate. The raw body is
+     * preserved as is.
+     */
+    #[serde(untagged)]
+    Unknown {
+        command: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        body: Option<Value>,
+    },
+}
+
+impl<'de> Deserialize<'de> for ResponseBody {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (command, body) = split_message(deserializer, "command", "body")?;
+        Ok(match command.as_str() {
+            "cancel" => ResponseBody::Cancel,
+            "runInTerminal" => ResponseBody::RunInTerminal(parse_content(body)?),
+            "startDebugging" => ResponseBody::StartDebugging,
+            "initialize" => ResponseBody::Initialize(parse_content(body)?),
+            "configurationDone" => ResponseBody::ConfigurationDone,
+            "launch" => ResponseBody::Launch,
+            "attach" => ResponseBody::Attach,
+            "restart" => ResponseBody::Restart,
+            "disconnect" => ResponseBody::Disconnect,
+            "terminate" => ResponseBody::Terminate,
+            "breakpointLocations" => ResponseBody::BreakpointLocations(parse_content(body)?),
+            "setBreakpoints" => ResponseBody::SetBreakpoints(parse_content(body)?),
+            "setFunctionBreakpoints" => ResponseBody::SetFunctionBreakpoints(parse_content(body)?),
+            "setExceptionBreakpoints" => {
+                ResponseBody::SetExceptionBreakpoints(parse_content(body)?)
+            }
+            "dataBreakpointInfo" => ResponseBody::DataBreakpointInfo(parse_content(body)?),
+            "setDataBreakpoints" => ResponseBody::SetDataBreakpoints(parse_content(body)?),
+            "setInstructionBreakpoints" => {
+                ResponseBody::SetInstructionBreakpoints(parse_content(body)?)
+            }
+            "continue" => ResponseBody::Continue(parse_content(body)?),
+            "next" => ResponseBody::Next,
+            "stepIn" => ResponseBody::StepIn,
+            "stepOut" => ResponseBody::StepOut,
+            "stepBack" => ResponseBody::StepBack,
+            "reverseContinue" => ResponseBody::ReverseContinue,
+            "restartFrame" => ResponseBody::RestartFrame,
+            "goto" => ResponseBody::Goto,
+            "pause" => ResponseBody::Pause,
+            "stackTrace" => ResponseBody::StackTrace(parse_content(body)?),
+            "scopes" => ResponseBody::Scopes(parse_content(body)?),
+            "variables" => ResponseBody::Variables(parse_content(body)?),
+            "setVariable" => ResponseBody::SetVariable(parse_content(body)?),
+            "source" => ResponseBody::Source(parse_content(body)?),
+            "threads" => ResponseBody::Threads(parse_content(body)?),
+            "terminateThreads" => ResponseBody::TerminateThreads,
+            "modules" => ResponseBody::Modules(parse_content(body)?),
+            "loadedSources" => ResponseBody::LoadedSources(parse_content(body)?),
+            "evaluate" => ResponseBody::Evaluate(parse_content(body)?),
+            "setExpression" => ResponseBody::SetExpression(parse_content(body)?),
+            "stepInTargets" => ResponseBody::StepInTargets(parse_content(body)?),
+            "gotoTargets" => ResponseBody::GotoTargets(parse_content(body)?),
+            "completions" => ResponseBody::Completions(parse_content(body)?),
+            "exceptionInfo" => ResponseBody::ExceptionInfo(parse_content(body)?),
+            "readMemory" => ResponseBody::ReadMemory(parse_content(body)?),
+            "writeMemory" => ResponseBody::WriteMemory(parse_content(body)?),
+            "disassemble" => ResponseBody::Disassemble(parse_content(body)?),
+            "locations" => ResponseBody::Locations(parse_content(body)?),
+            _ => ResponseBody::Unknown { command, body },
+        })
+    }
+}
+
+impl ResponseBody {
+    /**
+     * The command this is a response to.
+     */
+    pub fn command(&self) -> &str {
+        match self {
+            ResponseBody::Cancel => "cancel",
+            ResponseBody::RunInTerminal(_) => "runInTerminal",
+            ResponseBody::StartDebugging => "startDebugging",
+            ResponseBody::Initialize(_) => "initialize",
+            ResponseBody::ConfigurationDone => "configurationDone",
+            ResponseBody::Launch => "launch",
+            ResponseBody::Attach => "attach",
+            ResponseBody::Restart => "restart",
+            ResponseBody::Disconnect => "disconnect",
+            ResponseBody::Terminate => "terminate",
+            ResponseBody::BreakpointLocations(_) => "breakpointLocations",
+            ResponseBody::SetBreakpoints(_) => "setBreakpoints",
+            ResponseBody::SetFunctionBreakpoints(_) => "setFunctionBreakpoints",
+            ResponseBody::SetExceptionBreakpoints(_) => "setExceptionBreakpoints",
+            ResponseBody::DataBreakpointInfo(_) => "dataBreakpointInfo",
+            ResponseBody::SetDataBreakpoints(_) => "setDataBreakpoints",
+            ResponseBody::SetInstructionBreakpoints(_) => "setInstructionBreakpoints",
+            ResponseBody::Continue(_) => "continue",
+            ResponseBody::Next => "next",
+            ResponseBody::StepIn => "stepIn",
+            ResponseBody::StepOut => "stepOut",
+            ResponseBody::StepBack => "stepBack",
+            ResponseBody::ReverseContinue => "reverseContinue",
+            ResponseBody::RestartFrame => "restartFrame",
+            ResponseBody::Goto => "goto",
+            ResponseBody::Pause => "pause",
+            ResponseBody::StackTrace(_) => "stackTrace",
+            ResponseBody::Scopes(_) => "scopes",
+            ResponseBody::Variables(_) => "variables",
+            ResponseBody::SetVariable(_) => "setVariable",
+            ResponseBody::Source(_) => "source",
+            ResponseBody::Threads(_) => "threads",
+            ResponseBody::TerminateThreads => "terminateThreads",
+            ResponseBody::Modules(_) => "modules",
+            ResponseBody::LoadedSources(_) => "loadedSources",
+            ResponseBody::Evaluate(_) => "evaluate",
+            ResponseBody::SetExpression(_) => "setExpression",
+            ResponseBody::StepInTargets(_) => "stepInTargets",
+            ResponseBody::GotoTargets(_) => "gotoTargets",
+            ResponseBody::Completions(_) => "completions",
+            ResponseBody::ExceptionInfo(_) => "exceptionInfo",
+            ResponseBody::ReadMemory(_) => "readMemory",
+            ResponseBody::WriteMemory(_) => "writeMemory",
+            ResponseBody::Disassemble(_) => "disassemble",
+            ResponseBody::Locations(_) => "locations",
+            ResponseBody::Unknown { command, .. } => command,
+        }
+    }
+}
+
+impl From<RunInTerminalResponse> for ResponseBody {
+    fn from(body: RunInTerminalResponse) -> Self {
+        ResponseBody::RunInTerminal(body)
+    }
+}
+
+impl From<InitializeResponse> for ResponseBody {
+    fn from(body: InitializeResponse) -> Self {
+        ResponseBody::Initialize(body)
+    }
+}
+
+impl From<BreakpointLocationsResponse> for ResponseBody {
+    fn from(body: BreakpointLocationsResponse) -> Self {
+        ResponseBody::BreakpointLocations(body)
+    }
+}
+
+impl From<SetBreakpointsResponse> for ResponseBody {
+    fn from(body: SetBreakpointsResponse) -> Self {
+        ResponseBody::SetBreakpoints(body)
+    }
+}
+
+impl From<SetFunctionBreakpointsResponse> for ResponseBody {
+    fn from(body: SetFunctionBreakpointsResponse) -> Self {
+        ResponseBody::SetFunctionBreakpoints(body)
+    }
+}
+
+impl From<SetExceptionBreakpointsResponse> for ResponseBody {
+    fn from(body: SetExceptionBreakpointsResponse) -> Self {
+        ResponseBody::SetExceptionBreakpoints(body)
+    }
+}
+
+impl From<DataBreakpointInfoResponse> for ResponseBody {
+    fn from(body: DataBreakpointInfoResponse) -> Self {
+        ResponseBody::DataBreakpointInfo(body)
+    }
+}
+
+impl From<SetDataBreakpointsResponse> for ResponseBody {
+    fn from(body: SetDataBreakpointsResponse) -> Self {
+        ResponseBody::SetDataBreakpoints(body)
+    }
+}
+
+impl From<SetInstructionBreakpointsResponse> for ResponseBody {
+    fn from(body: SetInstructionBreakpointsResponse) -> Self {
+        ResponseBody::SetInstructionBreakpoints(body)
+    }
+}
+
+impl From<ContinueResponse> for ResponseBody {
+    fn from(body: ContinueResponse) -> Self {
+        ResponseBody::Continue(body)
+    }
+}
+
+impl From<StackTraceResponse> for ResponseBody {
+    fn from(body: StackTraceResponse) -> Self {
+        ResponseBody::StackTrace(body)
+    }
+}
+
+impl From<ScopesResponse> for ResponseBody {
+    fn from(body: ScopesResponse) -> Self {
+        ResponseBody::Scopes(body)
+    }
+}
+
+impl From<VariablesResponse> for ResponseBody {
+    fn from(body: VariablesResponse) -> Self {
+        ResponseBody::Variables(body)
+    }
+}
+
+impl From<SetVariableResponse> for ResponseBody {
+    fn from(body: SetVariableResponse) -> Self {
+        ResponseBody::SetVariable(body)
+    }
+}
+
+impl From<SourceResponse> for ResponseBody {
+    fn from(body: SourceResponse) -> Self {
+        ResponseBody::Source(body)
+    }
+}
+
+impl From<ThreadsResponse> for ResponseBody {
+    fn from(body: ThreadsResponse) -> Self {
+        ResponseBody::Threads(body)
+    }
+}
+
+impl From<ModulesResponse> for ResponseBody {
+    fn from(body: ModulesResponse) -> Self {
+        ResponseBody::Modules(body)
+    }
+}
+
+impl From<LoadedSourcesResponse> for ResponseBody {
+    fn from(body: LoadedSourcesResponse) -> Self {
+        ResponseBody::LoadedSources(body)
+    }
+}
+
+impl From<EvaluateResponse> for ResponseBody {
+    fn from(body: EvaluateResponse) -> Self {
+        ResponseBody::Evaluate(body)
+    }
+}
+
+impl From<SetExpressionResponse> for ResponseBody {
+    fn from(body: SetExpressionResponse) -> Self {
+        ResponseBody::SetExpression(body)
+    }
+}
+
+impl From<StepInTargetsResponse> for ResponseBody {
+    fn from(body: StepInTargetsResponse) -> Self {
+        ResponseBody::StepInTargets(body)
+    }
+}
+
+impl From<GotoTargetsResponse> for ResponseBody {
+    fn from(body: GotoTargetsResponse) -> Self {
+        ResponseBody::GotoTargets(body)
+    }
+}
+
+impl From<CompletionsResponse> for ResponseBody {
+    fn from(body: CompletionsResponse) -> Self {
+        ResponseBody::Completions(body)
+    }
+}
+
+impl From<ExceptionInfoResponse> for ResponseBody {
+    fn from(body: ExceptionInfoResponse) -> Self {
+        ResponseBody::ExceptionInfo(body)
+    }
+}
+
+impl From<ReadMemoryResponse> for ResponseBody {
+    fn from(body: ReadMemoryResponse) -> Self {
+        ResponseBody::ReadMemory(body)
+    }
+}
+
+impl From<WriteMemoryResponse> for ResponseBody {
+    fn from(body: WriteMemoryResponse) -> Self {
+        ResponseBody::WriteMemory(body)
+    }
+}
+
+impl From<DisassembleResponse> for ResponseBody {
+    fn from(body: DisassembleResponse) -> Self {
+        ResponseBody::Disassemble(body)
+    }
+}
+
+impl From<LocationsResponse> for ResponseBody {
+    fn from(body: LocationsResponse) -> Self {
+        ResponseBody::Locations(body)
+    }
+}
+
+/**
+ * Response to 'runInTerminal' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunInTerminalResponse {
+    /**
+     * The process ID. The value should be less than or equal to 2147483647
+     * (2^31-1).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_id: Option<ProcessId>,
+
+    /**
+     * The process ID of the terminal shell. The value should be less than or
+     * equal to 2147483647 (2^31-1).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell_process_id: Option<ProcessId>,
+}
+
+impl RunInTerminalResponse {
+    pub fn process_id(mut self, process_id: impl Into<ProcessId>) -> Self {
+        self.process_id = Some(process_id.into());
+        self
+    }
+
+    pub fn shell_process_id(mut self, shell_process_id: impl Into<ProcessId>) -> Self {
+        self.shell_process_id = Some(shell_process_id.into());
+        self
+    }
+}
+
+/**
+ * Response to 'initialize' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct InitializeResponse {
+    /**
+     * The capabilities of this debug adapter.
+     */
+    pub capabilities: Capabilities,
+}
+
+impl InitializeResponse {
+    pub fn new(capabilities: impl Into<Capabilities>) -> Self {
+        InitializeResponse {
+            capabilities: capabilities.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'breakpointLocations' request.
+ * Contains possible locations for source breakpoints.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakpointLocationsResponse {
+    /**
+     * Sorted set of possible breakpoint locations.
+     */
+    pub breakpoints: Vec<BreakpointLocation>,
+}
+
+impl BreakpointLocationsResponse {
+    pub fn new(breakpoints: impl Into<Vec<BreakpointLocation>>) -> Self {
+        BreakpointLocationsResponse {
+            breakpoints: breakpoints.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'setBreakpoints' request.
+ * Returned is information about each breakpoint created by this request.
+ * This includes the actual code location and whether the breakpoint could be
+ * verified.
+ * The breakpoints returned are in the same order as the elements of the
+ * 'breakpoints'
+ * (or the deprecated 'lines') array in the arguments.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetBreakpointsResponse {
+    /**
+     * Information about the breakpoints.
+     * The array elements are in the same order as the elements of the
+     * 'breakpoints' (or the deprecated 'lines') array in the arguments.
+     */
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl SetBreakpointsResponse {
+    pub fn new(breakpoints: impl Into<Vec<Breakpoint>>) -> Self {
+        SetBreakpointsResponse {
+            breakpoints: breakpoints.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'setFunctionBreakpoints' request.
+ * Returned is information about each breakpoint created by this request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetFunctionBreakpointsResponse {
+    /**
+     * Information about the breakpoints. The array elements correspond to the
+     * elements of the 'breakpoints' array.
+     */
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl SetFunctionBreakpointsResponse {
+    pub fn new(breakpoints: impl Into<Vec<Breakpoint>>) -> Self {
+        SetFunctionBreakpointsResponse {
+            breakpoints: breakpoints.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'setExceptionBreakpoints' request.
+ * The response contains an array of 'Breakpoint' objects with information about
+ * each exception breakpoint or filter. The 'Breakpoint' objects are in the same
+ * order as the elements of the 'filters', 'filterOptions', 'exceptionOptions'
+ * arrays given as arguments. If both 'filters' and 'filterOptions' are given,
+ * the returned array must start with 'filters' information first, followed by
+ * 'filterOptions' information.
+ * The 'verified' property of a 'Breakpoint' object signals whether the
+ * exception breakpoint or filter could be successfully created and whether the
+ * condition is valid. In case of an error the 'message' property explains the
+ * problem. The 'id' property can be used to introduce a unique ID for the
+ * exception breakpoint or filter so that it can be updated subsequently by
+ * sending breakpoint events.
+ * For backward compatibility both the 'breakpoints' array and the enclosing
+ * 'body' are optional. If these elements are missing a client is not able to
+ * show problems for individual exception breakpoints or filters.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetExceptionBreakpointsResponse {
+    /**
+     * Information about the exception breakpoints or filters.
+     * The breakpoints returned are in the same order as the elements of the
+     * 'filters', 'filterOptions', 'exceptionOptions' arrays in the arguments.
+     * If both 'filters' and 'filterOptions' are given, the returned array must
+     * start with 'filters' information first, followed by 'filterOptions'
+     * information.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breakpoints: Option<Vec<Breakpoint>>,
+}
+
+impl SetExceptionBreakpointsResponse {
+    pub fn breakpoints(mut self, breakpoints: impl Into<Vec<Breakpoint>>) -> Self {
+        self.breakpoints = Some(breakpoints.into());
+        self
+    }
+}
+
+/**
+ * Response to 'dataBreakpointInfo' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataBreakpointInfoResponse {
+    /**
+     * An identifier for the data on which a data breakpoint can be registered
+     * with the 'setDataBreakpoints' request or null if no data breakpoint is
+     * available. If a 'variablesReference' or 'frameId' is passed, the 'dataId'
+     * is valid in the current suspended state, otherwise it's valid
+     * indefinitely. See 'Lifetime of Object References' in the Overview section
+     * for details. Breakpoints set using the 'dataId' in the
+     * 'setDataBreakpoints' request may outlive the lifetime of the associated
+     * 'dataId'.
+     */
+    pub data_id: Option<String>,
+
+    /**
+     * UI string that describes on what data the breakpoint is set on or why a
+     * data breakpoint is not available.
+     */
+    pub description: String,
+
+    /**
+     * Attribute lists the available access types for a potential data
+     * breakpoint. A UI client could surface this information.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_types: Option<Vec<DataBreakpointAccessType>>,
+
+    /**
+     * Attribute indicates that a potential data breakpoint could be persisted
+     * across sessions.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_persist: Option<bool>,
+}
+
+impl DataBreakpointInfoResponse {
+    pub fn new(description: impl Into<String>) -> Self {
+        DataBreakpointInfoResponse {
+            data_id: None,
+            description: description.into(),
+            access_types: None,
+            can_persist: None,
+        }
+    }
+
+    pub fn data_id(mut self, data_id: impl Into<String>) -> Self {
+        self.data_id = Some(data_id.into());
+        self
+    }
+
+    pub fn access_types(mut self, access_types: impl Into<Vec<DataBreakpointAccessType>>) -> Self {
+        self.access_types = Some(access_types.into());
+        self
+    }
+
+    pub fn can_persist(mut self, can_persist: impl Into<bool>) -> Self {
+        self.can_persist = Some(can_persist.into());
+        self
+    }
+}
+
+/**
+ * Response to 'setDataBreakpoints' request.
+ * Returned is information about each breakpoint created by this request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDataBreakpointsResponse {
+    /**
+     * Information about the data breakpoints. The array elements correspond to
+     * the elements of the input argument 'breakpoints' array.
+     */
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl SetDataBreakpointsResponse {
+    pub fn new(breakpoints: impl Into<Vec<Breakpoint>>) -> Self {
+        SetDataBreakpointsResponse {
+            breakpoints: breakpoints.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'setInstructionBreakpoints' request
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetInstructionBreakpointsResponse {
+    /**
+     * Information about the breakpoints. The array elements correspond to the
+     * elements of the 'breakpoints' array.
+     */
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl SetInstructionBreakpointsResponse {
+    pub fn new(breakpoints: impl Into<Vec<Breakpoint>>) -> Self {
+        SetInstructionBreakpointsResponse {
+            breakpoints: breakpoints.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'continue' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinueResponse {
+    /**
+     * The value true (or a missing property) signals to the client that all
+     * threads have been resumed. The value false indicates that not all threads
+     * were resumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_threads_continued: Option<bool>,
+}
+
+impl ContinueResponse {
+    pub fn all_threads_continued(mut self, all_threads_continued: impl Into<bool>) -> Self {
+        self.all_threads_continued = Some(all_threads_continued.into());
+        self
+    }
+}
+
+/**
+ * Response to 'stackTrace' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StackTraceResponse {
+    /**
+     * The frames of the stack frame. If the array has length zero, there are no
+     * stack frames available.
+     * This means that there is no location information available.
+     */
+    pub stack_frames: Vec<StackFrame>,
+
+    /**
+     * The total number of frames available in the stack. If omitted or if
+     * 'totalFrames' is larger than the available frames, a client is expected
+     * to request frames until a request returns less frames than requested
+     * (which indicates the end of the stack). Returning monotonically
+     * increasing 'totalFrames' values for subsequent requests can be used to
+     * enforce paging in the client.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_frames: Option<i64>,
+}
+
+impl StackTraceResponse {
+    pub fn new(stack_frames: impl Into<Vec<StackFrame>>) -> Self {
+        StackTraceResponse {
+            stack_frames: stack_frames.into(),
+            total_frames: None,
+        }
+    }
+
+    pub fn total_frames(mut self, total_frames: impl Into<i64>) -> Self {
+        self.total_frames = Some(total_frames.into());
+        self
+    }
+}
+
+/**
+ * Response to 'scopes' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopesResponse {
+    /**
+     * The scopes of the stack frame. If the array has length zero, there are no
+     * scopes available.
+     */
+    pub scopes: Vec<Scope>,
+}
+
+impl ScopesResponse {
+    pub fn new(scopes: impl Into<Vec<Scope>>) -> Self {
+        ScopesResponse {
+            scopes: scopes.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'variables' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariablesResponse {
+    /**
+     * All (or a range) of variables for the given variable reference.
+     */
+    pub variables: Vec<Variable>,
+}
+
+impl VariablesResponse {
+    pub fn new(variables: impl Into<Vec<Variable>>) -> Self {
+        VariablesResponse {
+            variables: variables.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'setVariable' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetVariableResponse {
+    /**
+     * The new value of the variable.
+     */
+    pub value: String,
+
+    /**
+     * The type of the new value. Typically shown in the UI when hovering over
+     * the value.
+     */
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub variable_type: Option<String>,
+
+    /**
+     * If 'variablesReference' is > 0, the new value is structured and its
+     * children can be retrieved by passing 'variablesReference' to the
+     * 'variables' request as long as execution remains suspended. See 'Lifetime
+     * of Object References' in the Overview section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables_reference: Option<VariablesReference>,
+
+    /**
+     * The number of named child variables.
+     * The client can use this information to present the variables in a paged
+     * UI and fetch them in chunks.
+     * The value should be less than or equal to 2147483647 (2^31-1).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub named_variables: Option<VariableCount>,
+
+    /**
+     * The number of indexed child variables.
+     * The client can use this information to present the variables in a paged
+     * UI and fetch them in chunks.
+     * The value should be less than or equal to 2147483647 (2^31-1).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexed_variables: Option<VariableCount>,
+
+    /**
+     * A memory reference to a location appropriate for this result.
+     * For pointer type eval results, this is generally a reference to the
+     * memory address contained in the pointer.
+     * This attribute may be returned by a debug adapter if corresponding
+     * capability 'supportsMemoryReferences' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_reference: Option<MemoryReference>,
+
+    /**
+     * A reference that allows the client to request the location where the new
+     * value is declared. For example, if the new value is function pointer, the
+     * adapter may be able to look up the function's location. This should be
+     * present only if the adapter is likely to be able to resolve the location.
+     *
+     * This reference shares the same lifetime as the 'variablesReference'. See
+     * 'Lifetime of Object References' in the Overview section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_location_reference: Option<i64>,
+}
+
+impl SetVariableResponse {
+    pub fn new(value: impl Into<String>) -> Self {
+        SetVariableResponse {
+            value: value.into(),
+            variable_type: None,
+            variables_reference: None,
+            named_variables: None,
+            indexed_variables: None,
+            memory_reference: None,
+            value_location_reference: None,
+        }
+    }
+
+    pub fn variable_type(mut self, variable_type: impl Into<String>) -> Self {
+        self.variable_type = Some(variable_type.into());
+        self
+    }
+
+    pub fn variables_reference(
+        mut self,
+        variables_reference: impl Into<VariablesReference>,
+    ) -> Self {
+        self.variables_reference = Some(variables_reference.into());
+        self
+    }
+
+    pub fn named_variables(mut self, named_variables: impl Into<VariableCount>) -> Self {
+        self.named_variables = Some(named_variables.into());
+        self
+    }
+
+    pub fn indexed_variables(mut self, indexed_variables: impl Into<VariableCount>) -> Self {
+        self.indexed_variables = Some(indexed_variables.into());
+        self
+    }
+
+    pub fn memory_reference(mut self, memory_reference: impl Into<MemoryReference>) -> Self {
+        self.memory_reference = Some(memory_reference.into());
+        self
+    }
+
+    pub fn value_location_reference(mut self, value_location_reference: impl Into<i64>) -> Self {
+        self.value_location_reference = Some(value_location_reference.into());
+        self
+    }
+}
+
+/**
+ * Response to 'source' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceResponse {
+    /**
+     * Content of the source reference.
+     */
+    pub content: String,
+
+    /**
+     * Content type (MIME type) of the source.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+impl SourceResponse {
+    pub fn new(content: impl Into<String>) -> Self {
+        SourceResponse {
+            content: content.into(),
+            mime_type: None,
+        }
+    }
+
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+}
+
+/**
+ * Response to 'threads' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadsResponse {
+    /**
+     * All threads.
+     */
+    pub threads: Vec<Thread>,
+}
+
+impl ThreadsResponse {
+    pub fn new(threads: impl Into<Vec<Thread>>) -> Self {
+        ThreadsResponse {
+            threads: threads.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'modules' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModulesResponse {
+    /**
+     * All modules or range of modules.
+     */
+    pub modules: Vec<Module>,
+
+    /**
+     * The total number of modules available.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_modules: Option<i64>,
+}
+
+impl ModulesResponse {
+    pub fn new(modules: impl Into<Vec<Module>>) -> Self {
+        ModulesResponse {
+            modules: modules.into(),
+            total_modules: None,
+        }
+    }
+
+    pub fn total_modules(mut self, total_modules: impl Into<i64>) -> Self {
+        self.total_modules = Some(total_modules.into());
+        self
+    }
+}
+
+/**
+ * Response to 'loadedSources' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadedSourcesResponse {
+    /**
+     * Set of loaded sources.
+     */
+    pub sources: Vec<Source>,
+}
+
+impl LoadedSourcesResponse {
+    pub fn new(sources: impl Into<Vec<Source>>) -> Self {
+        LoadedSourcesResponse {
+            sources: sources.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'evaluate' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluateResponse {
+    /**
+     * The result of the evaluate request.
+     */
+    pub result: String,
+
+    /**
+     * The type of the evaluate result.
+     * This attribute should only be returned by a debug adapter if the
+     * corresponding capability 'supportsVariableType' is true.
+     */
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub result_type: Option<String>,
+
+    /**
+     * Properties of an evaluate result that can be used to determine how to
+     * render the result in the UI.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation_hint: Option<VariablePresentationHint>,
+
+    /**
+     * If 'variablesReference' is > 0, the evaluate result is structured and its
+     * children can be retrieved by passing 'variablesReference' to the
+     * 'variables' request as long as execution remains suspended. See 'Lifetime
+     * of Object References' in the Overview section for details.
+     */
+    pub variables_reference: VariablesReference,
+
+    /**
+     * The number of named child variables.
+     * The client can use this information to present the variables in a paged
+     * UI and fetch them in chunks.
+     * The value should be less than or equal to 2147483647 (2^31-1).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub named_variables: Option<VariableCount>,
+
+    /**
+     * The number of indexed child variables.
+     * The client can use this information to present the variables in a paged
+     * UI and fetch them in chunks.
+     * The value should be less than or equal to 2147483647 (2^31-1).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexed_variables: Option<VariableCount>,
+
+    /**
+     * A memory reference to a location appropriate for this result.
+     * For pointer type eval results, this is generally a reference to the
+     * memory address contained in the pointer.
+     * This attribute may be returned by a debug adapter if corresponding
+     * capability 'supportsMemoryReferences' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_reference: Option<MemoryReference>,
+
+    /**
+     * A reference that allows the client to request the location where the
+     * returned value is declared. For example, if a function pointer is
+     * returned, the adapter may be able to look up the function's location.
+     * This should be present only if the adapter is likely to be able to
+     * resolve the location.
+     *
+     * This reference shares the same lifetime as the 'variablesReference'. See
+     * 'Lifetime of Object References' in the Overview section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_location_reference: Option<i64>,
+}
+
+impl EvaluateResponse {
+    pub fn new(
+        result: impl Into<String>,
+        variables_reference: impl Into<VariablesReference>,
+    ) -> Self {
+        EvaluateResponse {
+            result: result.into(),
+            result_type: None,
+            presentation_hint: None,
+            variables_reference: variables_reference.into(),
+            named_variables: None,
+            indexed_variables: None,
+            memory_reference: None,
+            value_location_reference: None,
+        }
+    }
+
+    pub fn result_type(mut self, result_type: impl Into<String>) -> Self {
+        self.result_type = Some(result_type.into());
+        self
+    }
+
+    pub fn presentation_hint(
+        mut self,
+        presentation_hint: impl Into<VariablePresentationHint>,
+    ) -> Self {
+        self.presentation_hint = Some(presentation_hint.into());
+        self
+    }
+
+    pub fn named_variables(mut self, named_variables: impl Into<VariableCount>) -> Self {
+        self.named_variables = Some(named_variables.into());
+        self
+    }
+
+    pub fn indexed_variables(mut self, indexed_variables: impl Into<VariableCount>) -> Self {
+        self.indexed_variables = Some(indexed_variables.into());
+        self
+    }
+
+    pub fn memory_reference(mut self, memory_reference: impl Into<MemoryReference>) -> Self {
+        self.memory_reference = Some(memory_reference.into());
+        self
+    }
+
+    pub fn value_location_reference(mut self, value_location_reference: impl Into<i64>) -> Self {
+        self.value_location_reference = Some(value_location_reference.into());
+        self
+    }
+}
+
+/**
+ * Response to 'setExpression' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetExpressionResponse {
+    /**
+     * The new value of the expression.
+     */
+    pub value: String,
+
+    /**
+     * The type of the value.
+     * This attribute should only be returned by a debug adapter if the
+     * corresponding capability 'supportsVariableType' is true.
+     */
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<String>,
+
+    /**
+     * Properties of a value that can be used to determine how to render the
+     * result in the UI.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation_hint: Option<VariablePresentationHint>,
+
+    /**
+     * If 'variablesReference' is > 0, the evaluate result is structured and its
+     * children can be retrieved by passing 'variablesReference' to the
+     * 'variables' request as long as execution remains suspended. See 'Lifetime
+     * of Object References' in the Overview section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables_reference: Option<VariablesReference>,
+
+    /**
+     * The number of named child variables.
+     * The client can use this information to present the variables in a paged
+     * UI and fetch them in chunks.
+     * The value should be less than or equal to 2147483647 (2^31-1).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub named_variables: Option<VariableCount>,
+
+    /**
+     * The number of indexed child variables.
+     * The client can use this information to present the variables in a paged
+     * UI and fetch them in chunks.
+     * The value should be less than or equal to 2147483647 (2^31-1).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexed_variables: Option<VariableCount>,
+
+    /**
+     * A memory reference to a location appropriate for this result.
+     * For pointer type eval results, this is generally a reference to the
+     * memory address contained in the pointer.
+     * This attribute may be returned by a debug adapter if corresponding
+     * capability 'supportsMemoryReferences' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_reference: Option<MemoryReference>,
+
+    /**
+     * A reference that allows the client to request the location where the new
+     * value is declared. For example, if the new value is function pointer, the
+     * adapter may be able to look up the function's location. This should be
+     * present only if the adapter is likely to be able to resolve the location.
+     *
+     * This reference shares the same lifetime as the 'variablesReference'. See
+     * 'Lifetime of Object References' in the Overview section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_location_reference: Option<i64>,
+}
+
+impl SetExpressionResponse {
+    pub fn new(value: impl Into<String>) -> Self {
+        SetExpressionResponse {
+            value: value.into(),
+            value_type: None,
+            presentation_hint: None,
+            variables_reference: None,
+            named_variables: None,
+            indexed_variables: None,
+            memory_reference: None,
+            value_location_reference: None,
+        }
+    }
+
+    pub fn value_type(mut self, value_type: impl Into<String>) -> Self {
+        self.value_type = Some(value_type.into());
+        self
+    }
+
+    pub fn presentation_hint(
+        mut self,
+        presentation_hint: impl Into<VariablePresentationHint>,
+    ) -> Self {
+        self.presentation_hint = Some(presentation_hint.into());
+        self
+    }
+
+    pub fn variables_reference(
+        mut self,
+        variables_reference: impl Into<VariablesReference>,
+    ) -> Self {
+        self.variables_reference = Some(variables_reference.into());
+        self
+    }
+
+    pub fn named_variables(mut self, named_variables: impl Into<VariableCount>) -> Self {
+        self.named_variables = Some(named_variables.into());
+        self
+    }
+
+    pub fn indexed_variables(mut self, indexed_variables: impl Into<VariableCount>) -> Self {
+        self.indexed_variables = Some(indexed_variables.into());
+        self
+    }
+
+    pub fn memory_reference(mut self, memory_reference: impl Into<MemoryReference>) -> Self {
+        self.memory_reference = Some(memory_reference.into());
+        self
+    }
+
+    pub fn value_location_reference(mut self, value_location_reference: impl Into<i64>) -> Self {
+        self.value_location_reference = Some(value_location_reference.into());
+        self
+    }
+}
+
+/**
+ * Response to 'stepInTargets' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepInTargetsResponse {
+    /**
+     * The possible step-in targets of the specified source location.
+     */
+    pub targets: Vec<StepInTarget>,
+}
+
+impl StepInTargetsResponse {
+    pub fn new(targets: impl Into<Vec<StepInTarget>>) -> Self {
+        StepInTargetsResponse {
+            targets: targets.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'gotoTargets' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GotoTargetsResponse {
+    /**
+     * The possible goto targets of the specified location.
+     */
+    pub targets: Vec<GotoTarget>,
+}
+
+impl GotoTargetsResponse {
+    pub fn new(targets: impl Into<Vec<GotoTarget>>) -> Self {
+        GotoTargetsResponse {
+            targets: targets.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'completions' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionsResponse {
+    /**
+     * The possible completions for .
+     */
+    pub targets: Vec<CompletionItem>,
+}
+
+impl CompletionsResponse {
+    pub fn new(targets: impl Into<Vec<CompletionItem>>) -> Self {
+        CompletionsResponse {
+            targets: targets.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'exceptionInfo' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExceptionInfoResponse {
+    /**
+     * ID of the exception that was thrown.
+     */
+    pub exception_id: String,
+
+    /**
+     * Descriptive text for the exception.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /**
+     * Mode that caused the exception notification to be raised.
+     */
+    pub break_mode: ExceptionBreakMode,
+
+    /**
+     * Detailed information about the exception.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<ExceptionDetails>,
+}
+
+impl ExceptionInfoResponse {
+    pub fn new(exception_id: impl Into<String>, break_mode: impl Into<ExceptionBreakMode>) -> Self {
+        ExceptionInfoResponse {
+            exception_id: exception_id.into(),
+            description: None,
+            break_mode: break_mode.into(),
+            details: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn details(mut self, details: impl Into<ExceptionDetails>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+}
+
+/**
+ * Response to 'readMemory' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadMemoryResponse {
+    /**
+     * The address of the first byte of data returned.
+     * Treated as a hex value if prefixed with '0x', or as a decimal value
+     * otherwise.
+     */
+    pub address: Address,
+
+    /**
+     * The number of unreadable bytes encountered after the last successfully
+     * read byte.
+     * This can be used to determine the number of bytes that should be skipped
+     * before a subsequent 'readMemory' request succeeds.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unreadable_bytes: Option<i64>,
+
+    /**
+     * The bytes read from memory, encoded using base64. If the decoded length
+     * of 'data' is less than the requested 'count' in the original 'readMemory'
+     * request, and 'unreadableBytes' is zero or omitted, then the client should
+     * assume it's reached the end of readable memory.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+}
+
+impl ReadMemoryResponse {
+    pub fn new(address: impl Into<Address>) -> Self {
+        ReadMemoryResponse {
+            address: address.into(),
+            unreadable_bytes: None,
+            data: None,
+        }
+    }
+
+    pub fn unreadable_bytes(mut self, unreadable_bytes: impl Into<i64>) -> Self {
+        self.unreadable_bytes = Some(unreadable_bytes.into());
+        self
+    }
+
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+}
+
+/**
+ * Response to 'writeMemory' request.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteMemoryResponse {
+    /**
+     * Property that should be returned when 'allowPartial' is true to indicate
+     * the offset of the first byte of data successfully written. Can be
+     * negative.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+
+    /**
+     * Property that should be returned when 'allowPartial' is true to indicate
+     * the number of bytes starting from address that were successfully written.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_written: Option<i64>,
+}
+
+impl WriteMemoryResponse {
+    pub fn offset(mut self, offset: impl Into<i64>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn bytes_written(mut self, bytes_written: impl Into<i64>) -> Self {
+        self.bytes_written = Some(bytes_written.into());
+        self
+    }
+}
+
+/**
+ * Response to 'disassemble' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisassembleResponse {
+    /**
+     * The list of disassembled instructions.
+     */
+    pub instructions: Vec<DisassembledInstruction>,
+}
+
+impl DisassembleResponse {
+    pub fn new(instructions: impl Into<Vec<DisassembledInstruction>>) -> Self {
+        DisassembleResponse {
+            instructions: instructions.into(),
+        }
+    }
+}
+
+/**
+ * Response to 'locations' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationsResponse {
+    /**
+     * The source containing the location; either 'source.path' or
+     * 'source.sourceReference' must be specified.
+     */
+    pub source: Source,
+
+    /**
+     * The line number of the location. The client capability 'linesStartAt1'
+     * determines whether it is 0- or 1-based.
+     */
+    pub line: i64,
+
+    /**
+     * Position of the location within the 'line'. It is measured in UTF-16 code
+     * units and the client capability 'columnsStartAt1' determines whether it
+     * is 0- or 1-based. If no column is given, the first position in the start
+     * line is assumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * End line of the location, present if the location refers to a range. The
+     * client capability 'linesStartAt1' determines whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<i64>,
+
+    /**
+     * End position of the location within 'endLine', present if the location
+     * refers to a range. It is measured in UTF-16 code units and the client
+     * capability 'columnsStartAt1' determines whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+}
+
+impl LocationsResponse {
+    pub fn new(source: impl Into<Source>, line: impl Into<i64>) -> Self {
+        LocationsResponse {
+            source: source.into(),
+            line: line.into(),
+            column: None,
+            end_line: None,
+            end_column: None,
+        }
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn end_line(mut self, end_line: impl Into<i64>) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    pub fn end_column(mut self, end_column: impl Into<i64>) -> Self {
+        self.end_column = Some(end_column.into());
+        self
+    }
+}
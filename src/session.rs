@@ -0,0 +1,467 @@
+/*!
+
+Tracks the lifecycle of a debug session and checks that messages arrive in the
+order the protocol requires:
+
+1. The client sends 'initialize' and the adapter returns its capabilities.
+2. The adapter sends the 'initialized' event, after which the client configures
+   breakpoints and finishes with 'configurationDone'.
+3. The client sends 'launch' or 'attach', possibly before step 2, and may then
+   inspect and control the debuggee until the adapter sends 'terminated'.
+4. The client sends 'disconnect' to end the session.
+
+The same [`Session`] works on either side of the connection: feed it the
+requests, responses and events as they are sent or received.
+
+*/
+
+use std::error::Error;
+use std::fmt;
+
+use crate::capabilities::Negotiated;
+use crate::events::EventBody;
+use crate::requests::{InitializeRequestArguments, RequestCommand};
+use crate::responses::{Response, ResponseBody};
+use crate::types::Capabilities;
+use crate::{MessageKind, ProtocolMessage};
+
+/**
+ * The stage a debug session is in.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub enum Phase {
+    /**
+     * The client has not sent 'initialize' yet.
+     */
+    #[default]
+    Uninitialized,
+
+    /**
+     * The client sent 'initialize' and waits for the response.
+     */
+    Initializing,
+
+    /**
+     * The adapter returned its capabilities, but has not sent the 'initialized'
+     * event yet.
+     */
+    Initialized,
+
+    /**
+     * The adapter sent the 'initialized' event, so the client may configure
+     * breakpoints until it sends 'configurationDone'.
+     */
+    Configuring,
+
+    /**
+     * The client sent 'configurationDone'.
+     */
+    Configured,
+
+    /**
+     * The adapter sent the 'terminated' event, so the debuggee is gone.
+     */
+    Terminated,
+
+    /**
+     * The client sent 'disconnect' and waits for the response.
+     */
+    Disconnecting,
+
+    /**
+     * The adapter answered 'disconnect', so the session is over.
+     */
+    Disconnected,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Phase::Uninitialized => "uninitialized",
+            Phase::Initializing => "initializing",
+            Phase::Initialized => "initialized",
+            Phase::Configuring => "configuring",
+            Phase::Configured => "configured",
+            Phase::Terminated => "terminated",
+            Phase::Disconnecting => "disconnecting",
+            Phase::Disconnected => "disconnected",
+        })
+    }
+}
+
+/**
+ * A message that is not allowed in the current phase of the session.
+ */
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Violation {
+    /**
+     * The request was sent before the response to 'initialize'.
+     */
+    NotInitialized { command: String },
+
+    /**
+     * 'initialize' was sent more than once.
+     */
+    AlreadyInitialized,
+
+    /**
+     * A configuration request was sent before the 'initialized' event.
+     */
+    NotConfigurable { command: String },
+
+    /**
+     * 'configurationDone' was sent more than once.
+     */
+    AlreadyConfigured,
+
+    /**
+     * 'launch' or 'attach' was sent more than once.
+     */
+    AlreadyLaunched { command: String },
+
+    /**
+     * The request needs a debuggee, but neither 'launch' nor 'attach' was sent.
+     */
+    NotLaunched { command: String },
+
+    /**
+     * The request needs a debuggee, but the adapter sent the 'terminated'
+     * event.
+     */
+    Terminated { command: String },
+
+    /**
+     * The message was sent after 'disconnect'.
+     */
+    Disconnected { message: String },
+
+    /**
+     * The event is not expected in the current phase.
+     */
+    UnexpectedEvent { event: String, phase: Phase },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::NotInitialized { command } => {
+                write!(f, "'{}' was sent before 'initialize' completed", command)
+            }
+            Violation::AlreadyInitialized => f.write_str("the session is already initialized"),
+            Violation::NotConfigurable { command } => {
+                write!(f, "'{}' was sent before the 'initialized' event", command)
+            }
+            Violation::AlreadyConfigured => f.write_str("the session is already configured"),
+            Violation::AlreadyLaunched { command } => write!(
+                f,
+                "'{}' was sent after the debuggee was launched or attached to",
+                command
+            ),
+            Violation::NotLaunched { command } => {
+                write!(f, "'{}' was sent before 'launch' or 'attach'", command)
+            }
+            Violation::Terminated { command } => {
+                write!(f, "'{}' was sent after the debuggee terminated", command)
+            }
+            Violation::Disconnected { message } => {
+                write!(f, "'{}' was sent after 'disconnect'", message)
+            }
+            Violation::UnexpectedEvent { event, phase } => {
+                write!(f, "unexpected '{}' event while {}", event, phase)
+            }
+        }
+    }
+}
+
+impl Error for Violation {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+enum Step {
+    #[default]
+    NotStarted,
+    Requested,
+    Done,
+}
+
+/**
+ * What a request needs from the session before it may be sent.
+ */
+enum Requirement {
+    Initialize,
+    Disconnect,
+    Configuration,
+    ConfigurationDone,
+    Launch,
+    Restart,
+    Debuggee,
+    Initialized,
+}
+
+fn requirement(command: &RequestCommand) -> Requirement {
+    match command {
+        RequestCommand::Initialize(_) => Requirement::Initialize,
+        RequestCommand::Disconnect(_) => Requirement::Disconnect,
+        RequestCommand::SetBreakpoints(_)
+        | RequestCommand::SetFunctionBreakpoints(_)
+        | RequestCommand::SetExceptionBreakpoints(_)
+        | RequestCommand::SetDataBreakpoints(_)
+        | RequestCommand::SetInstructionBreakpoints(_) => Requirement::Configuration,
+        RequestCommand::ConfigurationDone => Requirement::ConfigurationDone,
+        RequestCommand::Launch(_) | RequestCommand::Attach(_) => Requirement::Launch,
+        RequestCommand::Restart(_) => Requirement::Restart,
+        RequestCommand::Terminate(_)
+        | RequestCommand::Continue(_)
+        | RequestCommand::Next(_)
+        | RequestCommand::StepIn(_)
+        | RequestCommand::StepOut(_)
+        | RequestCommand::StepBack(_)
+        | RequestCommand::ReverseContinue(_)
+        | RequestCommand::RestartFrame(_)
+        | RequestCommand::Goto(_)
+        | RequestCommand::Pause(_)
+        | RequestCommand::StackTrace(_)
+        | RequestCommand::Scopes(_)
+        | RequestCommand::Variables(_)
+        | RequestCommand::SetVariable(_)
+        | RequestCommand::Threads
+        | RequestCommand::TerminateThreads(_)
+        | RequestCommand::Modules(_)
+        | RequestCommand::LoadedSources
+        | RequestCommand::SetExpression(_)
+        | RequestCommand::StepInTargets(_)
+        | RequestCommand::GotoTargets(_)
+        | RequestCommand::ExceptionInfo(_)
+        | RequestCommand::ReadMemory(_)
+        | RequestCommand::WriteMemory(_)
+        | RequestCommand::Disassemble(_)
+        | RequestCommand::Locations(_) => Requirement::Debuggee,
+        // These do not need a debuggee: 'evaluate' may run in a REPL without
+        // one, and reverse requests are only answered by the client.
+        RequestCommand::Cancel(_)
+        | RequestCommand::BreakpointLocations(_)
+        | RequestCommand::DataBreakpointInfo(_)
+        | RequestCommand::Source(_)
+        | RequestCommand::Evaluate(_)
+        | RequestCommand::Completions(_)
+        | RequestCommand::RunInTerminal(_)
+        | RequestCommand::StartDebugging(_)
+        | RequestCommand::Unknown { .. } => Requirement::Initialized,
+    }
+}
+
+/**
+ * The state of a debug session, updated from the messages exchanged between
+ * client and adapter.
+ *
+ * Requests and events are checked before they change the state, so a message
+ * that violates the protocol leaves the session as it was.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    initialize: Step,
+    initialized_event: bool,
+    configured: bool,
+    launch: Step,
+    terminated: bool,
+    disconnect: Step,
+    /**
+     * The arguments of 'initialize', until the adapter answers it.
+     */
+    client: Option<InitializeRequestArguments>,
+    negotiated: Option<Negotiated>,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Session::default()
+    }
+
+    /**
+     * The stage the session is in.
+     */
+    pub fn phase(&self) -> Phase {
+        match self.disconnect {
+            Step::Done => return Phase::Disconnected,
+            Step::Requested => return Phase::Disconnecting,
+            Step::NotStarted => {}
+        }
+        if self.terminated {
+            Phase::Terminated
+        } else if self.configured {
+            Phase::Configured
+        } else if self.initialized_event {
+            Phase::Configuring
+        } else {
+            match self.initialize {
+                Step::Done => Phase::Initialized,
+                Step::Requested => Phase::Initializing,
+                Step::NotStarted => Phase::Uninitialized,
+            }
+        }
+    }
+
+    /**
+     * Returns true once the client sent 'launch' or 'attach'.
+     */
+    pub fn is_launched(&self) -> bool {
+        self.launch != Step::NotStarted
+    }
+
+    /**
+     * The capabilities the adapter returned from 'initialize'.
+     */
+    pub fn capabilities(&self) -> Option<&Capabilities> {
+        self.negotiated
+            .as_ref()
+            .map(|negotiated| &negotiated.adapter)
+    }
+
+    /**
+     * The capabilities of both sides, once 'initialize' succeeded.
+     */
+    pub fn negotiated(&self) -> Option<&Negotiated> {
+        self.negotiated.as_ref()
+    }
+
+    /**
+     * Checks whether a request may be sent in the current phase, without
+     * recording it.
+     */
+    pub fn check_request(&self, command: &RequestCommand) -> Result<(), Violation> {
+        let name = || command.command().to_string();
+        if self.disconnect != Step::NotStarted {
+            return Err(Violation::Disconnected { message: name() });
+        }
+        let requirement = requirement(command);
+        match requirement {
+            Requirement::Initialize if self.initialize != Step::NotStarted => {
+                return Err(Violation::AlreadyInitialized)
+            }
+            Requirement::Initialize | Requirement::Disconnect => return Ok(()),
+            _ if self.initialize != Step::Done => {
+                return Err(Violation::NotInitialized { command: name() })
+            }
+            _ => {}
+        }
+        match requirement {
+            Requirement::Configuration | Requirement::ConfigurationDone
+                if !self.initialized_event =>
+            {
+                Err(Violation::NotConfigurable { command: name() })
+            }
+            Requirement::ConfigurationDone if self.configured => Err(Violation::AlreadyConfigured),
+            Requirement::Launch if self.is_launched() => {
+                Err(Violation::AlreadyLaunched { command: name() })
+            }
+            Requirement::Restart | Requirement::Debuggee if !self.is_launched() => {
+                Err(Violation::NotLaunched { command: name() })
+            }
+            // Restarting is how a client recovers from a terminated debuggee.
+            Requirement::Debuggee if self.terminated => {
+                Err(Violation::Terminated { command: name() })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /**
+     * Records a request sent by the client, or fails if it is not allowed in
+     * the current phase.
+     */
+    pub fn request(&mut self, command: &RequestCommand) -> Result<(), Violation> {
+        self.check_request(command)?;
+        match command {
+            RequestCommand::Initialize(arguments) => {
+                self.initialize = Step::Requested;
+                self.client = Some(arguments.clone());
+            }
+            RequestCommand::ConfigurationDone => self.configured = true,
+            RequestCommand::Launch(_) | RequestCommand::Attach(_) => self.launch = Step::Requested,
+            RequestCommand::Disconnect(_) => self.disconnect = Step::Requested,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /**
+     * Records the response to a request. A failed 'initialize', 'launch' or
+     * 'attach' may be sent again.
+     */
+    pub fn response(&mut self, response: &Response) {
+        match (response.command(), &response.result) {
+            ("initialize", Ok(body)) if self.initialize == Step::Requested => {
+                self.initialize = Step::Done;
+                if let (Some(client), ResponseBody::Initialize(body)) = (self.client.take(), body) {
+                    self.negotiated = Some(Negotiated::new(client, body.capabilities.clone()));
+                }
+            }
+            ("initialize", Err(_)) if self.initialize == Step::Requested => {
+                self.initialize = Step::NotStarted;
+            }
+            ("launch", result) | ("attach", result) if self.launch == Step::Requested => {
+                self.launch = match result {
+                    Ok(_) => Step::Done,
+                    Err(_) => Step::NotStarted,
+                };
+            }
+            ("restart", Ok(_)) => self.terminated = false,
+            ("disconnect", result) if self.disconnect == Step::Requested => {
+                self.disconnect = match result {
+                    Ok(_) => Step::Done,
+                    Err(_) => Step::NotStarted,
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /**
+     * Records an event sent by the adapter, or fails if it is not expected in
+     * the current phase.
+     */
+    pub fn event(&mut self, event: &EventBody) -> Result<(), Violation> {
+        let unexpected = || Violation::UnexpectedEvent {
+            event: event.event().to_string(),
+            phase: self.phase(),
+        };
+        if self.disconnect == Step::Done {
+            return Err(Violation::Disconnected {
+                message: event.event().to_string(),
+            });
+        }
+        match event {
+            EventBody::Initialized if self.initialize != Step::Done || self.initialized_event => {
+                Err(unexpected())
+            }
+            // Only the exit code may follow the end of the debuggee.
+            EventBody::Exited(_) => Ok(()),
+            _ if self.terminated => Err(unexpected()),
+            EventBody::Initialized => {
+                self.initialized_event = true;
+                Ok(())
+            }
+            EventBody::Terminated(_) => {
+                self.terminated = true;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /**
+     * Records any message, whichever side sent it.
+     */
+    pub fn observe(&mut self, message: &ProtocolMessage) -> Result<(), Violation> {
+        match &message.message {
+            MessageKind::Request(command) => self.request(command),
+            MessageKind::Response(response) => {
+                self.response(response);
+                Ok(())
+            }
+            MessageKind::Event(event) => match &event.body {
+                Some(body) => self.event(body),
+                None => Ok(()),
+            },
+        }
+    }
+}
@@ -0,0 +1,3317 @@
+// The definitions in this module are maintained by `cargo xtask codegen`, which
+// regenerates them from the Debug Adapter Protocol JSON schema. Do not edit
+// them by hand.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::{
+    Address, BreakpointId, FrameId, MemoryReference, ModuleId, SourceReference, ThreadId,
+    VariableCount, VariablesReference,
+};
+
+/**
+ * Information about the capabilities of a debug adapter.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    /**
+     * The debug adapter supports the 'configurationDone' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_configuration_done_request: Option<bool>,
+
+    /**
+     * The debug adapter supports function breakpoints.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_function_breakpoints: Option<bool>,
+
+    /**
+     * The debug adapter supports conditional breakpoints.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_conditional_breakpoints: Option<bool>,
+
+    /**
+     * The debug adapter supports breakpoints that break execution after a
+     * specified number of hits.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_hit_conditional_breakpoints: Option<bool>,
+
+    /**
+     * The debug adapter supports a (side effect free) 'evaluate' request for
+     * data hovers.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_evaluate_for_hovers: Option<bool>,
+
+    /**
+     * Available exception filter options for the 'setExceptionBreakpoints'
+     * request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exception_breakpoint_filters: Option<Vec<ExceptionBreakpointsFilter>>,
+
+    /**
+     * The debug adapter supports stepping back via the 'stepBack' and
+     * 'reverseContinue' requests.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_step_back: Option<bool>,
+
+    /**
+     * The debug adapter supports setting a variable to a value.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_set_variable: Option<bool>,
+
+    /**
+     * The debug adapter supports restarting a frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_restart_frame: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'gotoTargets' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_goto_targets_request: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'stepInTargets' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_step_in_targets_request: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'completions' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_completions_request: Option<bool>,
+
+    /**
+     * The set of characters that should trigger completion in a REPL. If not
+     * specified, the UI should assume the '.' character.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_trigger_characters: Option<Vec<String>>,
+
+    /**
+     * The debug adapter supports the 'modules' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_modules_request: Option<bool>,
+
+    /**
+     * The set of additional module information exposed by the debug adapter.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_module_columns: Option<Vec<ColumnDescriptor>>,
+
+    /**
+     * Checksum algorithms supported by the debug adapter.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_checksum_algorithms: Option<Vec<ChecksumAlgorithm>>,
+
+    /**
+     * The debug adapter supports the 'restart' request. In this case a client
+     * should not implement 'restart' by terminating and relaunching the adapter
+     * but by calling the 'restart' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_restart_request: Option<bool>,
+
+    /**
+     * The debug adapter supports 'exceptionOptions' on the
+     * 'setExceptionBreakpoints' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_exception_options: Option<bool>,
+
+    /**
+     * The debug adapter supports a 'format' attribute on the 'stackTrace',
+     * 'variables', and 'evaluate' requests.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_value_formatting_options: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'exceptionInfo' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_exception_info_request: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'terminateDebuggee' attribute on the
+     * 'disconnect' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_terminate_debuggee: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'suspendDebuggee' attribute on the
+     * 'disconnect' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_suspend_debuggee: Option<bool>,
+
+    /**
+     * The debug adapter supports the delayed loading of parts of the stack,
+     * which requires that both the 'startFrame' and 'levels' arguments and the
+     * 'totalFrames' result of the 'stackTrace' request are supported.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_delayed_stack_trace_loading: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'loadedSources' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_loaded_sources_request: Option<bool>,
+
+    /**
+     * The debug adapter supports log points by interpreting the 'logMessage'
+     * attribute of the 'SourceBreakpoint'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_log_points: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'terminateThreads' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_terminate_threads_request: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'setExpression' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_set_expression: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'terminate' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_terminate_request: Option<bool>,
+
+    /**
+     * The debug adapter supports data breakpoints.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_data_breakpoints: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'readMemory' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_read_memory_request: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'writeMemory' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_write_memory_request: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'disassemble' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_disassemble_request: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'cancel' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_cancel_request: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'breakpointLocations' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_breakpoint_locations_request: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'clipboard' context value in the
+     * 'evaluate' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_clipboard_context: Option<bool>,
+
+    /**
+     * The debug adapter supports stepping granularities (argument
+     * 'granularity') for the stepping requests.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_stepping_granularity: Option<bool>,
+
+    /**
+     * The debug adapter supports adding breakpoints based on instruction
+     * references.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_instruction_breakpoints: Option<bool>,
+
+    /**
+     * The debug adapter supports 'filterOptions' as an argument on the
+     * 'setExceptionBreakpoints' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_exception_filter_options: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'singleThread' property on the execution
+     * requests ('continue', 'next', 'stepIn', 'stepOut', 'reverseContinue',
+     * 'stepBack').
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_single_thread_execution_requests: Option<bool>,
+
+    /**
+     * The debug adapter supports the 'asAddress' and 'bytes' fields in the
+     * 'dataBreakpointInfo' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_data_breakpoint_bytes: Option<bool>,
+
+    /**
+     * Modes of breakpoints supported by the debug adapter, such as 'hardware'
+     * or 'software'. If present, the client may allow the user to select a mode
+     * and include it in its 'setBreakpoints' request.
+     *
+     * Clients may present the first applicable mode in this array as the
+     * 'default' mode in gestures that set breakpoints.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breakpoint_modes: Option<Vec<BreakpointMode>>,
+
+    /**
+     * The debug adapter supports ANSI escape sequences in styling of
+     * 'OutputEvent.output' and 'Variable.value' fields.
+     */
+    #[serde(
+        rename = "supportsANSIStyling",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub supports_ansi_styling: Option<bool>,
+}
+
+impl Capabilities {
+    pub fn supports_configuration_done_request(
+        mut self,
+        supports_configuration_done_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_configuration_done_request = Some(supports_configuration_done_request.into());
+        self
+    }
+
+    pub fn supports_function_breakpoints(
+        mut self,
+        supports_function_breakpoints: impl Into<bool>,
+    ) -> Self {
+        self.supports_function_breakpoints = Some(supports_function_breakpoints.into());
+        self
+    }
+
+    pub fn supports_conditional_breakpoints(
+        mut self,
+        supports_conditional_breakpoints: impl Into<bool>,
+    ) -> Self {
+        self.supports_conditional_breakpoints = Some(supports_conditional_breakpoints.into());
+        self
+    }
+
+    pub fn supports_hit_conditional_breakpoints(
+        mut self,
+        supports_hit_conditional_breakpoints: impl Into<bool>,
+    ) -> Self {
+        self.supports_hit_conditional_breakpoints =
+            Some(supports_hit_conditional_breakpoints.into());
+        self
+    }
+
+    pub fn supports_evaluate_for_hovers(
+        mut self,
+        supports_evaluate_for_hovers: impl Into<bool>,
+    ) -> Self {
+        self.supports_evaluate_for_hovers = Some(supports_evaluate_for_hovers.into());
+        self
+    }
+
+    pub fn exception_breakpoint_filters(
+        mut self,
+        exception_breakpoint_filters: impl Into<Vec<ExceptionBreakpointsFilter>>,
+    ) -> Self {
+        self.exception_breakpoint_filters = Some(exception_breakpoint_filters.into());
+        self
+    }
+
+    pub fn supports_step_back(mut self, supports_step_back: impl Into<bool>) -> Self {
+        self.supports_step_back = Some(supports_step_back.into());
+        self
+    }
+
+    pub fn supports_set_variable(mut self, supports_set_variable: impl Into<bool>) -> Self {
+        self.supports_set_variable = Some(supports_set_variable.into());
+        self
+    }
+
+    pub fn supports_restart_frame(mut self, supports_restart_frame: impl Into<bool>) -> Self {
+        self.supports_restart_frame = Some(supports_restart_frame.into());
+        self
+    }
+
+    pub fn supports_goto_targets_request(
+        mut self,
+        supports_goto_targets_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_goto_targets_request = Some(supports_goto_targets_request.into());
+        self
+    }
+
+    pub fn supports_step_in_targets_request(
+        mut self,
+        supports_step_in_targets_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_step_in_targets_request = Some(supports_step_in_targets_request.into());
+        self
+    }
+
+    pub fn supports_completions_request(
+        mut self,
+        supports_completions_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_completions_request = Some(supports_completions_request.into());
+        self
+    }
+
+    pub fn completion_trigger_characters(
+        mut self,
+        completion_trigger_characters: impl Into<Vec<String>>,
+    ) -> Self {
+        self.completion_trigger_characters = Some(completion_trigger_characters.into());
+        self
+    }
+
+    pub fn supports_modules_request(mut self, supports_modules_request: impl Into<bool>) -> Self {
+        self.supports_modules_request = Some(supports_modules_request.into());
+        self
+    }
+
+    pub fn additional_module_columns(
+        mut self,
+        additional_module_columns: impl Into<Vec<ColumnDescriptor>>,
+    ) -> Self {
+        self.additional_module_columns = Some(additional_module_columns.into());
+        self
+    }
+
+    pub fn supported_checksum_algorithms(
+        mut self,
+        supported_checksum_algorithms: impl Into<Vec<ChecksumAlgorithm>>,
+    ) -> Self {
+        self.supported_checksum_algorithms = Some(supported_checksum_algorithms.into());
+        self
+    }
+
+    pub fn supports_restart_request(mut self, supports_restart_request: impl Into<bool>) -> Self {
+        self.supports_restart_request = Some(supports_restart_request.into());
+        self
+    }
+
+    pub fn supports_exception_options(
+        mut self,
+        supports_exception_options: impl Into<bool>,
+    ) -> Self {
+        self.supports_exception_options = Some(supports_exception_options.into());
+        self
+    }
+
+    pub fn supports_value_formatting_options(
+        mut self,
+        supports_value_formatting_options: impl Into<bool>,
+    ) -> Self {
+        self.supports_value_formatting_options = Some(supports_value_formatting_options.into());
+        self
+    }
+
+    pub fn supports_exception_info_request(
+        mut self,
+        supports_exception_info_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_exception_info_request = Some(supports_exception_info_request.into());
+        self
+    }
+
+    pub fn support_terminate_debuggee(
+        mut self,
+        support_terminate_debuggee: impl Into<bool>,
+    ) -> Self {
+        self.support_terminate_debuggee = Some(support_terminate_debuggee.into());
+        self
+    }
+
+    pub fn support_suspend_debuggee(mut self, support_suspend_debuggee: impl Into<bool>) -> Self {
+        self.support_suspend_debuggee = Some(support_suspend_debuggee.into());
+        self
+    }
+
+    pub fn supports_delayed_stack_trace_loading(
+        mut self,
+        supports_delayed_stack_trace_loading: impl Into<bool>,
+    ) -> Self {
+        self.supports_delayed_stack_trace_loading =
+            Some(supports_delayed_stack_trace_loading.into());
+        self
+    }
+
+    pub fn supports_loaded_sources_request(
+        mut self,
+        supports_loaded_sources_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_loaded_sources_request = Some(supports_loaded_sources_request.into());
+        self
+    }
+
+    pub fn supports_log_points(mut self, supports_log_points: impl Into<bool>) -> Self {
+        self.supports_log_points = Some(supports_log_points.into());
+        self
+    }
+
+    pub fn supports_terminate_threads_request(
+        mut self,
+        supports_terminate_threads_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_terminate_threads_request = Some(supports_terminate_threads_request.into());
+        self
+    }
+
+    pub fn supports_set_expression(mut self, supports_set_expression: impl Into<bool>) -> Self {
+        self.supports_set_expression = Some(supports_set_expression.into());
+        self
+    }
+
+    pub fn supports_terminate_request(
+        mut self,
+        supports_terminate_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_terminate_request = Some(supports_terminate_request.into());
+        self
+    }
+
+    pub fn supports_data_breakpoints(mut self, supports_data_breakpoints: impl Into<bool>) -> Self {
+        self.supports_data_breakpoints = Some(supports_data_breakpoints.into());
+        self
+    }
+
+    pub fn supports_read_memory_request(
+        mut self,
+        supports_read_memory_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_read_memory_request = Some(supports_read_memory_request.into());
+        self
+    }
+
+    pub fn supports_write_memory_request(
+        mut self,
+        supports_write_memory_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_write_memory_request = Some(supports_write_memory_request.into());
+        self
+    }
+
+    pub fn supports_disassemble_request(
+        mut self,
+        supports_disassemble_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_disassemble_request = Some(supports_disassemble_request.into());
+        self
+    }
+
+    pub fn supports_cancel_request(mut self, supports_cancel_request: impl Into<bool>) -> Self {
+        self.supports_cancel_request = Some(supports_cancel_request.into());
+        self
+    }
+
+    pub fn supports_breakpoint_locations_request(
+        mut self,
+        supports_breakpoint_locations_request: impl Into<bool>,
+    ) -> Self {
+        self.supports_breakpoint_locations_request =
+            Some(supports_breakpoint_locations_request.into());
+        self
+    }
+
+    pub fn supports_clipboard_context(
+        mut self,
+        supports_clipboard_context: impl Into<bool>,
+    ) -> Self {
+        self.supports_clipboard_context = Some(supports_clipboard_context.into());
+        self
+    }
+
+    pub fn supports_stepping_granularity(
+        mut self,
+        supports_stepping_granularity: impl Into<bool>,
+    ) -> Self {
+        self.supports_stepping_granularity = Some(supports_stepping_granularity.into());
+        self
+    }
+
+    pub fn supports_instruction_breakpoints(
+        mut self,
+        supports_instruction_breakpoints: impl Into<bool>,
+    ) -> Self {
+        self.supports_instruction_breakpoints = Some(supports_instruction_breakpoints.into());
+        self
+    }
+
+    pub fn supports_exception_filter_options(
+        mut self,
+        supports_exception_filter_options: impl Into<bool>,
+    ) -> Self {
+        self.supports_exception_filter_options = Some(supports_exception_filter_options.into());
+        self
+    }
+
+    pub fn supports_single_thread_execution_requests(
+        mut self,
+        supports_single_thread_execution_requests: impl Into<bool>,
+    ) -> Self {
+        self.supports_single_thread_execution_requests =
+            Some(supports_single_thread_execution_requests.into());
+        self
+    }
+
+    pub fn supports_data_breakpoint_bytes(
+        mut self,
+        supports_data_breakpoint_bytes: impl Into<bool>,
+    ) -> Self {
+        self.supports_data_breakpoint_bytes = Some(supports_data_breakpoint_bytes.into());
+        self
+    }
+
+    pub fn breakpoint_modes(mut self, breakpoint_modes: impl Into<Vec<BreakpointMode>>) -> Self {
+        self.breakpoint_modes = Some(breakpoint_modes.into());
+        self
+    }
+
+    pub fn supports_ansi_styling(mut self, supports_ansi_styling: impl Into<bool>) -> Self {
+        self.supports_ansi_styling = Some(supports_ansi_styling.into());
+        self
+    }
+}
+
+/**
+ * An 'ExceptionBreakpointsFilter' is shown in the UI as an filter option for
+ * configuring how exceptions are dealt with.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExceptionBreakpointsFilter {
+    /**
+     * The internal ID of the filter option. This value is passed to the
+     * 'setExceptionBreakpoints' request.
+     */
+    pub filter: String,
+
+    /**
+     * The name of the filter option. This is shown in the UI.
+     */
+    pub label: String,
+
+    /**
+     * A help text providing additional information about the exception filter.
+     * This string is typically shown as a hover and can be translated.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /**
+     * Initial value of the filter option. If not specified a value false is
+     * assumed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+
+    /**
+     * Controls whether a condition can be specified for this filter option. If
+     * false or missing, a condition can not be set.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_condition: Option<bool>,
+
+    /**
+     * A help text providing information about the condition. This string is
+     * shown as the placeholder text for a text box and can be translated.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition_description: Option<String>,
+}
+
+impl ExceptionBreakpointsFilter {
+    pub fn new(filter: impl Into<String>, label: impl Into<String>) -> Self {
+        ExceptionBreakpointsFilter {
+            filter: filter.into(),
+            label: label.into(),
+            description: None,
+            default: None,
+            supports_condition: None,
+            condition_description: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn default(mut self, default: impl Into<bool>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    pub fn supports_condition(mut self, supports_condition: impl Into<bool>) -> Self {
+        self.supports_condition = Some(supports_condition.into());
+        self
+    }
+
+    pub fn condition_description(mut self, condition_description: impl Into<String>) -> Self {
+        self.condition_description = Some(condition_description.into());
+        self
+    }
+}
+
+/**
+ * A structured message object. Used to return errors from requests.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    /**
+     * Unique (within a debug adapter implementation) identifier for the
+     * message. The purpose of these error IDs is to help extension authors that
+     * have the requirement that every user visible error message needs a
+     * corresponding error number, so that users or customer support can find
+     * information about the specific error more easily.
+     */
+    pub id: i64,
+
+    /**
+     * A format string for the message. Embedded variables have the form
+     * '{name}'.
+     * If variable name starts with an underscore character, the variable does
+     * not contain user data (PII) and can be safely used for telemetry
+     * purposes.
+     */
+    pub format: String,
+
+    /**
+     * An object used as a dictionary for looking up the variables in the format
+     * string.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<HashMap<String, String>>,
+
+    /**
+     * If true send to telemetry.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_telemetry: Option<bool>,
+
+    /**
+     * If true show user.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_user: Option<bool>,
+
+    /**
+     * A url where additional information about this message can be found.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /**
+     * A label that is presented to the user as the UI for opening the url.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_label: Option<String>,
+}
+
+impl Message {
+    pub fn new(id: impl Into<i64>, format: impl Into<String>) -> Self {
+        Message {
+            id: id.into(),
+            format: format.into(),
+            variables: None,
+            send_telemetry: None,
+            show_user: None,
+            url: None,
+            url_label: None,
+        }
+    }
+
+    pub fn variables(mut self, variables: impl Into<HashMap<String, String>>) -> Self {
+        self.variables = Some(variables.into());
+        self
+    }
+
+    pub fn send_telemetry(mut self, send_telemetry: impl Into<bool>) -> Self {
+        self.send_telemetry = Some(send_telemetry.into());
+        self
+    }
+
+    pub fn show_user(mut self, show_user: impl Into<bool>) -> Self {
+        self.show_user = Some(show_user.into());
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn url_label(mut self, url_label: impl Into<String>) -> Self {
+        self.url_label = Some(url_label.into());
+        self
+    }
+}
+
+/**
+ * A Module object represents a row in the modules view.
+ * The 'id' attribute identifies a module in the modules view and is used in a
+ * 'module' event for identifying a module for adding, updating or deleting.
+ * The 'name' attribute is used to minimally render the module in the UI.
+ *
+ * Additional attributes can be added to the module. They show up in the module
+ * view if they have a corresponding 'ColumnDescriptor'.
+ *
+ * To avoid an unnecessary proliferation of additional attributes with similar
+ * semantics but different names, we recommend to re-use attributes from the
+ * 'recommended' list below first, and only introduce new attributes if nothing
+ * appropriate could be found.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Module {
+    /**
+     * Unique identifier for the module.
+     */
+    pub id: ModuleId,
+
+    /**
+     * A name of the module.
+     */
+    pub name: String,
+
+    /**
+     * Logical full path to the module. The exact definition is implementation
+     * defined, but usually this would be a full path to the on-disk file for
+     * the module.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /**
+     * True if the module is optimized.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_optimized: Option<bool>,
+
+    /**
+     * True if the module is considered 'user code' by a debugger that supports
+     * 'Just My Code'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_user_code: Option<bool>,
+
+    /**
+     * Version of Module.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /**
+     * User-understandable description of if symbols were found for the module
+     * (ex: 'Symbols Loaded', 'Symbols not found', etc.)
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_status: Option<String>,
+
+    /**
+     * Logical full path to the symbol file. The exact definition is
+     * implementation defined.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_file_path: Option<String>,
+
+    /**
+     * Module created or modified, encoded as a RFC 3339 timestamp.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_time_stamp: Option<String>,
+
+    /**
+     * Address range covered by this module.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_range: Option<String>,
+}
+
+impl Module {
+    pub fn new(id: impl Into<ModuleId>, name: impl Into<String>) -> Self {
+        Module {
+            id: id.into(),
+            name: name.into(),
+            path: None,
+            is_optimized: None,
+            is_user_code: None,
+            version: None,
+            symbol_status: None,
+            symbol_file_path: None,
+            date_time_stamp: None,
+            address_range: None,
+        }
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    pub fn is_optimized(mut self, is_optimized: impl Into<bool>) -> Self {
+        self.is_optimized = Some(is_optimized.into());
+        self
+    }
+
+    pub fn is_user_code(mut self, is_user_code: impl Into<bool>) -> Self {
+        self.is_user_code = Some(is_user_code.into());
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn symbol_status(mut self, symbol_status: impl Into<String>) -> Self {
+        self.symbol_status = Some(symbol_status.into());
+        self
+    }
+
+    pub fn symbol_file_path(mut self, symbol_file_path: impl Into<String>) -> Self {
+        self.symbol_file_path = Some(symbol_file_path.into());
+        self
+    }
+
+    pub fn date_time_stamp(mut self, date_time_stamp: impl Into<String>) -> Self {
+        self.date_time_stamp = Some(date_time_stamp.into());
+        self
+    }
+
+    pub fn address_range(mut self, address_range: impl Into<String>) -> Self {
+        self.address_range = Some(address_range.into());
+        self
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ColumnType {
+    String,
+    Number,
+    Boolean,
+    UnixTimestampUTC,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * A 'ColumnDescriptor' specifies what module attribute to show in a column of
+ * the modules view, how to format it,
+ * and what the column's label should be.
+ * It is only used if the underlying UI actually supports this level of
+ * customization.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnDescriptor {
+    /**
+     * Name of the attribute rendered in this column.
+     */
+    pub attribute_name: String,
+
+    /**
+     * Header UI label of column.
+     */
+    pub label: String,
+
+    /**
+     * Format to use for the rendered values in this column. TBD how the format
+     * strings looks like.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /**
+     * Datatype of values in this column. Defaults to 'string' if not specified.
+     * Values: 'string', 'number', 'boolean', 'unixTimestampUTC'
+     */
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub column_type: Option<ColumnType>,
+
+    /**
+     * Width of this column in characters (hint only).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+}
+
+impl ColumnDescriptor {
+    pub fn new(attribute_name: impl Into<String>, label: impl Into<String>) -> Self {
+        ColumnDescriptor {
+            attribute_name: attribute_name.into(),
+            label: label.into(),
+            format: None,
+            column_type: None,
+            width: None,
+        }
+    }
+
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    pub fn column_type(mut self, column_type: impl Into<ColumnType>) -> Self {
+        self.column_type = Some(column_type.into());
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<i64>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+}
+
+/**
+ * A Thread
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Thread {
+    /**
+     * Unique identifier for the thread.
+     */
+    pub id: ThreadId,
+
+    /**
+     * The name of the thread.
+     */
+    pub name: String,
+}
+
+impl Thread {
+    pub fn new(id: impl Into<ThreadId>, name: impl Into<String>) -> Self {
+        Thread {
+            id: id.into(),
+            name: name.into(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PresentationHint {
+    Normal,
+    Emphasize,
+    Deemphasize,
+    Label,
+    Subtle,
+    Arguments,
+    Locals,
+    Registers,
+    ReturnValue,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * A 'Source' is a descriptor for source code.
+ * It is returned from the debug adapter as part of a 'StackFrame' and it is
+ * used by clients when specifying breakpoints.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Source {
+    /**
+     * The short name of the source. Every source returned from the debug
+     * adapter has a name.
+     * When sending a source to the debug adapter this name is optional.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /**
+     * The path of the source to be shown in the UI.
+     * It is only used to locate and load the content of the source if no
+     * 'sourceReference' is specified (or its value is 0).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /**
+     * If the value > 0 the contents of the source must be retrieved through the
+     * 'source' request (even if a path is specified).
+     * Since a 'sourceReference' is only valid for a session, it can not be used
+     * to persist a source.
+     * The value should be less than or equal to 2147483647 (2^31-1).
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_reference: Option<SourceReference>,
+
+    /**
+     * A hint for how to present the source in the UI.
+     * A value of 'deemphasize' can be used to indicate that the source is not
+     * available or that it is skipped on stepping.
+     * Values: 'normal', 'emphasize', 'deemphasize'
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation_hint: Option<PresentationHint>,
+
+    /**
+     * The origin of this source. For example, 'internal module', 'inlined
+     * content from source map', etc.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+
+    /**
+     * A list of sources that are related to this source. These may be the
+     * source that generated this source.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<Source>>,
+
+    /**
+     * Additional data that a debug adapter might want to loop through the
+     * client.
+     * The client should leave the data intact and persist it across sessions.
+     * The client should not interpret the data.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adapter_data: Option<Value>,
+
+    /**
+     * The checksums associated with this file.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksums: Option<Vec<Checksum>>,
+}
+
+impl Source {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    pub fn source_reference(mut self, source_reference: impl Into<SourceReference>) -> Self {
+        self.source_reference = Some(source_reference.into());
+        self
+    }
+
+    pub fn presentation_hint(mut self, presentation_hint: impl Into<PresentationHint>) -> Self {
+        self.presentation_hint = Some(presentation_hint.into());
+        self
+    }
+
+    pub fn origin(mut self, origin: impl Into<String>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
+
+    pub fn sources(mut self, sources: impl Into<Vec<Source>>) -> Self {
+        self.sources = Some(sources.into());
+        self
+    }
+
+    pub fn adapter_data(mut self, adapter_data: impl Into<Value>) -> Self {
+        self.adapter_data = Some(adapter_data.into());
+        self
+    }
+
+    pub fn checksums(mut self, checksums: impl Into<Vec<Checksum>>) -> Self {
+        self.checksums = Some(checksums.into());
+        self
+    }
+}
+
+/**
+ * A Stackframe contains the source location.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StackFrame {
+    /**
+     * An identifier for the stack frame. It must be unique across all threads.
+     * This id can be used to retrieve the scopes of the frame with the 'scopes'
+     * request or to restart the execution of a stack frame.
+     */
+    pub id: FrameId,
+
+    /**
+     * The name of the stack frame, typically a method name.
+     */
+    pub name: String,
+
+    /**
+     * The source of the frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+
+    /**
+     * The line within the source of the frame. If the source attribute is
+     * missing or doesn't exist, 'line' is 0 and should be ignored by the
+     * client.
+     */
+    pub line: i64,
+
+    /**
+     * Start position of the range covered by the stack frame. It is measured in
+     * UTF-16 code units and the client capability 'columnsStartAt1' determines
+     * whether it is 0- or 1-based. If attribute 'source' is missing or doesn't
+     * exist, 'column' is 0 and should be ignored by the client.
+     */
+    pub column: i64,
+
+    /**
+     * The end line of the range covered by the stack frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<i64>,
+
+    /**
+     * End position of the range covered by the stack frame. It is measured in
+     * UTF-16 code units and the client capability 'columnsStartAt1' determines
+     * whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+
+    /**
+     * Indicates whether this frame can be restarted with the 'restart' request.
+     * Clients should only use this if the debug adapter supports the 'restart'
+     * request and the corresponding capability 'supportsRestartRequest' is
+     * true. If a debug adapter has this capability, then 'canRestart' defaults
+     * to 'true' if the property is absent.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_restart: Option<bool>,
+
+    /**
+     * A memory reference for the current instruction pointer in this frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instruction_pointer_reference: Option<MemoryReference>,
+
+    /**
+     * The module associated with this frame, if any.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_id: Option<ModuleId>,
+
+    /**
+     * A hint for how to present this frame in the UI.
+     * A value of 'label' can be used to indicate that the frame is an
+     * artificial frame that is used as a visual label or separator. A value of
+     * 'subtle' can be used to change the appearance of a frame in a 'subtle'
+     * way.
+     * Values: 'normal', 'label', 'subtle'
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation_hint: Option<PresentationHint>,
+}
+
+impl StackFrame {
+    pub fn new(
+        id: impl Into<FrameId>,
+        name: impl Into<String>,
+        line: impl Into<i64>,
+        column: impl Into<i64>,
+    ) -> Self {
+        StackFrame {
+            id: id.into(),
+            name: name.into(),
+            source: None,
+            line: line.into(),
+            column: column.into(),
+            end_line: None,
+            end_column: None,
+            can_restart: None,
+            instruction_pointer_reference: None,
+            module_id: None,
+            presentation_hint: None,
+        }
+    }
+
+    pub fn source(mut self, source: impl Into<Source>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn end_line(mut self, end_line: impl Into<i64>) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    pub fn end_column(mut self, end_column: impl Into<i64>) -> Self {
+        self.end_column = Some(end_column.into());
+        self
+    }
+
+    pub fn can_restart(mut self, can_restart: impl Into<bool>) -> Self {
+        self.can_restart = Some(can_restart.into());
+        self
+    }
+
+    pub fn instruction_pointer_reference(
+        mut self,
+        instruction_pointer_reference: impl Into<MemoryReference>,
+    ) -> Self {
+        self.instruction_pointer_reference = Some(instruction_pointer_reference.into());
+        self
+    }
+
+    pub fn module_id(mut self, module_id: impl Into<ModuleId>) -> Self {
+        self.module_id = Some(module_id.into());
+        self
+    }
+
+    pub fn presentation_hint(mut self, presentation_hint: impl Into<PresentationHint>) -> Self {
+        self.presentation_hint = Some(presentation_hint.into());
+        self
+    }
+}
+
+/**
+ * A 'Scope' is a named container for variables. Optionally a scope can map to a
+ * source or a range within a source.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Scope {
+    /**
+     * Name of the scope such as 'Arguments', 'Locals', or 'Registers'. This
+     * string is shown in the UI as is and can be translated.
+     */
+    pub name: String,
+
+    /**
+     * A hint for how to present this scope in the UI. If this attribute is
+     * missing, the scope is shown with a generic UI.
+     * Values:
+     * 'arguments': Scope contains method arguments.
+     * 'locals': Scope contains local variables.
+     * 'registers': Scope contains registers. Only a single 'registers' scope
+     * should be returned from a 'scopes' request.
+     * 'returnValue': Scope contains one or more return values.
+     * etc.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation_hint: Option<PresentationHint>,
+
+    /**
+     * The variables of this scope can be retrieved by passing the value of
+     * 'variablesReference' to the 'variables' request as long as execution
+     * remains suspended. See 'Lifetime of Object References' in the Overview
+     * section for details.
+     */
+    pub variables_reference: VariablesReference,
+
+    /**
+     * The number of named variables in this scope.
+     * The client can use this information to present the variables in a paged
+     * UI and fetch them in chunks.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub named_variables: Option<VariableCount>,
+
+    /**
+     * The number of indexed variables in this scope.
+     * The client can use this information to present the variables in a paged
+     * UI and fetch them in chunks.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexed_variables: Option<VariableCount>,
+
+    /**
+     * If true, the number of variables in this scope is large or expensive to
+     * retrieve.
+     */
+    pub expensive: bool,
+
+    /**
+     * The source for this scope.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+
+    /**
+     * The start line of the range covered by this scope.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<i64>,
+
+    /**
+     * Start position of the range covered by the scope. It is measured in
+     * UTF-16 code units and the client capability 'columnsStartAt1' determines
+     * whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * The end line of the range covered by this scope.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<i64>,
+
+    /**
+     * End position of the range covered by the scope. It is measured in UTF-16
+     * code units and the client capability 'columnsStartAt1' determines whether
+     * it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+}
+
+impl Scope {
+    pub fn new(
+        name: impl Into<String>,
+        variables_reference: impl Into<VariablesReference>,
+        expensive: impl Into<bool>,
+    ) -> Self {
+        Scope {
+            name: name.into(),
+            presentation_hint: None,
+            variables_reference: variables_reference.into(),
+            named_variables: None,
+            indexed_variables: None,
+            expensive: expensive.into(),
+            source: None,
+            line: None,
+            column: None,
+            end_line: None,
+            end_column: None,
+        }
+    }
+
+    pub fn presentation_hint(mut self, presentation_hint: impl Into<PresentationHint>) -> Self {
+        self.presentation_hint = Some(presentation_hint.into());
+        self
+    }
+
+    pub fn named_variables(mut self, named_variables: impl Into<VariableCount>) -> Self {
+        self.named_variables = Some(named_variables.into());
+        self
+    }
+
+    pub fn indexed_variables(mut self, indexed_variables: impl Into<VariableCount>) -> Self {
+        self.indexed_variables = Some(indexed_variables.into());
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<Source>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn line(mut self, line: impl Into<i64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn end_line(mut self, end_line: impl Into<i64>) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    pub fn end_column(mut self, end_column: impl Into<i64>) -> Self {
+        self.end_column = Some(end_column.into());
+        self
+    }
+}
+
+/**
+ * A Variable is a name/value pair.
+ * The 'type' attribute is shown if space permits or when hovering over the
+ * variable's name.
+ * The 'kind' attribute is used to render additional properties of the variable,
+ * e.g. different icons can be used to indicate that a variable is public or
+ * private.
+ * If the value is structured (has children), a handle is provided to retrieve
+ * the children with the 'variables' request.
+ * If the number of named or indexed children is large, the numbers should be
+ * returned via the 'namedVariables' and 'indexedVariables' attributes.
+ * The client can use this information to present the children in a paged UI and
+ * fetch them in chunks.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Variable {
+    /**
+     * The variable's name.
+     */
+    pub name: String,
+
+    /**
+     * The variable's value.
+     * This can be a multi-line text, e.g. for a function the body of a
+     * function.
+     * For structured variables (which do not have a simple value), it is
+     * recommended to provide a one-line representation of the structured
+     * object. This helps to identify the structured object in the collapsed
+     * state when its children are not yet visible.
+     * An empty string can be used if no value should be shown in the UI.
+     */
+    pub value: String,
+
+    /**
+     * The type of the variable's value. Typically shown in the UI when hovering
+     * over the value.
+     * This attribute should only be returned by a debug adapter if the
+     * corresponding capability 'supportsVariableType' is true.
+     */
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub var_type: Option<String>,
+
+    /**
+     * Properties of a variable that can be used to determine how to render the
+     * variable in the UI.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation_hint: Option<VariablePresentationHint>,
+
+    /**
+     * The evaluatable name of this variable which can be passed to the
+     * 'evaluate' request to fetch the variable's value.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluate_name: Option<String>,
+
+    /**
+     * If 'variablesReference' is > 0, the variable is structured and its
+     * children can be retrieved by passing 'variablesReference' to the
+     * 'variables' request as long as execution remains suspended. See 'Lifetime
+     * of Object References' in the Overview section for details.
+     */
+    pub variables_reference: VariablesReference,
+
+    /**
+     * The number of named child variables.
+     * The client can use this information to present the children in a paged UI
+     * and fetch them in chunks.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub named_variables: Option<VariableCount>,
+
+    /**
+     * The number of indexed child variables.
+     * The client can use this information to present the children in a paged UI
+     * and fetch them in chunks.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexed_variables: Option<VariableCount>,
+
+    /**
+     * A memory reference associated with this variable.
+     * For pointer type variables, this is generally a reference to the memory
+     * address contained in the pointer.
+     * For executable data, this reference may later be used in a 'disassemble'
+     * request.
+     * This attribute may be returned by a debug adapter if corresponding
+     * capability 'supportsMemoryReferences' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_reference: Option<MemoryReference>,
+
+    /**
+     * A reference that allows the client to request the location where the
+     * variable is declared. This should be present only if the adapter is
+     * likely to be able to resolve the location.
+     *
+     * This reference shares the same lifetime as the 'variablesReference'. See
+     * 'Lifetime of Object References' in the Overview section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub declaration_location_reference: Option<i64>,
+
+    /**
+     * A reference that allows the client to request the location where the
+     * variable's value is declared. For example, if the variable contains a
+     * function pointer, the adapter may be able to look up the function's
+     * location. This should be present only if the adapter is likely to be able
+     * to resolve the location.
+     *
+     * This reference shares the same lifetime as the 'variablesReference'. See
+     * 'Lifetime of Object References' in the Overview section for details.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_location_reference: Option<i64>,
+}
+
+impl Variable {
+    pub fn new(
+        name: impl Into<String>,
+        value: impl Into<String>,
+        variables_reference: impl Into<VariablesReference>,
+    ) -> Self {
+        Variable {
+            name: name.into(),
+            value: value.into(),
+            var_type: None,
+            presentation_hint: None,
+            evaluate_name: None,
+            variables_reference: variables_reference.into(),
+            named_variables: None,
+            indexed_variables: None,
+            memory_reference: None,
+            declaration_location_reference: None,
+            value_location_reference: None,
+        }
+    }
+
+    pub fn var_type(mut self, var_type: impl Into<String>) -> Self {
+        self.var_type = Some(var_type.into());
+        self
+    }
+
+    pub fn presentation_hint(
+        mut self,
+        presentation_hint: impl Into<VariablePresentationHint>,
+    ) -> Self {
+        self.presentation_hint = Some(presentation_hint.into());
+        self
+    }
+
+    pub fn evaluate_name(mut self, evaluate_name: impl Into<String>) -> Self {
+        self.evaluate_name = Some(evaluate_name.into());
+        self
+    }
+
+    pub fn named_variables(mut self, named_variables: impl Into<VariableCount>) -> Self {
+        self.named_variables = Some(named_variables.into());
+        self
+    }
+
+    pub fn indexed_variables(mut self, indexed_variables: impl Into<VariableCount>) -> Self {
+        self.indexed_variables = Some(indexed_variables.into());
+        self
+    }
+
+    pub fn memory_reference(mut self, memory_reference: impl Into<MemoryReference>) -> Self {
+        self.memory_reference = Some(memory_reference.into());
+        self
+    }
+
+    pub fn declaration_location_reference(
+        mut self,
+        declaration_location_reference: impl Into<i64>,
+    ) -> Self {
+        self.declaration_location_reference = Some(declaration_location_reference.into());
+        self
+    }
+
+    pub fn value_location_reference(mut self, value_location_reference: impl Into<i64>) -> Self {
+        self.value_location_reference = Some(value_location_reference.into());
+        self
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VariableKind {
+    Property,
+    Method,
+    Class,
+    Data,
+    Event,
+    BaseClass,
+    InnerClass,
+    Interface,
+    MostDerivedClass,
+    Virtual,
+    DataBreakpoint,
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VariableAttribute {
+    Static,
+    Constant,
+    ReadOnly,
+    RawString,
+    HasObjectId,
+    CanHaveObjectId,
+    HasSideEffects,
+    HasDataBreakpoint,
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Visibility {
+    Public,
+    Private,
+    Protected,
+    Internal,
+    Final,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * Properties of a variable that can be used to determine how to render the
+ * variable in the UI.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariablePresentationHint {
+    /**
+     * The kind of variable. Before introducing additional values, try to use
+     * the listed values.
+     * Values:
+     * 'property': Indicates that the object is a property.
+     * 'method': Indicates that the object is a method.
+     * 'class': Indicates that the object is a class.
+     * 'data': Indicates that the object is data.
+     * 'event': Indicates that the object is an event.
+     * 'baseClass': Indicates that the object is a base class.
+     * 'innerClass': Indicates that the object is an inner class.
+     * 'interface': Indicates that the object is an interface.
+     * 'mostDerivedClass': Indicates that the object is the most derived class.
+     * 'virtual': Indicates that the object is virtual, that means it is a
+     * synthetic object introduced by the adapter for rendering purposes, e.g.
+     * an index range for large arrays.
+     * 'dataBreakpoint': Deprecated: Indicates that a data breakpoint is
+     * registered for the object. The 'hasDataBreakpoint' attribute should
+     * generally be used instead.
+     * etc.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<VariableKind>,
+
+    /**
+     * Set of attributes represented as an array of strings. Before introducing
+     * additional values, try to use the listed values.
+     * Values:
+     * 'static': Indicates that the object is static.
+     * 'constant': Indicates that the object is a constant.
+     * 'readOnly': Indicates that the object is read only.
+     * 'rawString': Indicates that the object is a raw string.
+     * 'hasObjectId': Indicates that the object can have an Object ID created
+     * for it. This is a vestigial attribute that is used by some clients;
+     * 'Object ID's are not specified in the protocol.
+     * 'canHaveObjectId': Indicates that the object has an Object ID associated
+     * with it. This is a vestigial attribute that is used by some clients;
+     * 'Object ID's are not specified in the protocol.
+     * 'hasSideEffects': Indicates that the evaluation had side effects.
+     * 'hasDataBreakpoint': Indicates that the object has its value tracked by a
+     * data breakpoint.
+     * etc.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<VariableAttribute>>,
+
+    /**
+     * Visibility of variable. Before introducing additional values, try to use
+     * the listed values.
+     * Values: 'public', 'private', 'protected', 'internal', 'final', etc.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+
+    /**
+     * If true, clients can present the variable with a UI that supports a
+     * specific gesture to trigger its evaluation.
+     * This mechanism can be used for properties that require executing code
+     * when retrieving their value and where the code execution can be expensive
+     * and/or produce side-effects. A typical example are properties based on a
+     * getter function.
+     * Please note that in addition to the 'lazy' flag, the variable's
+     * 'variablesReference' is expected to refer to a variable that will provide
+     * the value through another 'variable' request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lazy: Option<bool>,
+}
+
+impl VariablePresentationHint {
+    pub fn kind(mut self, kind: impl Into<VariableKind>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    pub fn attributes(mut self, attributes: impl Into<Vec<VariableAttribute>>) -> Self {
+        self.attributes = Some(attributes.into());
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<Visibility>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn lazy(mut self, lazy: impl Into<bool>) -> Self {
+        self.lazy = Some(lazy.into());
+        self
+    }
+}
+
+/**
+ * Properties of a breakpoint location returned from the 'breakpointLocations'
+ * request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakpointLocation {
+    /**
+     * Start line of breakpoint location.
+     */
+    pub line: i64,
+
+    /**
+     * The start position of a breakpoint location. Position is measured in
+     * UTF-16 code units and the client capability 'columnsStartAt1' determines
+     * whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * The end line of breakpoint location if the location covers a range.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<i64>,
+
+    /**
+     * The end position of a breakpoint location (if the location covers a
+     * range). Position is measured in UTF-16 code units and the client
+     * capability 'columnsStartAt1' determines whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+}
+
+impl BreakpointLocation {
+    pub fn new(line: impl Into<i64>) -> Self {
+        BreakpointLocation {
+            line: line.into(),
+            column: None,
+            end_line: None,
+            end_column: None,
+        }
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn end_line(mut self, end_line: impl Into<i64>) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    pub fn end_column(mut self, end_column: impl Into<i64>) -> Self {
+        self.end_column = Some(end_column.into());
+        self
+    }
+}
+
+/**
+ * Properties of a breakpoint or logpoint passed to the 'setBreakpoints'
+ * request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceBreakpoint {
+    /**
+     * The source line of the breakpoint or logpoint.
+     */
+    pub line: i64,
+
+    /**
+     * Start position within source line of the breakpoint or logpoint. It is
+     * measured in UTF-16 code units and the client capability 'columnsStartAt1'
+     * determines whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * The expression for conditional breakpoints.
+     * It is only honored by a debug adapter if the corresponding capability
+     * 'supportsConditionalBreakpoints' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+
+    /**
+     * The expression that controls how many hits of the breakpoint are ignored.
+     * The debug adapter is expected to interpret the expression as needed.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsHitConditionalBreakpoints' is true.
+     * If both this property and 'condition' are specified, 'hitCondition'
+     * should be evaluated only if the 'condition' is met, and the debug adapter
+     * should stop only if both conditions are met.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hit_condition: Option<String>,
+
+    /**
+     * If this attribute exists and is non-empty, the debug adapter must not
+     * 'break' (stop)
+     * but log the message instead. Expressions within '{}' are interpolated.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsLogPoints' is true.
+     * If either 'hitCondition' or 'condition' is specified, then the message
+     * should only be logged if those conditions are met.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_message: Option<String>,
+
+    /**
+     * The mode of this breakpoint. If defined, this must be one of the
+     * 'breakpointModes' the debug adapter advertised in its 'Capabilities'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+impl SourceBreakpoint {
+    pub fn new(line: impl Into<i64>) -> Self {
+        SourceBreakpoint {
+            line: line.into(),
+            column: None,
+            condition: None,
+            hit_condition: None,
+            log_message: None,
+            mode: None,
+        }
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn condition(mut self, condition: impl Into<String>) -> Self {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    pub fn hit_condition(mut self, hit_condition: impl Into<String>) -> Self {
+        self.hit_condition = Some(hit_condition.into());
+        self
+    }
+
+    pub fn log_message(mut self, log_message: impl Into<String>) -> Self {
+        self.log_message = Some(log_message.into());
+        self
+    }
+
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+}
+
+/**
+ * Properties of a breakpoint passed to the 'setFunctionBreakpoints' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionBreakpoint {
+    /**
+     * The name of the function.
+     */
+    pub name: String,
+
+    /**
+     * An expression for conditional breakpoints.
+     * It is only honored by a debug adapter if the corresponding capability
+     * 'supportsConditionalBreakpoints' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+
+    /**
+     * An expression that controls how many hits of the breakpoint are ignored.
+     * The debug adapter is expected to interpret the expression as needed.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsHitConditionalBreakpoints' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hit_condition: Option<String>,
+
+    /**
+     * The mode of this breakpoint. If defined, this must be one of the
+     * 'breakpointModes' the debug adapter advertised in its 'Capabilities'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+impl FunctionBreakpoint {
+    pub fn new(name: impl Into<String>) -> Self {
+        FunctionBreakpoint {
+            name: name.into(),
+            condition: None,
+            hit_condition: None,
+            mode: None,
+        }
+    }
+
+    pub fn condition(mut self, condition: impl Into<String>) -> Self {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    pub fn hit_condition(mut self, hit_condition: impl Into<String>) -> Self {
+        self.hit_condition = Some(hit_condition.into());
+        self
+    }
+
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+}
+
+/**
+ * This enumeration defines all possible access types for data breakpoints.
+ * Values: 'read', 'write', 'readWrite'
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DataBreakpointAccessType {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+/**
+ * Properties of a data breakpoint passed to the 'setDataBreakpoints' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataBreakpoint {
+    /**
+     * An id representing the data. This id is returned from the
+     * 'dataBreakpointInfo' request.
+     */
+    pub data_id: String,
+
+    /**
+     * The access type of the data.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_type: Option<DataBreakpointAccessType>,
+
+    /**
+     * An expression for conditional breakpoints.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+
+    /**
+     * An expression that controls how many hits of the breakpoint are ignored.
+     * The debug adapter is expected to interpret the expression as needed.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hit_condition: Option<String>,
+
+    /**
+     * The mode of this breakpoint. If defined, this must be one of the
+     * 'breakpointModes' the debug adapter advertised in its 'Capabilities'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+impl DataBreakpoint {
+    pub fn new(data_id: impl Into<String>) -> Self {
+        DataBreakpoint {
+            data_id: data_id.into(),
+            access_type: None,
+            condition: None,
+            hit_condition: None,
+            mode: None,
+        }
+    }
+
+    pub fn access_type(mut self, access_type: impl Into<DataBreakpointAccessType>) -> Self {
+        self.access_type = Some(access_type.into());
+        self
+    }
+
+    pub fn condition(mut self, condition: impl Into<String>) -> Self {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    pub fn hit_condition(mut self, hit_condition: impl Into<String>) -> Self {
+        self.hit_condition = Some(hit_condition.into());
+        self
+    }
+
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+}
+
+/**
+ * Properties of a breakpoint passed to the 'setInstructionBreakpoints' request
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionBreakpoint {
+    /**
+     * The instruction reference of the breakpoint.
+     * This should be a memory or instruction pointer reference from an
+     * 'EvaluateResponse', 'Variable', 'StackFrame', 'GotoTarget', or
+     * 'Breakpoint'.
+     */
+    pub instruction_reference: MemoryReference,
+
+    /**
+     * The offset from the instruction reference in bytes.
+     * This can be negative.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+
+    /**
+     * An expression for conditional breakpoints.
+     * It is only honored by a debug adapter if the corresponding capability
+     * 'supportsConditionalBreakpoints' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+
+    /**
+     * An expression that controls how many hits of the breakpoint are ignored.
+     * The debug adapter is expected to interpret the expression as needed.
+     * The attribute is only honored by a debug adapter if the corresponding
+     * capability 'supportsHitConditionalBreakpoints' is true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hit_condition: Option<String>,
+
+    /**
+     * The mode of this breakpoint. If defined, this must be one of the
+     * 'breakpointModes' the debug adapter advertised in its 'Capabilities'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+impl InstructionBreakpoint {
+    pub fn new(instruction_reference: impl Into<MemoryReference>) -> Self {
+        InstructionBreakpoint {
+            instruction_reference: instruction_reference.into(),
+            offset: None,
+            condition: None,
+            hit_condition: None,
+            mode: None,
+        }
+    }
+
+    pub fn offset(mut self, offset: impl Into<i64>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn condition(mut self, condition: impl Into<String>) -> Self {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    pub fn hit_condition(mut self, hit_condition: impl Into<String>) -> Self {
+        self.hit_condition = Some(hit_condition.into());
+        self
+    }
+
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BreakpointReason {
+    Pending,
+    Failed,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * Information about a breakpoint created in 'setBreakpoints',
+ * 'setFunctionBreakpoints', 'setInstructionBreakpoints', or
+ * 'setDataBreakpoints' requests.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Breakpoint {
+    /**
+     * The identifier for the breakpoint. It is needed if breakpoint events are
+     * used to update or remove breakpoints.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<BreakpointId>,
+
+    /**
+     * If true, the breakpoint could be set (but not necessarily at the desired
+     * location).
+     */
+    pub verified: bool,
+
+    /**
+     * A message about the state of the breakpoint.
+     * This is shown to the user and can be used to explain why a breakpoint
+     * could not be verified.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /**
+     * The source where the breakpoint is located.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+
+    /**
+     * The start line of the actual range covered by the breakpoint.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<i64>,
+
+    /**
+     * Start position of the source range covered by the breakpoint. It is
+     * measured in UTF-16 code units and the client capability 'columnsStartAt1'
+     * determines whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * The end line of the actual range covered by the breakpoint.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<i64>,
+
+    /**
+     * End position of the source range covered by the breakpoint. It is
+     * measured in UTF-16 code units and the client capability 'columnsStartAt1'
+     * determines whether it is 0- or 1-based.
+     * If no end line is given, then the end column is assumed to be in the
+     * start line.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+
+    /**
+     * A memory reference to where the breakpoint is set.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instruction_reference: Option<MemoryReference>,
+
+    /**
+     * The offset from the instruction reference.
+     * This can be negative.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+
+    /**
+     * A machine-readable explanation of why a breakpoint may not be verified.
+     * If a breakpoint is verified or a specific reason is not known, the
+     * adapter should omit this property. Possible values include:
+     *
+     * - 'pending': Indicates a breakpoint might be verified in the future, but
+     *   the adapter cannot verify it in the current state.
+     * - 'failed': Indicates a breakpoint was not able to be verified, and the
+     *   adapter does not believe it can be verified without intervention.
+     *
+     * Values: 'pending', 'failed'
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<BreakpointReason>,
+}
+
+impl Breakpoint {
+    pub fn new(verified: impl Into<bool>) -> Self {
+        Breakpoint {
+            id: None,
+            verified: verified.into(),
+            message: None,
+            source: None,
+            line: None,
+            column: None,
+            end_line: None,
+            end_column: None,
+            instruction_reference: None,
+            offset: None,
+            reason: None,
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<BreakpointId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<Source>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn line(mut self, line: impl Into<i64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn end_line(mut self, end_line: impl Into<i64>) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    pub fn end_column(mut self, end_column: impl Into<i64>) -> Self {
+        self.end_column = Some(end_column.into());
+        self
+    }
+
+    pub fn instruction_reference(
+        mut self,
+        instruction_reference: impl Into<MemoryReference>,
+    ) -> Self {
+        self.instruction_reference = Some(instruction_reference.into());
+        self
+    }
+
+    pub fn offset(mut self, offset: impl Into<i64>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn reason(mut self, reason: impl Into<BreakpointReason>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
+/**
+ * The granularity of one 'step' in the stepping requests 'next', 'stepIn',
+ * 'stepOut', and 'stepBack'.
+ * Values:
+ * 'statement': The step should allow the program to run until the current
+ * statement has finished executing.
+ * The meaning of a statement is determined by the adapter and it may be
+ * considered equivalent to a line.
+ * For example 'for(int i = 0; i < 10; i++)' could be considered to have 3
+ * statements 'int i = 0', 'i < 10', and 'i++'.
+ * 'line': The step should allow the program to run until the current source
+ * line has executed.
+ * 'instruction': The step should allow one instruction to execute (e.g. one x86
+ * instruction).
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SteppingGranularity {
+    Statement,
+    Line,
+    Instruction,
+}
+
+/**
+ * A 'StepInTarget' can be used in the 'stepIn' request and determines into
+ * which single target the 'stepIn' request should step.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepInTarget {
+    /**
+     * Unique identifier for a step-in target.
+     */
+    pub id: i64,
+
+    /**
+     * The name of the step-in target (shown in the UI).
+     */
+    pub label: String,
+
+    /**
+     * The line of the step-in target.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<i64>,
+
+    /**
+     * Start position of the range covered by the step in target. It is measured
+     * in UTF-16 code units and the client capability 'columnsStartAt1'
+     * determines whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * The end line of the range covered by the step-in target.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<i64>,
+
+    /**
+     * End position of the range covered by the step in target. It is measured
+     * in UTF-16 code units and the client capability 'columnsStartAt1'
+     * determines whether it is 0- or 1-based.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+}
+
+impl StepInTarget {
+    pub fn new(id: impl Into<i64>, label: impl Into<String>) -> Self {
+        StepInTarget {
+            id: id.into(),
+            label: label.into(),
+            line: None,
+            column: None,
+            end_line: None,
+            end_column: None,
+        }
+    }
+
+    pub fn line(mut self, line: impl Into<i64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn end_line(mut self, end_line: impl Into<i64>) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    pub fn end_column(mut self, end_column: impl Into<i64>) -> Self {
+        self.end_column = Some(end_column.into());
+        self
+    }
+}
+
+/**
+ * A 'GotoTarget' describes a code location that can be used as a target in the
+ * 'goto' request.
+ * The possible goto targets can be determined via the 'gotoTargets' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GotoTarget {
+    /**
+     * Unique identifier for a goto target. This is used in the 'goto' request.
+     */
+    pub id: i64,
+
+    /**
+     * The name of the goto target (shown in the UI).
+     */
+    pub label: String,
+
+    /**
+     * The line of the goto target.
+     */
+    pub line: i64,
+
+    /**
+     * The column of the goto target.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * The end line of the range covered by the goto target.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<i64>,
+
+    /**
+     * The end column of the range covered by the goto target.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+
+    /**
+     * A memory reference for the instruction pointer value represented by this
+     * target.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instruction_pointer_reference: Option<MemoryReference>,
+}
+
+impl GotoTarget {
+    pub fn new(id: impl Into<i64>, label: impl Into<String>, line: impl Into<i64>) -> Self {
+        GotoTarget {
+            id: id.into(),
+            label: label.into(),
+            line: line.into(),
+            column: None,
+            end_line: None,
+            end_column: None,
+            instruction_pointer_reference: None,
+        }
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn end_line(mut self, end_line: impl Into<i64>) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    pub fn end_column(mut self, end_column: impl Into<i64>) -> Self {
+        self.end_column = Some(end_column.into());
+        self
+    }
+
+    pub fn instruction_pointer_reference(
+        mut self,
+        instruction_pointer_reference: impl Into<MemoryReference>,
+    ) -> Self {
+        self.instruction_pointer_reference = Some(instruction_pointer_reference.into());
+        self
+    }
+}
+
+/**
+ * 'CompletionItems' are the suggestions returned from the 'completions'
+ * request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionItem {
+    /**
+     * The label of this completion item. By default this is also the text that
+     * is inserted when selecting this completion.
+     */
+    pub label: String,
+
+    /**
+     * If text is returned and not an empty string, then it is inserted instead
+     * of the label.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /**
+     * A string that should be used when comparing this item with other items.
+     * If not returned or an empty string, the 'label' is used instead.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_text: Option<String>,
+
+    /**
+     * A human-readable string with additional information about this item, like
+     * type or symbol information.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+
+    /**
+     * The item's type. Typically the client uses this information to render the
+     * item in the UI with an icon.
+     */
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<CompletionItemType>,
+
+    /**
+     * Start position (within the 'text' attribute of the 'completions' request)
+     * where the completion text is added. The position is measured in UTF-16
+     * code units and the client capability 'columnsStartAt1' determines whether
+     * it is 0- or 1-based. If the start position is omitted the text is added
+     * at the location specified by the 'column' attribute of the 'completions'
+     * request.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<i64>,
+
+    /**
+     * Length determines how many characters are overwritten by the completion
+     * text and it is measured in UTF-16 code units. If missing the value 0 is
+     * assumed which results in the completion text being inserted.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<i64>,
+
+    /**
+     * Determines the start of the new selection after the text has been
+     * inserted (or replaced). 'selectionStart' is measured in UTF-16 code units
+     * and must be in the range 0 and length of the completion text. If omitted
+     * the selection starts at the end of the completion text.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_start: Option<i64>,
+
+    /**
+     * Determines the length of the new selection after the text has been
+     * inserted (or replaced) and it is measured in UTF-16 code units. The
+     * selection can not extend beyond the bounds of the completion text. If
+     * omitted the length is assumed to be 0.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_length: Option<i64>,
+}
+
+impl CompletionItem {
+    pub fn new(label: impl Into<String>) -> Self {
+        CompletionItem {
+            label: label.into(),
+            text: None,
+            sort_text: None,
+            detail: None,
+            item_type: None,
+            start: None,
+            length: None,
+            selection_start: None,
+            selection_length: None,
+        }
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn sort_text(mut self, sort_text: impl Into<String>) -> Self {
+        self.sort_text = Some(sort_text.into());
+        self
+    }
+
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn item_type(mut self, item_type: impl Into<CompletionItemType>) -> Self {
+        self.item_type = Some(item_type.into());
+        self
+    }
+
+    pub fn start(mut self, start: impl Into<i64>) -> Self {
+        self.start = Some(start.into());
+        self
+    }
+
+    pub fn length(mut self, length: impl Into<i64>) -> Self {
+        self.length = Some(length.into());
+        self
+    }
+
+    pub fn selection_start(mut self, selection_start: impl Into<i64>) -> Self {
+        self.selection_start = Some(selection_start.into());
+        self
+    }
+
+    pub fn selection_length(mut self, selection_length: impl Into<i64>) -> Self {
+        self.selection_length = Some(selection_length.into());
+        self
+    }
+}
+
+/**
+ * Some predefined types for the CompletionItem. Please note that not all
+ * clients have specific icons for all of them.
+ * Values: 'method', 'function', 'constructor', 'field', 'variable', 'class',
+ * 'interface', 'module', 'property', 'unit', 'value', 'enum', 'keyword',
+ * 'snippet', 'text', 'color', 'file', 'reference', 'customcolor'
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CompletionItemType {
+    Method,
+    Function,
+    Constructor,
+    Field,
+    Variable,
+    Class,
+    Interface,
+    Module,
+    Property,
+    Unit,
+    Value,
+    Enum,
+    Keyword,
+    Snippet,
+    Text,
+    Color,
+    File,
+    Reference,
+    Customcolor,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * Names of checksum algorithms that may be supported by a debug adapter.
+ * Values: 'MD5', 'SHA1', 'SHA256', 'timestamp'
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChecksumAlgorithm {
+    #[serde(rename = "MD5")]
+    MD5,
+    #[serde(rename = "SHA1")]
+    SHA1,
+    #[serde(rename = "SHA256")]
+    SHA256,
+    Timestamp,
+}
+
+/**
+ * The checksum of an item calculated by the specified algorithm.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checksum {
+    /**
+     * The algorithm used to calculate this checksum.
+     */
+    pub algorithm: ChecksumAlgorithm,
+
+    /**
+     * Value of the checksum, encoded as a hexadecimal value.
+     */
+    pub checksum: String,
+}
+
+impl Checksum {
+    pub fn new(algorithm: impl Into<ChecksumAlgorithm>, checksum: impl Into<String>) -> Self {
+        Checksum {
+            algorithm: algorithm.into(),
+            checksum: checksum.into(),
+        }
+    }
+}
+
+/**
+ * Provides formatting information for a value.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueFormat {
+    /**
+     * Display the value in hex.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hex: Option<bool>,
+}
+
+impl ValueFormat {
+    pub fn hex(mut self, hex: impl Into<bool>) -> Self {
+        self.hex = Some(hex.into());
+        self
+    }
+}
+
+/**
+ * Provides formatting information for a stack frame.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StackFrameFormat {
+    /**
+     * Display the value in hex.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hex: Option<bool>,
+
+    /**
+     * Displays parameters for the stack frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<bool>,
+
+    /**
+     * Displays the types of parameters for the stack frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_types: Option<bool>,
+
+    /**
+     * Displays the names of parameters for the stack frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_names: Option<bool>,
+
+    /**
+     * Displays the values of parameters for the stack frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_values: Option<bool>,
+
+    /**
+     * Displays the line number of the stack frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<bool>,
+
+    /**
+     * Displays the module of the stack frame.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module: Option<bool>,
+
+    /**
+     * Includes all stack frames, including those the debug adapter might
+     * otherwise hide.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_all: Option<bool>,
+}
+
+impl StackFrameFormat {
+    pub fn hex(mut self, hex: impl Into<bool>) -> Self {
+        self.hex = Some(hex.into());
+        self
+    }
+
+    pub fn parameters(mut self, parameters: impl Into<bool>) -> Self {
+        self.parameters = Some(parameters.into());
+        self
+    }
+
+    pub fn parameter_types(mut self, parameter_types: impl Into<bool>) -> Self {
+        self.parameter_types = Some(parameter_types.into());
+        self
+    }
+
+    pub fn parameter_names(mut self, parameter_names: impl Into<bool>) -> Self {
+        self.parameter_names = Some(parameter_names.into());
+        self
+    }
+
+    pub fn parameter_values(mut self, parameter_values: impl Into<bool>) -> Self {
+        self.parameter_values = Some(parameter_values.into());
+        self
+    }
+
+    pub fn line(mut self, line: impl Into<bool>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    pub fn module(mut self, module: impl Into<bool>) -> Self {
+        self.module = Some(module.into());
+        self
+    }
+
+    pub fn include_all(mut self, include_all: impl Into<bool>) -> Self {
+        self.include_all = Some(include_all.into());
+        self
+    }
+}
+
+/**
+ * An 'ExceptionFilterOptions' is used to specify an exception filter together
+ * with a condition for the 'setExceptionBreakpoints' request.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExceptionFilterOptions {
+    /**
+     * ID of an exception filter returned by the 'exceptionBreakpointFilters'
+     * capability.
+     */
+    pub filter_id: String,
+
+    /**
+     * An expression for conditional exceptions.
+     * The exception breaks into the debugger if the result of the condition is
+     * true.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+
+    /**
+     * The mode of this breakpoint. If defined, this must be one of the
+     * 'breakpointModes' the debug adapter advertised in its 'Capabilities'.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+impl ExceptionFilterOptions {
+    pub fn new(filter_id: impl Into<String>) -> Self {
+        ExceptionFilterOptions {
+            filter_id: filter_id.into(),
+            condition: None,
+            mode: None,
+        }
+    }
+
+    pub fn condition(mut self, condition: impl Into<String>) -> Self {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+}
+
+/**
+ * An 'ExceptionOptions' assigns configuration options to a set of exceptions.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExceptionOptions {
+    /**
+     * A path that selects a single or multiple exceptions in a tree. If 'path'
+     * is missing, the whole tree is selected.
+     * By convention the first segment of the path is a category that is used to
+     * group exceptions in the UI.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<Vec<ExceptionPathSegment>>,
+
+    /**
+     * Condition when a thrown exception should result in a break.
+     */
+    pub break_mode: ExceptionBreakMode,
+}
+
+impl ExceptionOptions {
+    pub fn new(break_mode: impl Into<ExceptionBreakMode>) -> Self {
+        ExceptionOptions {
+            path: None,
+            break_mode: break_mode.into(),
+        }
+    }
+
+    pub fn path(mut self, path: impl Into<Vec<ExceptionPathSegment>>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+/**
+ * This enumeration defines all possible conditions when a thrown exception
+ * should result in a break.
+ * never: never breaks,
+ * always: always breaks,
+ * unhandled: breaks when exception unhandled,
+ * userUnhandled: breaks if the exception is not handled by user code.
+ * Values: 'never', 'always', 'unhandled', 'userUnhandled'
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExceptionBreakMode {
+    Never,
+    Always,
+    Unhandled,
+    UserUnhandled,
+}
+
+/**
+ * An 'ExceptionPathSegment' represents a segment in a path that is used to
+ * match leafs or nodes in a tree of exceptions.
+ * If a segment consists of more than one name, it matches the names provided if
+ * 'negate' is false or missing, or it matches anything except the names
+ * provided if 'negate' is true.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExceptionPathSegment {
+    /**
+     * If false or missing this segment matches the names provided, otherwise it
+     * matches anything except the names provided.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub negate: Option<bool>,
+
+    /**
+     * Depending on the value of 'negate' the names that should match or not
+     * match.
+     */
+    pub names: Vec<String>,
+}
+
+impl ExceptionPathSegment {
+    pub fn new(names: impl Into<Vec<String>>) -> Self {
+        ExceptionPathSegment {
+            negate: None,
+            names: names.into(),
+        }
+    }
+
+    pub fn negate(mut self, negate: impl Into<bool>) -> Self {
+        self.negate = Some(negate.into());
+        self
+    }
+}
+
+/**
+ * Detailed information about an exception that has occurred.
+ */
+#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExceptionDetails {
+    /**
+     * Message contained in the exception.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /**
+     * Short type name of the exception object.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+
+    /**
+     * Fully-qualified type name of the exception object.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_type_name: Option<String>,
+
+    /**
+     * An expression that can be evaluated in the current scope to obtain the
+     * exception object.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluate_name: Option<String>,
+
+    /**
+     * Stack trace at the time the exception was thrown.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack_trace: Option<String>,
+
+    /**
+     * Details of the exception contained by this exception, if any.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner_exception: Option<Vec<ExceptionDetails>>,
+}
+
+impl ExceptionDetails {
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn type_name(mut self, type_name: impl Into<String>) -> Self {
+        self.type_name = Some(type_name.into());
+        self
+    }
+
+    pub fn full_type_name(mut self, full_type_name: impl Into<String>) -> Self {
+        self.full_type_name = Some(full_type_name.into());
+        self
+    }
+
+    pub fn evaluate_name(mut self, evaluate_name: impl Into<String>) -> Self {
+        self.evaluate_name = Some(evaluate_name.into());
+        self
+    }
+
+    pub fn stack_trace(mut self, stack_trace: impl Into<String>) -> Self {
+        self.stack_trace = Some(stack_trace.into());
+        self
+    }
+
+    pub fn inner_exception(mut self, inner_exception: impl Into<Vec<ExceptionDetails>>) -> Self {
+        self.inner_exception = Some(inner_exception.into());
+        self
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InstructionPresentationHint {
+    Normal,
+    Invalid,
+}
+
+/**
+ * Represents a single disassembled instruction.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisassembledInstruction {
+    /**
+     * The address of the instruction. Treated as a hex value if prefixed with
+     * '0x', or as a decimal value otherwise.
+     */
+    pub address: Address,
+
+    /**
+     * Raw bytes representing the instruction and its operands, in an
+     * implementation-defined format.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instruction_bytes: Option<String>,
+
+    /**
+     * Text representing the instruction and its operands, in an
+     * implementation-defined format.
+     */
+    pub instruction: String,
+
+    /**
+     * Name of the symbol that corresponds with the location of this
+     * instruction, if any.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /**
+     * Source location that corresponds to this instruction, if any.
+     * Should always be set (if available) on the first instruction returned,
+     * but can be omitted afterwards if this instruction maps to the same source
+     * file as the previous instruction.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Source>,
+
+    /**
+     * The line within the source location that corresponds to this instruction,
+     * if any.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<i64>,
+
+    /**
+     * The column within the line that corresponds to this instruction, if any.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<i64>,
+
+    /**
+     * The end line of the range that corresponds to this instruction, if any.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<i64>,
+
+    /**
+     * The end column of the range that corresponds to this instruction, if any.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+
+    /**
+     * A hint for how to present the instruction in the UI.
+     *
+     * A value of 'invalid' may be used to indicate this instruction is 'filler'
+     * and cannot be reached by the program. For example, unreadable memory
+     * addresses may be presented is 'invalid.'
+     * Values: 'normal', 'invalid'
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation_hint: Option<InstructionPresentationHint>,
+}
+
+impl DisassembledInstruction {
+    pub fn new(address: impl Into<Address>, instruction: impl Into<String>) -> Self {
+        DisassembledInstruction {
+            address: address.into(),
+            instruction_bytes: None,
+            instruction: instruction.into(),
+            symbol: None,
+            location: None,
+            line: None,
+            column: None,
+            end_line: None,
+            end_column: None,
+            presentation_hint: None,
+        }
+    }
+
+    pub fn instruction_bytes(mut self, instruction_bytes: impl Into<String>) -> Self {
+        self.instruction_bytes = Some(instruction_bytes.into());
+        self
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn location(mut self, location: impl Into<Source>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    pub fn line(mut self, line: impl Into<i64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    pub fn column(mut self, column: impl Into<i64>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn end_line(mut self, end_line: impl Into<i64>) -> Self {
+        self.end_line = Some(end_line.into());
+        self
+    }
+
+    pub fn end_column(mut self, end_column: impl Into<i64>) -> Self {
+        self.end_column = Some(end_column.into());
+        self
+    }
+
+    pub fn presentation_hint(
+        mut self,
+        presentation_hint: impl Into<InstructionPresentationHint>,
+    ) -> Self {
+        self.presentation_hint = Some(presentation_hint.into());
+        self
+    }
+}
+
+/**
+ * Logical areas that can be invalidated by the 'invalidated' event.
+ * Values:
+ * 'all': All previously fetched data has become invalid and needs to be
+ * refetched.
+ * 'stacks': Previously fetched stack related data has become invalid and needs
+ * to be refetched.
+ * 'threads': Previously fetched thread related data has become invalid and
+ * needs to be refetched.
+ * 'variables': Previously fetched variable data has become invalid and needs to
+ * be refetched.
+ * etc.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InvalidatedAreas {
+    All,
+    Stacks,
+    Threads,
+    Variables,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/**
+ * A 'BreakpointMode' is provided as a option when setting breakpoints on
+ * sources or instructions.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakpointMode {
+    /**
+     * The internal ID of the mode. This value is passed to the 'setBreakpoints'
+     * request.
+     */
+    pub mode: String,
+
+    /**
+     * The name of the breakpoint mode. This is shown in the UI.
+     */
+    pub label: String,
+
+    /**
+     * A help text providing additional information about the breakpoint mode.
+     * This string is typically shown as a hover and can be translated.
+     */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /**
+     * Describes one or more type of breakpoint this mode applies to.
+     */
+    pub applies_to: Vec<BreakpointModeApplicability>,
+}
+
+impl BreakpointMode {
+    pub fn new(
+        mode: impl Into<String>,
+        label: impl Into<String>,
+        applies_to: impl Into<Vec<BreakpointModeApplicability>>,
+    ) -> Self {
+        BreakpointMode {
+            mode: mode.into(),
+            label: label.into(),
+            description: None,
+            applies_to: applies_to.into(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/**
+ * Describes one or more type of breakpoint a 'BreakpointMode' applies to. This
+ * is a non-exhaustive enumeration and may expand as future breakpoint types are
+ * added.
+ * Values:
+ * 'source': In 'SourceBreakpoint's
+ * 'exception': In exception breakpoints applied in the 'ExceptionFilterOptions'
+ * 'data': In data breakpoints requested in the 'DataBreakpointInfo' request
+ * 'instruction': In 'InstructionBreakpoint's
+ * etc.
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BreakpointModeApplicability {
+    Source,
+    Exception,
+    Data,
+    Instruction,
+    #[serde(untagged)]
+    Other(String),
+}
@@ -0,0 +1,517 @@
+mod common;
+
+use std::io::{BufReader, Write};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use debug_types::adapter::{self, Client, DebugAdapter, HandlerResult, RequestError};
+use debug_types::codec::{CodecError, MessageReader, MessageWriter};
+use debug_types::events::{Event, EventBody, OutputEvent};
+use debug_types::positions::Bases;
+use debug_types::requests::*;
+use debug_types::responses::{
+    EvaluateResponse, InitializeResponse, Response, ResponseBody, ResponseMessage,
+};
+use debug_types::types::{Capabilities, VariablesReference};
+use debug_types::{MessageKind, ProtocolMessage};
+use serde_json::json;
+
+use common::{pipe, PipeReader, PipeWriter};
+
+#[derive(Default)]
+struct TestAdapter {
+    process_id: Option<i64>,
+}
+
+impl DebugAdapter for TestAdapter {
+    fn send_initialized(&self) -> bool {
+        true
+    }
+
+    fn initialize(
+        &mut self,
+        _: &Client,
+        _: InitializeRequestArguments,
+    ) -> HandlerResult<InitializeRequestArguments> {
+        Ok(InitializeResponse {
+            capabilities: serde_json::from_value(json!({
+                "supportsConfigurationDoneRequest": true
+            }))
+            .unwrap(),
+        })
+    }
+
+    fn launch(
+        &mut self,
+        client: &Client,
+        _: LaunchRequestArguments,
+    ) -> HandlerResult<LaunchRequestArguments> {
+        let arguments: RunInTerminalRequestArguments =
+            serde_json::from_value(json!({ "cwd": "/tmp", "args": ["debuggee"] })).unwrap();
+        let response = client.send_request(arguments).unwrap().wait();
+        let body = response.and_then(|r| r.into_body::<RunInTerminalRequestArguments>());
+        match body {
+            Some(body) => {
+                self.process_id = body.process_id.map(i64::from);
+                Ok(())
+            }
+            None => Err(RequestError::new("cannot run the debuggee")),
+        }
+    }
+}
+
+/**
+ * Works with lines and columns that start at 0, and with native paths.
+ */
+#[derive(Default)]
+struct NativeAdapter {
+    paths: Vec<String>,
+}
+
+impl DebugAdapter for NativeAdapter {
+    fn positions(&self) -> Option<Bases> {
+        Some(Bases::ZERO)
+    }
+
+    fn path_format(&self) -> Option<PathFormat> {
+        Some(PathFormat::Path)
+    }
+
+    fn initialize(
+        &mut self,
+        _: &Client,
+        _: InitializeRequestArguments,
+    ) -> HandlerResult<InitializeRequestArguments> {
+        Ok(InitializeResponse::new(Capabilities::default()))
+    }
+
+    fn attach(
+        &mut self,
+        client: &Client,
+        arguments: AttachRequestArguments,
+    ) -> HandlerResult<AttachRequestArguments> {
+        let mappings = arguments.additional_attributes["pathMappings"].clone();
+        client.set_path_mappings(serde_json::from_value(mappings).unwrap());
+        Ok(())
+    }
+
+    fn evaluate(
+        &mut self,
+        client: &Client,
+        arguments: EvaluateArguments,
+    ) -> HandlerResult<EvaluateArguments> {
+        assert_eq!((arguments.line, arguments.column), (Some(0), Some(4)));
+        let source = arguments.source.unwrap();
+        self.paths.extend(source.path.clone());
+        let output = OutputEvent::new("x = 2").source(source).line(0).column(4);
+        client.send_event(output.into()).unwrap();
+        Ok(EvaluateResponse::new("2", VariablesReference::default()))
+    }
+}
+
+/**
+ * Evaluates until the request is cancelled.
+ */
+#[derive(Default)]
+struct SlowAdapter {
+    evaluated: Vec<String>,
+}
+
+impl DebugAdapter for SlowAdapter {
+    fn initialize(
+        &mut self,
+        _: &Client,
+        _: InitializeRequestArguments,
+    ) -> HandlerResult<InitializeRequestArguments> {
+        let capabilities = Capabilities::default().supports_cancel_request(true);
+        Ok(InitializeResponse::new(capabilities))
+    }
+
+    fn evaluate(
+        &mut self,
+        client: &Client,
+        arguments: EvaluateArguments,
+    ) -> HandlerResult<EvaluateArguments> {
+        self.evaluated.push(arguments.expression);
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while Instant::now() < deadline {
+            if client.is_cancelled() {
+                return Err(RequestError::cancelled());
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        panic!("the request was not cancelled");
+    }
+}
+
+type Runner<A = TestAdapter> = JoinHandle<(A, Result<(), CodecError>)>;
+
+struct Connection {
+    writer: MessageWriter<PipeWriter>,
+    reader: MessageReader<BufReader<PipeReader>>,
+    seq: i64,
+}
+
+impl Connection {
+    fn start() -> (Connection, Runner) {
+        Connection::start_with(TestAdapter::default())
+    }
+
+    fn start_with<A: DebugAdapter + Send + 'static>(mut adapter: A) -> (Connection, Runner<A>) {
+        let (client_writer, adapter_reader) = pipe();
+        let (adapter_writer, client_reader) = pipe();
+        let runner = thread::spawn(move || {
+            let result = adapter::run(&mut adapter, adapter_reader, adapter_writer);
+            (adapter, result)
+        });
+        let connection = Connection {
+            writer: MessageWriter::new(client_writer),
+            reader: MessageReader::new(client_reader),
+            seq: 0,
+        };
+        (connection, runner)
+    }
+
+    fn send(&mut self, message: MessageKind) -> i64 {
+        self.seq += 1;
+        self.writer
+            .write_message(&ProtocolMessage {
+                seq: self.seq,
+                message,
+            })
+            .unwrap();
+        self.seq
+    }
+
+    /**
+     * Sends a message as is, such as one that does not parse.
+     */
+    fn send_raw(&mut self, message: serde_json::Value) {
+        let body = message.to_string();
+        let writer = self.writer.get_mut();
+        write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body).unwrap();
+    }
+
+    fn receive(&mut self) -> ProtocolMessage {
+        self.reader.read_message().unwrap().unwrap()
+    }
+
+    fn initialize(&mut self) -> i64 {
+        self.initialize_with(json!({ "adapterID": "test" }))
+    }
+
+    fn initialize_with(&mut self, arguments: serde_json::Value) -> i64 {
+        let initialize: InitializeRequestArguments = serde_json::from_value(arguments).unwrap();
+        self.send(MessageKind::Request(initialize.into()))
+    }
+
+    fn receive_response(&mut self) -> (i64, Response) {
+        match self.receive() {
+            ProtocolMessage {
+                seq,
+                message: MessageKind::Response(response),
+            } => (seq, response),
+            other => panic!("expected a response, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn responds_to_requests() {
+    let (mut client, runner) = Connection::start();
+
+    let request_seq = client.initialize();
+    let (seq, response) = client.receive_response();
+    assert_eq!(seq, 1);
+    assert_eq!(response.request_seq, request_seq);
+    let body = response.into_body::<InitializeRequestArguments>().unwrap();
+    assert_eq!(
+        body.capabilities.supports_configuration_done_request,
+        Some(true)
+    );
+    // The adapter opts in, so the runner sends 'initialized' once the client
+    // has the capabilities.
+    assert_eq!(
+        client.receive(),
+        ProtocolMessage {
+            seq: 2,
+            message: MessageKind::Event(Event {
+                body: Some(EventBody::Initialized)
+            }),
+        }
+    );
+
+    let evaluate: EvaluateArguments =
+        serde_json::from_value(json!({ "expression": "1 + 1" })).unwrap();
+    let request_seq = client.send(MessageKind::Request(evaluate.into()));
+    let (seq, response) = client.receive_response();
+    assert_eq!(seq, 3);
+    assert_eq!(response.request_seq, request_seq);
+    assert_eq!(response.command(), "evaluate");
+    assert_eq!(
+        response.message,
+        Some(ResponseMessage::Error(
+            "the 'evaluate' request is not supported".to_string()
+        ))
+    );
+
+    let unknown: RequestCommand = serde_json::from_value(json!({ "command": "custom" })).unwrap();
+    client.send(MessageKind::Request(unknown));
+    let (_, response) = client.receive_response();
+    assert!(!response.is_success());
+    assert_eq!(response.command(), "custom");
+
+    let request_seq = client.send(MessageKind::Request(
+        DisconnectArguments {
+            restart: None,
+            terminate_debuggee: None,
+            suspend_debuggee: None,
+        }
+        .into(),
+    ));
+    let (_, response) = client.receive_response();
+    assert_eq!(response.request_seq, request_seq);
+
+    // The runner stops after 'disconnect', without waiting for the stream to
+    // close.
+    let (_, result) = runner.join().unwrap();
+    result.unwrap();
+}
+
+#[test]
+fn handlers_wait_for_reverse_requests() {
+    let (mut client, runner) = Connection::start();
+    client.initialize();
+    client.receive_response();
+    client.receive();
+
+    let launch: LaunchRequestArguments = serde_json::from_value(json!({})).unwrap();
+    let request_seq = client.send(MessageKind::Request(launch.into()));
+    let reverse_seq = match client.receive() {
+        ProtocolMessage {
+            seq,
+            message: MessageKind::Request(RequestCommand::RunInTerminal(arguments)),
+        } => {
+            assert_eq!(arguments.args, vec!["debuggee".to_string()]);
+            seq
+        }
+        other => panic!("expected 'runInTerminal', got {:?}", other),
+    };
+    let response: Response = serde_json::from_value(json!({
+        "request_seq": reverse_seq,
+        "success": true,
+        "command": "runInTerminal",
+        "body": { "processId": 42 }
+    }))
+    .unwrap();
+    client.send(MessageKind::Response(response));
+
+    let (_, response) = client.receive_response();
+    assert_eq!(response.request_seq, request_seq);
+    assert_eq!(response.result, Ok(ResponseBody::Launch));
+
+    drop(client);
+    let (adapter, result) = runner.join().unwrap();
+    result.unwrap();
+    assert_eq!(adapter.process_id, Some(42));
+}
+
+#[test]
+fn rejects_requests_out_of_order() {
+    let (mut client, runner) = Connection::start();
+
+    client.send(MessageKind::Request(RequestCommand::Threads));
+    let (_, response) = client.receive_response();
+    assert_eq!(
+        response.message,
+        Some(ResponseMessage::Error(
+            "'threads' was sent before 'initialize' completed".to_string()
+        ))
+    );
+
+    client.initialize();
+    client.receive_response();
+    client.receive();
+    client.send(MessageKind::Request(RequestCommand::Threads));
+    let (_, response) = client.receive_response();
+    assert_eq!(response.command(), "threads");
+    assert_eq!(
+        response.message,
+        Some(ResponseMessage::Error(
+            "'threads' was sent before 'launch' or 'attach'".to_string()
+        ))
+    );
+
+    client.initialize();
+    let (_, response) = client.receive_response();
+    assert_eq!(
+        response.message,
+        Some(ResponseMessage::Error(
+            "the session is already initialized".to_string()
+        ))
+    );
+
+    let evaluate: EvaluateArguments =
+        serde_json::from_value(json!({ "expression": "x", "context": "hover" })).unwrap();
+    client.send(MessageKind::Request(evaluate.into()));
+    let (_, response) = client.receive_response();
+    assert_eq!(
+        response.message,
+        Some(ResponseMessage::Error(
+            "the 'context' argument of 'evaluate' needs 'supportsEvaluateForHovers'".to_string()
+        ))
+    );
+
+    drop(client);
+    runner.join().unwrap().1.unwrap();
+}
+
+#[test]
+fn rejects_invalid_requests_and_goes_on() {
+    let (mut client, runner) = Connection::start();
+    client.initialize();
+    client.receive_response();
+    client.receive();
+
+    client.send_raw(json!({
+        "seq": 10,
+        "type": "request",
+        "command": "evaluate",
+        "arguments": { "expression": 1 },
+    }));
+    let (_, response) = client.receive_response();
+    assert_eq!(response.request_seq, 10);
+    assert_eq!(response.command(), "evaluate");
+    match response.message {
+        Some(ResponseMessage::Error(message)) => {
+            assert!(message.starts_with("invalid arguments: "), "{}", message)
+        }
+        other => panic!("expected an error message, got {:?}", other),
+    }
+
+    // Messages that cannot be answered are skipped.
+    client.send_raw(json!({ "seq": 11, "type": "event" }));
+    client.send_raw(json!([1, 2, 3]));
+
+    client.send(MessageKind::Request(RequestCommand::Threads));
+    let (_, response) = client.receive_response();
+    assert_eq!(response.command(), "threads");
+
+    drop(client);
+    runner.join().unwrap().1.unwrap();
+}
+
+#[test]
+fn cancels_requests_while_a_handler_runs() {
+    let (mut client, runner) = Connection::start_with(SlowAdapter::default());
+    client.initialize();
+    client.receive_response();
+
+    let evaluate = |expression: &str| -> EvaluateArguments {
+        serde_json::from_value(json!({ "expression": expression })).unwrap()
+    };
+    let running = client.send(MessageKind::Request(evaluate("running").into()));
+    let queued = client.send(MessageKind::Request(evaluate("queued").into()));
+    for request_id in [queued, running] {
+        let cancel = CancelArguments::default().request_id(request_id);
+        let cancel_seq = client.send(MessageKind::Request(cancel.into()));
+        let (_, response) = client.receive_response();
+        assert_eq!(response.request_seq, cancel_seq);
+        assert_eq!(response.result, Ok(ResponseBody::Cancel));
+    }
+    for request_seq in [running, queued] {
+        let (_, response) = client.receive_response();
+        assert_eq!(response.request_seq, request_seq);
+        assert_eq!(response.message, Some(ResponseMessage::Cancelled));
+    }
+
+    drop(client);
+    let (adapter, result) = runner.join().unwrap();
+    result.unwrap();
+    // The queued request failed without reaching its handler.
+    assert_eq!(adapter.evaluated, ["running"]);
+}
+
+#[test]
+fn stops_when_the_stream_closes() {
+    let (client, runner) = Connection::start();
+    drop(client);
+    let (_, result) = runner.join().unwrap();
+    result.unwrap();
+}
+
+#[test]
+fn converts_positions_and_paths() {
+    let (mut client, runner) = Connection::start_with(NativeAdapter::default());
+    client.initialize_with(json!({ "adapterID": "test", "pathFormat": "uri" }));
+    client.receive_response();
+
+    let evaluate: EvaluateArguments = serde_json::from_value(json!({
+        "expression": "x",
+        "source": { "path": "file:///src/my%20file.rs" },
+        "line": 1,
+        "column": 5,
+    }))
+    .unwrap();
+    client.send(MessageKind::Request(evaluate.into()));
+    match client.receive().message {
+        MessageKind::Event(Event {
+            body: Some(EventBody::Output(output)),
+        }) => {
+            assert_eq!((output.line, output.column), (Some(1), Some(5)));
+            assert_eq!(
+                output.source.unwrap().path.as_deref(),
+                Some("file:///src/my%20file.rs")
+            );
+        }
+        other => panic!("expected an output event, got {:?}", other),
+    }
+    let (_, response) = client.receive_response();
+    assert!(response.is_success());
+
+    drop(client);
+    let (adapter, result) = runner.join().unwrap();
+    result.unwrap();
+    assert_eq!(adapter.paths, ["/src/my file.rs"]);
+}
+
+#[test]
+fn maps_remote_paths() {
+    let (mut client, runner) = Connection::start_with(NativeAdapter::default());
+    client.initialize();
+    client.receive_response();
+
+    let attach: AttachRequestArguments = serde_json::from_value(json!({
+        "pathMappings": [
+            { "remoteRoot": "/", "localRoot": "/mnt/container" },
+            { "remoteRoot": "/app", "localRoot": "/home/me/project" },
+        ],
+    }))
+    .unwrap();
+    client.send(MessageKind::Request(attach.into()));
+    assert!(client.receive_response().1.is_success());
+
+    let evaluate: EvaluateArguments = serde_json::from_value(json!({
+        "expression": "x",
+        "source": { "path": "/home/me/project/contracts/x.clar" },
+        "line": 1,
+        "column": 5,
+    }))
+    .unwrap();
+    client.send(MessageKind::Request(evaluate.into()));
+    match client.receive().message {
+        MessageKind::Event(Event {
+            body: Some(EventBody::Output(output)),
+        }) => assert_eq!(
+            output.source.unwrap().path.as_deref(),
+            Some("/home/me/project/contracts/x.clar")
+        ),
+        other => panic!("expected an output event, got {:?}", other),
+    }
+    client.receive_response();
+
+    drop(client);
+    let (adapter, result) = runner.join().unwrap();
+    result.unwrap();
+    assert_eq!(adapter.paths, ["/app/contracts/x.clar"]);
+}
@@ -0,0 +1,78 @@
+use debug_types::events::{Event, StoppedReason};
+use debug_types::requests::LaunchRequestArguments;
+use debug_types::responses::{ErrorResponse, Response, ResponseMessage, ThreadsResponse};
+use debug_types::types::{Capabilities, Message, Source, StackFrame, Thread};
+use serde_json::json;
+
+#[test]
+fn builds_structs() {
+    let frame = StackFrame::new(1, "main", 10, 4)
+        .source(Source::default().path("/src/main.rs"))
+        .can_restart(true);
+    assert_eq!(
+        serde_json::to_value(&frame).unwrap(),
+        json!({
+            "id": 1,
+            "name": "main",
+            "line": 10,
+            "column": 4,
+            "source": { "path": "/src/main.rs" },
+            "canRestart": true,
+        })
+    );
+
+    let capabilities = Capabilities::default().supports_configuration_done_request(true);
+    assert_eq!(
+        serde_json::to_value(&capabilities).unwrap(),
+        json!({ "supportsConfigurationDoneRequest": true })
+    );
+
+    let launch = LaunchRequestArguments::default()
+        .no_debug(true)
+        .name("test");
+    assert_eq!(
+        serde_json::to_value(&launch).unwrap(),
+        json!({ "noDebug": true, "name": "test" })
+    );
+}
+
+#[test]
+fn builds_messages() {
+    let response = Response::success(3, ThreadsResponse::new(vec![Thread::new(1, "main")]));
+    assert!(response.is_success());
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        json!({
+            "request_seq": 3,
+            "success": true,
+            "command": "threads",
+            "body": { "threads": [{ "id": 1, "name": "main" }] },
+        })
+    );
+
+    let response = Response::error(
+        4,
+        ErrorResponse::new("evaluate").error(Message::new(1, "no such variable")),
+    )
+    .message(ResponseMessage::NotStopped);
+    assert!(!response.is_success());
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        json!({
+            "request_seq": 4,
+            "success": false,
+            "command": "evaluate",
+            "message": "notStopped",
+            "body": { "error": { "id": 1, "format": "no such variable" } },
+        })
+    );
+
+    let event = Event::stopped(StoppedReason::Breakpoint, 1);
+    assert_eq!(
+        serde_json::to_value(&event).unwrap(),
+        json!({
+            "event": "stopped",
+            "body": { "reason": "breakpoint", "threadId": 1 },
+        })
+    );
+}
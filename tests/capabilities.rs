@@ -0,0 +1,151 @@
+use debug_types::capabilities::{Capability, Negotiated, Unsupported};
+use debug_types::events::EventBody;
+use debug_types::requests::*;
+use serde_json::{json, Value};
+
+fn negotiated(client: Value, adapter: Value) -> Negotiated {
+    let mut arguments = json!({ "adapterID": "test" });
+    arguments
+        .as_object_mut()
+        .unwrap()
+        .extend(client.as_object().unwrap().clone());
+    Negotiated::new(
+        serde_json::from_value(arguments).unwrap(),
+        serde_json::from_value(adapter).unwrap(),
+    )
+}
+
+fn request(command: &str, arguments: Value) -> RequestCommand {
+    let request: RequestCommand =
+        serde_json::from_value(json!({ "command": command, "arguments": arguments })).unwrap();
+    assert!(!matches!(request, RequestCommand::Unknown { .. }));
+    request
+}
+
+#[test]
+fn requests_need_adapter_capabilities() {
+    let without = negotiated(json!({}), json!({}));
+    let with = negotiated(json!({}), json!({ "supportsStepBack": true }));
+    let step_back = request("stepBack", json!({ "threadId": 1 }));
+
+    assert_eq!(
+        without.check_request(&step_back),
+        Err(Unsupported {
+            message: "stepBack".to_string(),
+            argument: None,
+            capability: Capability::StepBack,
+        })
+    );
+    assert_eq!(with.check_request(&step_back), Ok(()));
+    assert_eq!(
+        without.check_request(&request("threads", json!(null))),
+        Ok(())
+    );
+}
+
+#[test]
+fn arguments_need_adapter_capabilities() {
+    let adapter = negotiated(json!({}), json!({ "supportsConditionalBreakpoints": true }));
+
+    let variables = request(
+        "variables",
+        json!({ "variablesReference": 1, "format": { "hex": true } }),
+    );
+    let err = adapter.check_request(&variables).unwrap_err();
+    assert_eq!(err.argument, Some("format"));
+    assert_eq!(err.capability, Capability::ValueFormattingOptions);
+    assert_eq!(
+        err.to_string(),
+        "the 'format' argument of 'variables' needs 'supportsValueFormattingOptions'"
+    );
+
+    let set_breakpoints = request(
+        "setBreakpoints",
+        json!({ "source": {}, "breakpoints": [{ "line": 1, "condition": "x" }] }),
+    );
+    assert_eq!(adapter.check_request(&set_breakpoints), Ok(()));
+    let set_breakpoints = request(
+        "setBreakpoints",
+        json!({ "source": {}, "breakpoints": [{ "line": 1, "logMessage": "x" }] }),
+    );
+    assert_eq!(
+        adapter
+            .check_request(&set_breakpoints)
+            .unwrap_err()
+            .capability,
+        Capability::LogPoints
+    );
+}
+
+#[test]
+fn false_flags_need_no_capability() {
+    let adapter = negotiated(json!({}), json!({ "supportsDataBreakpoints": true }));
+    let requests = [
+        request("next", json!({ "threadId": 1, "singleThread": false })),
+        request(
+            "disconnect",
+            json!({ "terminateDebuggee": false, "suspendDebuggee": false }),
+        ),
+        request(
+            "dataBreakpointInfo",
+            json!({ "name": "x", "asAddress": false }),
+        ),
+    ];
+    for request in &requests {
+        assert_eq!(adapter.check_request(request), Ok(()));
+    }
+    let client = negotiated(json!({ "supportsRunInTerminalRequest": true }), json!({}));
+    let run_in_terminal = request(
+        "runInTerminal",
+        json!({ "cwd": "/", "args": [], "argsCanBeInterpretedByShell": false }),
+    );
+    assert_eq!(client.check_request(&run_in_terminal), Ok(()));
+
+    let next = request("next", json!({ "threadId": 1, "singleThread": true }));
+    assert_eq!(
+        adapter.check_request(&next).unwrap_err().capability,
+        Capability::SingleThreadExecutionRequests
+    );
+}
+
+#[test]
+fn reverse_requests_and_events_need_client_capabilities() {
+    let without = negotiated(json!({}), json!({}));
+    let with = negotiated(
+        json!({ "supportsRunInTerminalRequest": true, "supportsProgressReporting": true }),
+        json!({}),
+    );
+    let run_in_terminal = request("runInTerminal", json!({ "cwd": "/", "args": [] }));
+    assert_eq!(
+        without
+            .check_request(&run_in_terminal)
+            .unwrap_err()
+            .capability,
+        Capability::RunInTerminalRequest
+    );
+    assert_eq!(with.check_request(&run_in_terminal), Ok(()));
+
+    let progress: EventBody = serde_json::from_value(json!({
+        "event": "progressStart",
+        "body": { "progressId": "1", "title": "Loading" }
+    }))
+    .unwrap();
+    assert_eq!(
+        without.check_event(&progress).unwrap_err().to_string(),
+        "'progressStart' needs 'supportsProgressReporting'"
+    );
+    assert_eq!(with.check_event(&progress), Ok(()));
+    assert_eq!(without.check_event(&EventBody::Initialized), Ok(()));
+}
+
+#[test]
+fn ansi_styling_needs_both_sides() {
+    let adapter_only = negotiated(json!({}), json!({ "supportsANSIStyling": true }));
+    let both = negotiated(
+        json!({ "supportsANSIStyling": true }),
+        json!({ "supportsANSIStyling": true }),
+    );
+    assert!(!adapter_only.supports(Capability::AnsiStyling));
+    assert!(both.supports(Capability::AnsiStyling));
+    assert_eq!(Capability::AnsiStyling.name(), "supportsANSIStyling");
+}
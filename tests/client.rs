@@ -0,0 +1,446 @@
+mod common;
+
+use std::io::{self, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use debug_types::adapter::{self, Client, DebugAdapter, HandlerResult, RequestError};
+use debug_types::client::{ClientError, DebugClient};
+use debug_types::codec::MessageReader;
+use debug_types::events::EventBody;
+use debug_types::requests::*;
+use debug_types::responses::{
+    InitializeResponse, ResponseBody, ResponseMessage, RunInTerminalResponse,
+};
+use debug_types::session::{Phase, Violation};
+use debug_types::types::ProcessId;
+use debug_types::{MessageKind, ProtocolMessage};
+use serde_json::json;
+
+use common::{pipe, PipeWriter};
+
+#[derive(Default)]
+struct TestAdapter {
+    process_id: Option<i64>,
+}
+
+impl DebugAdapter for TestAdapter {
+    fn send_initialized(&self) -> bool {
+        true
+    }
+
+    fn initialize(
+        &mut self,
+        _: &Client,
+        _: InitializeRequestArguments,
+    ) -> HandlerResult<InitializeRequestArguments> {
+        Ok(InitializeResponse {
+            capabilities: serde_json::from_value(json!({
+                "supportsConfigurationDoneRequest": true
+            }))
+            .unwrap(),
+        })
+    }
+
+    fn launch(
+        &mut self,
+        client: &Client,
+        _: LaunchRequestArguments,
+    ) -> HandlerResult<LaunchRequestArguments> {
+        let arguments: RunInTerminalRequestArguments =
+            serde_json::from_value(json!({ "cwd": "/tmp", "args": ["debuggee"] })).unwrap();
+        let response = client.send_request(arguments).unwrap().wait();
+        match response.and_then(|r| r.into_body::<RunInTerminalRequestArguments>()) {
+            Some(body) => {
+                self.process_id = body.process_id.map(i64::from);
+                Ok(())
+            }
+            None => Err(RequestError::new("cannot run the debuggee")),
+        }
+    }
+}
+
+fn initialize_arguments() -> InitializeRequestArguments {
+    serde_json::from_value(json!({ "adapterID": "test" })).unwrap()
+}
+
+fn launch_arguments() -> LaunchRequestArguments {
+    serde_json::from_value(json!({})).unwrap()
+}
+
+fn disconnect_arguments() -> DisconnectArguments {
+    DisconnectArguments {
+        restart: None,
+        terminate_debuggee: None,
+        suspend_debuggee: None,
+    }
+}
+
+#[test]
+fn typed_requests_and_events_over_tcp() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let runner = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let reader = BufReader::new(stream.try_clone().unwrap());
+        adapter::run(&mut TestAdapter::default(), reader, stream)
+    });
+
+    let client = DebugClient::connect(addr).unwrap();
+    let response = client.request(initialize_arguments()).unwrap();
+    assert_eq!(
+        response.capabilities.supports_configuration_done_request,
+        Some(true)
+    );
+    assert_eq!(
+        client.events().recv_timeout(Duration::from_secs(5)),
+        Ok(EventBody::Initialized)
+    );
+    assert_eq!(client.phase(), Phase::Configuring);
+
+    let evaluate: EvaluateArguments =
+        serde_json::from_value(json!({ "expression": "1 + 1" })).unwrap();
+    let err = client.request(evaluate).unwrap_err();
+    match &err {
+        ClientError::Failed { message, response } => {
+            assert_eq!(response.command, "evaluate");
+            assert_eq!(
+                message,
+                &Some(ResponseMessage::Error(
+                    "the 'evaluate' request is not supported".to_string()
+                ))
+            );
+        }
+        other => panic!("expected a failed response, got {:?}", other),
+    }
+    assert_eq!(
+        err.to_string(),
+        "'evaluate' request failed: the 'evaluate' request is not supported"
+    );
+
+    let err = client.request(disconnect_arguments()).unwrap_err();
+    assert!(matches!(err, ClientError::Failed { .. }));
+    runner.join().unwrap().unwrap();
+}
+
+#[test]
+fn answers_reverse_requests() {
+    let (client_writer, adapter_reader) = pipe();
+    let (adapter_writer, client_reader) = pipe();
+    let runner = thread::spawn(move || {
+        let mut adapter = TestAdapter::default();
+        adapter::run(&mut adapter, adapter_reader, adapter_writer).unwrap();
+        adapter
+    });
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let seen = events.clone();
+    let client = DebugClient::builder()
+        .with_timeout(Duration::from_secs(5))
+        .with_event_handler(move |event| seen.lock().unwrap().push(event))
+        .with_reverse_request_handler(|command| match command {
+            RequestCommand::RunInTerminal(arguments) => {
+                assert_eq!(arguments.cwd, "/tmp");
+                Ok(ResponseBody::RunInTerminal(RunInTerminalResponse {
+                    process_id: ProcessId::new(7).ok(),
+                    shell_process_id: None,
+                }))
+            }
+            other => Err(RequestError::unsupported(other.command())),
+        })
+        .build(client_reader, client_writer);
+
+    client.request(initialize_arguments()).unwrap();
+    client.request(launch_arguments()).unwrap();
+    assert!(client.session().is_launched());
+    drop(client);
+
+    let adapter = runner.join().unwrap();
+    assert_eq!(adapter.process_id, Some(7));
+    assert_eq!(*events.lock().unwrap(), vec![EventBody::Initialized]);
+}
+
+#[test]
+fn reverse_requests_fail_without_a_handler() {
+    let (client_writer, adapter_reader) = pipe();
+    let (adapter_writer, client_reader) = pipe();
+    let runner = thread::spawn(move || {
+        adapter::run(&mut TestAdapter::default(), adapter_reader, adapter_writer)
+    });
+
+    let client = DebugClient::new(client_reader, client_writer);
+    client.request(initialize_arguments()).unwrap();
+    let err = client.request(launch_arguments()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "'launch' request failed: cannot run the debuggee"
+    );
+    drop(client);
+    runner.join().unwrap().unwrap();
+}
+
+#[test]
+fn requests_time_out() {
+    let (client_writer, _adapter_reader) = pipe();
+    let (_adapter_writer, client_reader) = pipe();
+    let client = DebugClient::builder()
+        .with_timeout(Duration::from_secs(60))
+        .build(client_reader, client_writer);
+
+    let err = client
+        .request_with_timeout(initialize_arguments(), Some(Duration::from_millis(20)))
+        .unwrap_err();
+    assert!(matches!(err, ClientError::Timeout));
+}
+
+#[test]
+fn requests_fail_when_the_adapter_disconnects() {
+    let (client_writer, _adapter_reader) = pipe();
+    let (adapter_writer, client_reader) = pipe();
+    let client = DebugClient::new(client_reader, client_writer);
+
+    let pending = client.send_request(initialize_arguments()).unwrap();
+    drop(adapter_writer);
+    assert!(pending.wait().is_none());
+    assert!(matches!(
+        client.request(disconnect_arguments()),
+        Err(ClientError::Disconnected)
+    ));
+}
+
+#[test]
+fn requests_out_of_order_are_not_sent() {
+    let (client_writer, adapter_reader) = pipe();
+    let (adapter_writer, client_reader) = pipe();
+    let runner = thread::spawn(move || {
+        adapter::run(&mut TestAdapter::default(), adapter_reader, adapter_writer)
+    });
+
+    let client = DebugClient::new(client_reader, client_writer);
+    let err = client.request(ThreadsArguments {}).unwrap_err();
+    match &err {
+        ClientError::OutOfOrder(Violation::NotInitialized { command }) => {
+            assert_eq!(command, "threads")
+        }
+        other => panic!("expected an out of order request, got {:?}", other),
+    }
+    assert_eq!(
+        err.to_string(),
+        "'threads' was sent before 'initialize' completed"
+    );
+
+    client.request(initialize_arguments()).unwrap();
+    assert!(matches!(
+        client.request(ThreadsArguments {}),
+        Err(ClientError::OutOfOrder(Violation::NotLaunched { .. }))
+    ));
+    assert!(client.session().capabilities().is_some());
+
+    let completions: CompletionsArguments =
+        serde_json::from_value(json!({ "text": "x", "column": 1 })).unwrap();
+    let err = client.request(completions).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "'completions' needs 'supportsCompletionsRequest'"
+    );
+    assert!(matches!(err, ClientError::Unsupported(_)));
+
+    drop(client);
+    runner.join().unwrap().unwrap();
+}
+
+/**
+ * Writes a message body as is, such as one that does not parse.
+ */
+fn write_raw(writer: &mut impl Write, body: serde_json::Value) {
+    let body = body.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body).unwrap();
+}
+
+#[test]
+fn reverse_requests_run_off_the_reader_thread() {
+    let (client_writer, adapter_reader) = pipe();
+    let (mut adapter_writer, client_reader) = pipe();
+    let (event_sender, events) = mpsc::channel();
+    let client = DebugClient::builder()
+        .with_event_handler(move |event| event_sender.send(event).unwrap())
+        .with_reverse_request_handler(move |_| {
+            // The event arrives after the request, so waiting for it on the
+            // reader thread would never end.
+            let event = events.recv_timeout(Duration::from_secs(5)).unwrap();
+            assert!(matches!(event, EventBody::Output(_)));
+            Ok(ResponseBody::RunInTerminal(RunInTerminalResponse {
+                process_id: ProcessId::new(7).ok(),
+                shell_process_id: None,
+            }))
+        })
+        .build(client_reader, client_writer);
+
+    write_raw(
+        &mut adapter_writer,
+        json!({
+            "seq": 1,
+            "type": "request",
+            "command": "runInTerminal",
+            "arguments": { "cwd": "/tmp", "args": ["debuggee"] },
+        }),
+    );
+    write_raw(
+        &mut adapter_writer,
+        json!({ "seq": 2, "type": "event", "event": "output", "body": { "output": "x" } }),
+    );
+    let response = match MessageReader::new(adapter_reader).read_message() {
+        Ok(Some(ProtocolMessage {
+            message: MessageKind::Response(response),
+            ..
+        })) => response,
+        other => panic!("expected a response, got {:?}", other),
+    };
+    assert_eq!(response.request_seq, 1);
+    let body = response
+        .into_body::<RunInTerminalRequestArguments>()
+        .unwrap();
+    assert_eq!(body.process_id.map(i64::from), Some(7));
+    drop(client);
+}
+
+#[test]
+fn skips_invalid_messages() {
+    let (client_writer, adapter_reader) = pipe();
+    let (mut adapter_writer, client_reader) = pipe();
+    let client = DebugClient::builder()
+        .with_timeout(Duration::from_secs(5))
+        .build(client_reader, client_writer);
+    let mut adapter_reader = MessageReader::new(adapter_reader);
+    let mut receive = move || match adapter_reader.read_message() {
+        Ok(Some(message)) => message,
+        other => panic!("expected a message, got {:?}", other),
+    };
+
+    let pending = client.send_request(initialize_arguments()).unwrap();
+    let request_seq = receive().seq;
+    write_raw(&mut adapter_writer, json!("not a message"));
+    write_raw(
+        &mut adapter_writer,
+        json!({ "seq": 1, "type": "event", "event": "output", "body": { "output": 1 } }),
+    );
+    write_raw(
+        &mut adapter_writer,
+        json!({
+            "seq": 2,
+            "type": "request",
+            "command": "runInTerminal",
+            "arguments": { "cwd": 1 },
+        }),
+    );
+    match receive().message {
+        MessageKind::Response(response) => {
+            assert_eq!(response.request_seq, 2);
+            assert!(matches!(
+                response.message,
+                Some(ResponseMessage::Error(message)) if message.starts_with("invalid arguments: ")
+            ));
+        }
+        other => panic!("expected a response, got {:?}", other),
+    }
+    write_raw(
+        &mut adapter_writer,
+        json!({
+            "seq": 3,
+            "type": "response",
+            "request_seq": request_seq,
+            "success": true,
+            "command": "initialize",
+            "body": { "supportsConfigurationDoneRequest": "yes" },
+        }),
+    );
+    let response = pending.wait().unwrap();
+    assert!(matches!(
+        response.message,
+        Some(ResponseMessage::Error(message)) if message.starts_with("invalid response: ")
+    ));
+    assert!(client.session().capabilities().is_none());
+
+    // The client is still connected.
+    let pending = client.send_request(initialize_arguments()).unwrap();
+    let request_seq = receive().seq;
+    write_raw(
+        &mut adapter_writer,
+        json!({
+            "seq": 4,
+            "type": "response",
+            "request_seq": request_seq,
+            "success": true,
+            "command": "initialize",
+            "body": {},
+        }),
+    );
+    assert!(pending.wait().unwrap().is_success());
+    assert!(client.session().capabilities().is_some());
+}
+
+/**
+ * A writer whose first write blocks until the gate opens, like a pipe the other
+ * side does not read from.
+ */
+struct GatedWriter {
+    started: mpsc::Sender<()>,
+    gate: Option<mpsc::Receiver<()>>,
+    inner: PipeWriter,
+}
+
+impl Write for GatedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(gate) = self.gate.take() {
+            self.started.send(()).unwrap();
+            let _ = gate.recv();
+        }
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[test]
+fn reads_while_a_request_is_being_written() {
+    let (client_writer, adapter_reader) = pipe();
+    let (mut adapter_writer, client_reader) = pipe();
+    let (started_sender, started) = mpsc::channel();
+    let (open, gate) = mpsc::channel();
+    let writer = GatedWriter {
+        started: started_sender,
+        gate: Some(gate),
+        inner: client_writer,
+    };
+    let (event_sender, events) = mpsc::channel();
+    let client = DebugClient::builder()
+        .with_event_handler(move |event| event_sender.send(event).unwrap())
+        .build(client_reader, writer);
+
+    let adapter = thread::spawn(move || {
+        started.recv_timeout(Duration::from_secs(5)).unwrap();
+        write_raw(
+            &mut adapter_writer,
+            json!({ "seq": 1, "type": "event", "event": "output", "body": { "output": "x" } }),
+        );
+        // The write of the request is still blocked, yet the event gets through.
+        let event = events.recv_timeout(Duration::from_secs(5));
+        open.send(()).unwrap();
+        (event, adapter_writer)
+    });
+    let pending = client.send_request(initialize_arguments()).unwrap();
+    let (event, _adapter_writer) = adapter.join().unwrap();
+    assert!(matches!(event, Ok(EventBody::Output(_))));
+    match MessageReader::new(adapter_reader).read_message() {
+        Ok(Some(ProtocolMessage {
+            seq,
+            message: MessageKind::Request(RequestCommand::Initialize(_)),
+        })) => assert_eq!(seq, pending.seq()),
+        other => panic!("expected the request, got {:?}", other),
+    }
+    drop(client);
+}
@@ -0,0 +1,97 @@
+use debug_types::events::EventBody;
+use debug_types::handles::{Handles, Registry};
+use debug_types::requests::*;
+use debug_types::types::{FrameId, VariablesReference};
+use serde_json::json;
+
+fn request(command: &str, arguments: serde_json::Value) -> RequestCommand {
+    serde_json::from_value(json!({ "command": command, "arguments": arguments })).unwrap()
+}
+
+#[test]
+fn allocates_unique_handles() {
+    let mut handles = Handles::<VariablesReference, &str>::new();
+    let locals = handles.insert("locals");
+    let globals = handles.insert("globals");
+    assert_eq!(locals.get(), 1);
+    assert_eq!(globals.get(), 2);
+    assert_eq!(handles.get(locals), Some(&"locals"));
+    assert_eq!(handles.get(VariablesReference::new(0).unwrap()), None);
+
+    // Cleared handles are not handed out again.
+    handles.clear();
+    assert_eq!(handles.get(locals), None);
+    assert_eq!(handles.insert("locals").get(), 3);
+}
+
+#[test]
+fn removes_handles() {
+    let mut handles = Handles::<FrameId, u32>::new();
+    let first = handles.insert(1);
+    let second = handles.insert(2);
+    *handles.get_mut(second).unwrap() += 1;
+
+    assert_eq!(handles.remove(first), Some(1));
+    assert_eq!(handles.remove(first), None);
+    assert!(!handles.contains(first));
+    assert_eq!(handles.get(second), Some(&3));
+    assert_eq!(handles.len(), 1);
+    assert_eq!(handles.insert(4), FrameId(3));
+}
+
+#[test]
+fn resolves_arguments() {
+    let mut registry = Registry::<&str, &str, &str>::new();
+    let frame = registry.frames.insert("main");
+    let locals = registry.variables.insert("locals");
+    let source = registry.sources.insert("generated.rs");
+
+    let scopes = ScopesArguments { frame_id: frame };
+    assert_eq!(registry.scopes(&scopes), Some(&"main"));
+
+    let variables: VariablesArguments =
+        serde_json::from_value(json!({ "variablesReference": locals })).unwrap();
+    assert_eq!(registry.variables(&variables), Some(&"locals"));
+
+    let deprecated: SourceArguments =
+        serde_json::from_value(json!({ "sourceReference": source })).unwrap();
+    assert_eq!(registry.source(&deprecated), Some(&"generated.rs"));
+    let preferred: SourceArguments = serde_json::from_value(json!({
+        "source": { "sourceReference": source },
+        "sourceReference": 0,
+    }))
+    .unwrap();
+    assert_eq!(registry.source(&preferred), Some(&"generated.rs"));
+}
+
+#[test]
+fn invalidates_handles_when_resuming() {
+    let mut registry = Registry::<(), (), ()>::new();
+    let frame = registry.frames.insert(());
+    let locals = registry.variables.insert(());
+    let source = registry.sources.insert(());
+
+    registry.request(&request("threads", json!(null)));
+    registry.request(&request("stackTrace", json!({ "threadId": 1 })));
+    assert!(registry.frames.contains(frame));
+
+    registry.request(&request("next", json!({ "threadId": 1 })));
+    assert!(!registry.frames.contains(frame));
+    assert!(!registry.variables.contains(locals));
+    assert!(registry.sources.contains(source));
+
+    let locals = registry.variables.insert(());
+    registry.event(&EventBody::Initialized);
+    assert!(registry.variables.contains(locals));
+    let continued: EventBody =
+        serde_json::from_value(json!({ "event": "continued", "body": { "threadId": 1 } })).unwrap();
+    registry.event(&continued);
+    assert!(registry.variables.is_empty());
+
+    let frame = registry.frames.insert(());
+    let locals = registry.variables.insert(());
+    registry.request(&request("restart", json!({})));
+    assert!(!registry.frames.contains(frame));
+    assert!(!registry.variables.contains(locals));
+    assert!(registry.sources.contains(source));
+}
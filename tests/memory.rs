@@ -0,0 +1,106 @@
+use debug_types::events::MemoryEvent;
+use debug_types::requests::{DisassembleArguments, ReadMemoryArguments};
+use debug_types::responses::ReadMemoryResponse;
+use debug_types::types::{Address, AddressError, DisassembledInstruction, MemoryReference};
+use serde_json::json;
+
+#[test]
+fn parses_addresses() {
+    assert_eq!("0x1000".parse(), Ok(Address(0x1000)));
+    assert_eq!("0XfF".parse(), Ok(Address(0xff)));
+    assert_eq!("4096".parse(), Ok(Address(4096)));
+    assert_eq!("0xffffffffffffffff".parse(), Ok(Address(u64::MAX)));
+    for invalid in [
+        "",
+        "0x",
+        "-1",
+        "+1",
+        "0x+1",
+        "1f",
+        "0x10000000000000000",
+        " 1",
+    ] {
+        assert_eq!(
+            invalid.parse::<Address>(),
+            Err(AddressError::Invalid(invalid.to_string())),
+            "{:?}",
+            invalid
+        );
+    }
+    assert_eq!(
+        "main".parse::<Address>().unwrap_err().to_string(),
+        "'main' is not an address"
+    );
+}
+
+#[test]
+fn formats_addresses() {
+    let address = Address(0xbeef);
+    assert_eq!(address.to_string(), "0xbeef");
+    assert_eq!(format!("{:#010x}", address), "0x0000beef");
+    assert_eq!(format!("{:X}", address), "BEEF");
+    assert_eq!(Address(0).to_string(), "0x0");
+    assert_eq!(
+        MemoryReference::from(address),
+        MemoryReference::from("0xbeef")
+    );
+}
+
+#[test]
+fn offsets_addresses() {
+    let address = Address(0x1000);
+    assert_eq!(address.checked_add(-0x10), Some(Address(0xff0)));
+    assert_eq!(address.checked_add(-0x1001), None);
+    assert_eq!(Address(u64::MAX).checked_add(1), None);
+    assert_eq!(
+        address.offset(-0x1001),
+        Err(AddressError::Overflow {
+            address,
+            offset: -0x1001
+        })
+    );
+    assert_eq!(
+        address.offset(-0x1001).unwrap_err().to_string(),
+        "0x1000 -4097 is out of range"
+    );
+    assert_eq!(Address(0xff0).offset_from(address), Some(-0x10));
+    assert_eq!(Address(u64::MAX).offset_from(Address(0)), None);
+}
+
+#[test]
+fn resolves_memory_references() {
+    let read = ReadMemoryArguments::new(Address(0x1000), 16).offset(-4);
+    assert_eq!(read.address(), Ok(Address(0xffc)));
+
+    let disassemble: DisassembleArguments = serde_json::from_value(json!({
+        "memoryReference": "4096",
+        "offset": 8,
+        "instructionOffset": -2,
+        "instructionCount": 10,
+    }))
+    .unwrap();
+    assert_eq!(disassemble.address(), Ok(Address(0x1008)));
+
+    let event = MemoryEvent::new("frame 1", 0, 4);
+    assert_eq!(
+        event.address(),
+        Err(AddressError::Invalid("frame 1".to_string()))
+    );
+}
+
+#[test]
+fn serializes_addresses() {
+    let response = ReadMemoryResponse::new(Address(0x1000)).unreadable_bytes(4);
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        json!({ "address": "0x1000", "unreadableBytes": 4 })
+    );
+
+    let instruction: DisassembledInstruction =
+        serde_json::from_value(json!({ "address": "4096", "instruction": "nop" })).unwrap();
+    assert_eq!(instruction.address, Address(0x1000));
+    assert!(serde_json::from_value::<DisassembledInstruction>(
+        json!({ "address": "pc", "instruction": "nop" })
+    )
+    .is_err());
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "base64")]
+
+use debug_types::memory::{MemoryError, MemoryRead};
+use debug_types::requests::{ReadMemoryArguments, WriteMemoryArguments};
+use debug_types::responses::{ReadMemoryResponse, WriteMemoryResponse};
+use debug_types::types::Address;
+
+#[test]
+fn reads_bytes() {
+    let arguments = ReadMemoryArguments::new("0x1000", 8);
+    let response = ReadMemoryResponse::new(0x1000).bytes(&[1, 2, 3, 4]);
+    assert_eq!(response.data.as_deref(), Some("AQIDBA=="));
+
+    let read = response
+        .clone()
+        .unreadable_bytes(4)
+        .read(&arguments)
+        .unwrap();
+    assert_eq!(
+        read,
+        MemoryRead {
+            address: Address(0x1000),
+            bytes: vec![1, 2, 3, 4],
+            unreadable_bytes: 4,
+            end_of_memory: false,
+        }
+    );
+    assert_eq!(read.next_address(), Some(Address(0x1008)));
+
+    let read = response.read(&arguments).unwrap();
+    assert!(read.end_of_memory);
+    assert_eq!(read.next_address(), Some(Address(0x1004)));
+
+    let empty = ReadMemoryResponse::new(0x1000).unreadable_bytes(8);
+    assert_eq!(empty.read(&arguments).unwrap().bytes, Vec::<u8>::new());
+}
+
+#[test]
+fn rejects_invalid_reads() {
+    let arguments = ReadMemoryArguments::new("0x1000", 2);
+    let response = ReadMemoryResponse::new(0x1000).bytes(&[1, 2, 3]);
+    assert_eq!(
+        response.read(&arguments),
+        Err(MemoryError::TooMany {
+            requested: 2,
+            returned: 3
+        })
+    );
+    let response = ReadMemoryResponse::new(0x1000)
+        .bytes(&[1])
+        .unreadable_bytes(2);
+    assert!(matches!(
+        response.read(&arguments),
+        Err(MemoryError::TooMany { .. })
+    ));
+    let response = ReadMemoryResponse::new(0x1000).data("not base64!");
+    assert!(matches!(
+        response.read(&arguments),
+        Err(MemoryError::InvalidData(_))
+    ));
+}
+
+#[test]
+fn writes_bytes() {
+    let arguments = WriteMemoryArguments::with_bytes("0x2000", &[0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(arguments.data, "3q2+7w==");
+    assert_eq!(arguments.decode_data(), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+
+    let response = arguments.respond(4).unwrap();
+    assert_eq!(response, WriteMemoryResponse::default());
+    assert_eq!(arguments.bytes_written(&response), Ok(4));
+    assert_eq!(
+        arguments.respond(2),
+        Err(MemoryError::PartialWrite {
+            written: 2,
+            length: 4
+        })
+    );
+    assert_eq!(
+        arguments.bytes_written(&WriteMemoryResponse::default().bytes_written(2)),
+        Err(MemoryError::PartialWrite {
+            written: 2,
+            length: 4
+        })
+    );
+
+    let partial = arguments.offset(16).allow_partial(true);
+    let response = partial.respond(2).unwrap();
+    assert_eq!(
+        response,
+        WriteMemoryResponse::default().offset(16).bytes_written(2)
+    );
+    assert_eq!(partial.bytes_written(&response), Ok(2));
+    assert_eq!(
+        partial.respond(5),
+        Err(MemoryError::TooManyWritten {
+            written: 5,
+            length: 4
+        })
+    );
+    assert_eq!(
+        partial.bytes_written(&WriteMemoryResponse::default().bytes_written(5)),
+        Err(MemoryError::TooManyWritten {
+            written: 5,
+            length: 4
+        })
+    );
+    assert_eq!(
+        partial.respond(5).unwrap_err().to_string(),
+        "5 bytes written, but only 4 given"
+    );
+}
@@ -0,0 +1,249 @@
+use debug_types::paths::{self, PathError, PathMappings, Sources};
+use debug_types::requests::PathFormat;
+use debug_types::ProtocolMessage;
+use serde_json::json;
+
+#[test]
+fn converts_paths_to_uris() {
+    let uri = |path| paths::path_to_uri(path).unwrap();
+    assert_eq!(uri("/src/main.rs"), "file:///src/main.rs");
+    assert_eq!(
+        uri("/home/me/my project/#1.rs"),
+        "file:///home/me/my%20project/%231.rs"
+    );
+    assert_eq!(uri("/tmp/ünï"), "file:///tmp/%C3%BCn%C3%AF");
+    assert_eq!(uri("C:\\project\\main.rs"), "file:///C:/project/main.rs");
+    assert_eq!(uri("C:\\"), "file:///C:/");
+    assert_eq!(uri("//server/share/main.rs"), "file://server/share/main.rs");
+    assert_eq!(
+        uri("\\\\server\\share\\main.rs"),
+        "file://server/share/main.rs"
+    );
+    // Only Windows paths use backslashes as separators.
+    assert_eq!(uri("/tmp/a\\b"), "file:///tmp/a%5Cb");
+
+    for path in [
+        "main.rs",
+        "./src/main.rs",
+        "../main.rs",
+        "C:main.rs",
+        "C:",
+        "",
+    ] {
+        assert_eq!(
+            paths::path_to_uri(path),
+            Err(PathError::RelativePath(path.to_string()))
+        );
+    }
+    assert_eq!(
+        paths::path_to_uri("main.rs").unwrap_err().to_string(),
+        "'main.rs' is not an absolute path"
+    );
+}
+
+#[test]
+fn converts_uris_to_paths() {
+    assert_eq!(
+        paths::uri_to_path("file:///home/me/my%20project/main.rs").unwrap(),
+        "/home/me/my project/main.rs"
+    );
+    assert_eq!(
+        paths::uri_to_path("file://localhost/src/main.rs").unwrap(),
+        "/src/main.rs"
+    );
+    // Clients encode the colon of a drive letter, or not.
+    assert_eq!(
+        paths::uri_to_path("file:///c%3A/project/main.rs").unwrap(),
+        "c:/project/main.rs"
+    );
+    assert_eq!(
+        paths::uri_to_path("FILE:///C:/project/main.rs").unwrap(),
+        "C:/project/main.rs"
+    );
+    assert_eq!(
+        paths::uri_to_path("file://server/share/main.rs").unwrap(),
+        "//server/share/main.rs"
+    );
+
+    for uri in [
+        "untitled:Untitled-1",
+        "file:///bad%2",
+        "file:///bad%zz",
+        "file:///%FF",
+    ] {
+        assert_eq!(
+            paths::uri_to_path(uri),
+            Err(PathError::InvalidUri(uri.to_string()))
+        );
+    }
+    assert_eq!(
+        paths::uri_to_path("untitled:1").unwrap_err().to_string(),
+        "'untitled:1' is not a file URI"
+    );
+}
+
+#[test]
+fn round_trips() {
+    for path in [
+        "/src/main.rs",
+        "/home/me/my project/100%/a+b=c.rs",
+        "/tmp/ünï/código.rs",
+        "/",
+        "C:/project/main.rs",
+        "c:/project/main.rs",
+        "//server/share/main.rs",
+    ] {
+        let uri = paths::path_to_uri(path).unwrap();
+        assert_eq!(paths::uri_to_path(&uri).unwrap(), path, "{}", uri);
+    }
+    // Windows paths come back with slashes.
+    for (path, normalized) in [
+        ("C:\\project\\main.rs", "C:/project/main.rs"),
+        ("\\\\server\\share\\main.rs", "//server/share/main.rs"),
+    ] {
+        let uri = paths::path_to_uri(path).unwrap();
+        assert_eq!(paths::uri_to_path(&uri).unwrap(), normalized, "{}", uri);
+    }
+    for uri in [
+        "file:///src/main.rs",
+        "file:///home/me/my%20project/%231.rs",
+        "file:///c:/project/main.rs",
+        "file://server/share/main.rs",
+    ] {
+        let path = paths::uri_to_path(uri).unwrap();
+        assert_eq!(paths::path_to_uri(&path).unwrap(), uri, "{}", path);
+    }
+}
+
+#[test]
+fn converts_sources_in_messages() {
+    let mut message: ProtocolMessage = serde_json::from_value(json!({
+        "seq": 1,
+        "type": "response",
+        "request_seq": 1,
+        "success": true,
+        "command": "loadedSources",
+        "body": {
+            "sources": [
+                { "path": "/src/my file.rs", "sources": [{ "path": "/src/nested.rs" }] },
+                { "name": "generated", "sourceReference": 3 },
+            ],
+        },
+    }))
+    .unwrap();
+    message.convert_paths(&PathFormat::Path, &PathFormat::Uri);
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(
+        value["body"]["sources"],
+        json!([
+            { "path": "file:///src/my%20file.rs", "sources": [{ "path": "file:///src/nested.rs" }] },
+            { "name": "generated", "sourceReference": 3 },
+        ])
+    );
+
+    message.convert_paths(&PathFormat::Uri, &PathFormat::Path);
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(value["body"]["sources"][0]["path"], "/src/my file.rs");
+
+    // Unknown formats are left alone.
+    let other = PathFormat::Other("custom".to_string());
+    message.convert_paths(&PathFormat::Path, &other);
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(value["body"]["sources"][0]["path"], "/src/my file.rs");
+}
+
+#[test]
+fn maps_paths_between_machines() {
+    let mappings = PathMappings::new()
+        .map("/", "/mnt/container")
+        .map("/app/", "/home/me/project")
+        .map("/app/contracts", "/home/me/contracts");
+
+    // The longest root wins.
+    assert_eq!(
+        mappings.local_path("/app/contracts/x.clar").as_deref(),
+        Some("/home/me/contracts/x.clar")
+    );
+    assert_eq!(
+        mappings.local_path("/app/main.rs").as_deref(),
+        Some("/home/me/project/main.rs")
+    );
+    assert_eq!(
+        mappings.local_path("/app").as_deref(),
+        Some("/home/me/project")
+    );
+    // Roots only match whole components.
+    assert_eq!(
+        mappings.local_path("/application/main.rs").as_deref(),
+        Some("/mnt/container/application/main.rs")
+    );
+    assert_eq!(
+        mappings.remote_path("/home/me/project/main.rs").as_deref(),
+        Some("/app/main.rs")
+    );
+    assert_eq!(mappings.remote_path("/mnt/container").as_deref(), Some("/"));
+    assert_eq!(mappings.remote_path("/home/me/projects/main.rs"), None);
+    assert_eq!(PathMappings::new().local_path("/app/main.rs"), None);
+}
+
+#[test]
+fn maps_sources_in_messages() {
+    let mappings: PathMappings = serde_json::from_value(json!([
+        { "remoteRoot": "/app", "localRoot": "/home/me/project" },
+    ]))
+    .unwrap();
+    assert_eq!(mappings.mappings().len(), 1);
+
+    let mut response: ProtocolMessage = serde_json::from_value(json!({
+        "seq": 1,
+        "type": "response",
+        "request_seq": 1,
+        "success": true,
+        "command": "stackTrace",
+        "body": {
+            "stackFrames": [
+                { "id": 1, "name": "main", "line": 1, "column": 1, "source": { "path": "/app/x.clar" } },
+                { "id": 2, "name": "std", "line": 1, "column": 1, "source": { "path": "/usr/lib/std.clar" } },
+            ],
+        },
+    }))
+    .unwrap();
+    mappings.map_to_local(&mut response);
+    let value = serde_json::to_value(&response).unwrap();
+    assert_eq!(
+        value["body"]["stackFrames"][0]["source"]["path"],
+        "/home/me/project/x.clar"
+    );
+    assert_eq!(
+        value["body"]["stackFrames"][1]["source"]["path"],
+        "/usr/lib/std.clar"
+    );
+
+    let mut request: ProtocolMessage = serde_json::from_value(json!({
+        "seq": 2,
+        "type": "request",
+        "command": "setBreakpoints",
+        "arguments": {
+            "source": { "path": "/home/me/project/x.clar" },
+            "breakpoints": [{ "line": 3 }],
+        },
+    }))
+    .unwrap();
+    mappings.map_to_remote(&mut request);
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["arguments"]["source"]["path"], "/app/x.clar");
+
+    let mut event: ProtocolMessage = serde_json::from_value(json!({
+        "seq": 3,
+        "type": "event",
+        "event": "breakpoint",
+        "body": { "reason": "changed", "breakpoint": { "verified": true, "source": { "path": "/app/x.clar" } } },
+    }))
+    .unwrap();
+    mappings.map_to_local(&mut event);
+    let value = serde_json::to_value(&event).unwrap();
+    assert_eq!(
+        value["body"]["breakpoint"]["source"]["path"],
+        "/home/me/project/x.clar"
+    );
+}
@@ -0,0 +1,117 @@
+use debug_types::positions::{Bases, Positions, Shift};
+use debug_types::requests::InitializeRequestArguments;
+use debug_types::ProtocolMessage;
+use serde_json::json;
+
+#[test]
+fn computes_shifts() {
+    let client: InitializeRequestArguments =
+        serde_json::from_value(json!({ "adapterID": "test", "columnsStartAt1": false })).unwrap();
+    let client = Bases::of_client(&client);
+    assert_eq!(
+        client,
+        Bases {
+            lines_start_at1: true,
+            columns_start_at1: false,
+        }
+    );
+
+    let shift = Shift::new(Bases::ZERO, client);
+    assert_eq!(
+        shift,
+        Shift {
+            lines: 1,
+            columns: 0,
+        }
+    );
+    assert_eq!(shift.reverse().lines, -1);
+    assert!(Shift::new(client, client).is_none());
+    assert_eq!(Bases::default(), Bases::ONE);
+}
+
+fn shifted(message: serde_json::Value, shift: Shift) -> serde_json::Value {
+    let mut message: ProtocolMessage = serde_json::from_value(message).unwrap();
+    message.shift(shift);
+    serde_json::to_value(message).unwrap()
+}
+
+#[test]
+fn shifts_messages() {
+    let to_client = Shift::new(Bases::ZERO, Bases::ONE);
+
+    let request = json!({
+        "seq": 1,
+        "type": "request",
+        "command": "setBreakpoints",
+        "arguments": {
+            "source": { "path": "/src/main.rs" },
+            "breakpoints": [{ "line": 3, "column": 1 }, { "line": 7 }],
+            "lines": [3, 7],
+        },
+    });
+    assert_eq!(
+        shifted(request, to_client.reverse())["arguments"],
+        json!({
+            "source": { "path": "/src/main.rs" },
+            "breakpoints": [{ "line": 2, "column": 0 }, { "line": 6 }],
+            "lines": [2, 6],
+        })
+    );
+
+    let response = json!({
+        "seq": 2,
+        "type": "response",
+        "request_seq": 1,
+        "success": true,
+        "command": "stackTrace",
+        "body": {
+            "stackFrames": [
+                { "id": 1, "name": "main", "source": {}, "line": 0, "column": 0, "endLine": 2 },
+                { "id": 2, "name": "start", "line": 0, "column": 0 },
+            ],
+        },
+    });
+    let response = shifted(response, to_client);
+    assert_eq!(
+        response["body"]["stackFrames"][0],
+        json!({ "id": 1, "name": "main", "source": {}, "line": 1, "column": 1, "endLine": 3 })
+    );
+    // Without a source, the position is 0 and to be ignored in every convention.
+    assert_eq!(
+        response["body"]["stackFrames"][1],
+        json!({ "id": 2, "name": "start", "line": 0, "column": 0 })
+    );
+    for shift in [to_client, to_client.reverse()] {
+        let scopes = json!({
+            "seq": 2,
+            "type": "response",
+            "request_seq": 1,
+            "success": true,
+            "command": "scopes",
+            "body": {
+                "scopes": [{
+                    "name": "Locals",
+                    "variablesReference": 1,
+                    "expensive": false,
+                    "line": 1,
+                }],
+            },
+        });
+        assert_eq!(shifted(scopes.clone(), shift), scopes);
+    }
+
+    let event = json!({
+        "seq": 3,
+        "type": "event",
+        "event": "breakpoint",
+        "body": { "reason": "changed", "breakpoint": { "verified": true, "line": 4 } },
+    });
+    assert_eq!(
+        shifted(event, to_client)["body"]["breakpoint"],
+        json!({ "verified": true, "line": 5 })
+    );
+
+    // Messages without positions are left alone.
+    let threads = json!({ "seq": 4, "type": "request", "command": "threads" });
+    assert_eq!(shifted(threads.clone(), to_client), threads);
+}
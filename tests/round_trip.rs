@@ -0,0 +1,972 @@
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+use debug_types::events::EventBody;
+use debug_types::events::ProgressUpdateEvent;
+use debug_types::requests::{
+    ConfigurationDoneArguments, GotoTargetsArguments, LaunchRequestArguments,
+    LoadedSourcesArguments, Request, RequestCommand, RestartArguments, ScopesArguments,
+    ThreadsArguments, VariablesArguments,
+};
+use debug_types::responses::{ResponseBody, RunInTerminalResponse};
+use debug_types::types::{
+    FrameId, ModuleId, ProcessId, Scope, SourceReference, StackFrame, ThreadId, VariablesReference,
+};
+use debug_types::{MessageKind, NumberOrString, ProtocolMessage};
+use serde_json::{json, Value};
+
+const REQUESTS: &[(&str, Option<&str>)] = &[
+    ("cancel", Some(r#"{"requestId":1,"progressId":"p1"}"#)),
+    (
+        "initialize",
+        Some(
+            r#"{"clientID":"vscode","clientName":"Visual Studio Code","adapterID":"clarinet",
+            "locale":"en-us","linesStartAt1":true,"columnsStartAt1":true,"pathFormat":"path",
+            "supportsVariableType":true,"supportsVariablePaging":true,
+            "supportsRunInTerminalRequest":true,"supportsMemoryReferences":true,
+            "supportsProgressReporting":true,"supportsInvalidatedEvent":true,
+            "supportsMemoryEvent":true,"supportsArgsCanBeInterpretedByShell":true,
+            "supportsStartDebuggingRequest":true,"supportsANSIStyling":true}"#,
+        ),
+    ),
+    ("configurationDone", None),
+    (
+        "launch",
+        Some(
+            r#"{"noDebug":true,"__restart":{"a":1},"type":"clarinet","name":"Launch","program":"main.clar"}"#,
+        ),
+    ),
+    (
+        "attach",
+        Some(r#"{"__restart":true,"type":"clarinet","name":"Attach","port":1234}"#),
+    ),
+    (
+        "restart",
+        Some(r#"{"arguments":{"noDebug":false,"program":"main.clar"}}"#),
+    ),
+    (
+        "disconnect",
+        Some(r#"{"restart":false,"terminateDebuggee":true,"suspendDebuggee":false}"#),
+    ),
+    ("terminate", Some(r#"{"restart":true}"#)),
+    (
+        "breakpointLocations",
+        Some(r#"{"source":{"path":"/a.clar"},"line":3,"column":1,"endLine":4,"endColumn":2}"#),
+    ),
+    (
+        "setBreakpoints",
+        Some(
+            r#"{"source":{"name":"a.clar","path":"/a.clar"},"breakpoints":[{"line":3,"column":1,
+            "condition":"x","hitCondition":"2","logMessage":"m","mode":"hardware"}],"lines":[3],"sourceModified":false}"#,
+        ),
+    ),
+    (
+        "setFunctionBreakpoints",
+        Some(r#"{"breakpoints":[{"name":"f","condition":"c","hitCondition":"1"}]}"#),
+    ),
+    (
+        "setExceptionBreakpoints",
+        Some(
+            r#"{"filters":["all"],"filterOptions":[{"filterId":"all","condition":"c"}],
+            "exceptionOptions":[{"path":[{"negate":false,"names":["E"]}],"breakMode":"userUnhandled"}]}"#,
+        ),
+    ),
+    (
+        "dataBreakpointInfo",
+        Some(r#"{"name":"0x1000","frameId":3,"bytes":8,"asAddress":true,"mode":"hardware"}"#),
+    ),
+    (
+        "setDataBreakpoints",
+        Some(
+            r#"{"breakpoints":[{"dataId":"d","accessType":"readWrite","condition":"c","hitCondition":"h"}]}"#,
+        ),
+    ),
+    (
+        "setInstructionBreakpoints",
+        Some(
+            r#"{"breakpoints":[{"instructionReference":"0x10","offset":-4,"condition":"c","hitCondition":"h"}]}"#,
+        ),
+    ),
+    ("continue", Some(r#"{"threadId":1,"singleThread":true}"#)),
+    (
+        "next",
+        Some(r#"{"threadId":1,"singleThread":false,"granularity":"line"}"#),
+    ),
+    (
+        "stepIn",
+        Some(r#"{"threadId":1,"targetId":2,"granularity":"instruction"}"#),
+    ),
+    (
+        "stepOut",
+        Some(r#"{"threadId":1,"granularity":"statement"}"#),
+    ),
+    ("stepBack", Some(r#"{"threadId":1}"#)),
+    (
+        "reverseContinue",
+        Some(r#"{"threadId":1,"singleThread":true}"#),
+    ),
+    ("restartFrame", Some(r#"{"frameId":3}"#)),
+    ("goto", Some(r#"{"threadId":1,"targetId":9}"#)),
+    ("pause", Some(r#"{"threadId":1}"#)),
+    (
+        "stackTrace",
+        Some(
+            r#"{"threadId":1,"startFrame":0,"levels":20,"format":{"hex":true,"parameters":true,
+            "parameterTypes":true,"parameterNames":true,"parameterValues":true,"line":true,
+            "module":true,"includeAll":true}}"#,
+        ),
+    ),
+    ("scopes", Some(r#"{"frameId":3}"#)),
+    (
+        "variables",
+        Some(
+            r#"{"variablesReference":5,"filter":"indexed","start":0,"count":10,"format":{"hex":false}}"#,
+        ),
+    ),
+    (
+        "setVariable",
+        Some(r#"{"variablesReference":5,"name":"x","value":"1","format":{"hex":true}}"#),
+    ),
+    (
+        "source",
+        Some(r#"{"source":{"sourceReference":7},"sourceReference":7}"#),
+    ),
+    ("threads", None),
+    ("terminateThreads", Some(r#"{"threadIds":[1,2]}"#)),
+    ("modules", Some(r#"{"startModule":0,"moduleCount":5}"#)),
+    ("loadedSources", None),
+    (
+        "evaluate",
+        Some(
+            r#"{"expression":"x","frameId":3,"context":"hover","line":3,"column":5,
+            "source":{"path":"/a.clar"},"format":{"hex":true}}"#,
+        ),
+    ),
+    (
+        "setExpression",
+        Some(r#"{"expression":"x","value":"2","frameId":3,"format":{"hex":false}}"#),
+    ),
+    ("stepInTargets", Some(r#"{"frameId":3}"#)),
+    (
+        "gotoTargets",
+        Some(r#"{"source":{"path":"/a.clar"},"line":3,"column":1}"#),
+    ),
+    (
+        "completions",
+        Some(r#"{"frameId":3,"text":"x.","column":3,"line":1}"#),
+    ),
+    ("exceptionInfo", Some(r#"{"threadId":1}"#)),
+    (
+        "readMemory",
+        Some(r#"{"memoryReference":"0x1000","offset":-8,"count":16}"#),
+    ),
+    (
+        "writeMemory",
+        Some(r#"{"memoryReference":"0x1000","offset":0,"allowPartial":true,"data":"AAEC"}"#),
+    ),
+    (
+        "disassemble",
+        Some(
+            r#"{"memoryReference":"0x1000","offset":0,"instructionOffset":-2,"instructionCount":10,
+            "resolveSymbols":true}"#,
+        ),
+    ),
+    ("locations", Some(r#"{"locationReference":4}"#)),
+    (
+        "runInTerminal",
+        Some(
+            r#"{"kind":"integrated","title":"t","cwd":"/w","args":["node","a.js"],
+            "env":{"A":"1","B":null},"argsCanBeInterpretedByShell":false}"#,
+        ),
+    ),
+    (
+        "startDebugging",
+        Some(r#"{"configuration":{"type":"node","program":"a.js"},"request":"launch"}"#),
+    ),
+];
+
+const RESPONSES: &[(&str, Option<&str>)] = &[
+    ("cancel", None),
+    (
+        "initialize",
+        Some(
+            r#"{"supportsConfigurationDoneRequest":true,"supportsFunctionBreakpoints":true,
+            "exceptionBreakpointFilters":[{"filter":"all","label":"All","description":"d",
+            "default":false,"supportsCondition":true,"conditionDescription":"c"}],
+            "additionalModuleColumns":[{"attributeName":"a","label":"A","format":"f",
+            "type":"unixTimestampUTC","width":4}],"supportedChecksumAlgorithms":["MD5","timestamp"],
+            "supportsCancelRequest":true,"supportsDataBreakpointBytes":true,
+            "breakpointModes":[{"mode":"hardware","label":"Hardware","description":"d",
+            "appliesTo":["source","data"]}],"supportsANSIStyling":true}"#,
+        ),
+    ),
+    ("configurationDone", None),
+    ("launch", None),
+    ("attach", None),
+    ("restart", None),
+    ("disconnect", None),
+    ("terminate", None),
+    (
+        "breakpointLocations",
+        Some(r#"{"breakpoints":[{"line":3,"column":1,"endLine":3,"endColumn":5}]}"#),
+    ),
+    (
+        "setBreakpoints",
+        Some(
+            r#"{"breakpoints":[{"id":1,"verified":true,"message":"m","source":{"path":"/a.clar"},
+            "line":3,"column":1,"endLine":3,"endColumn":4,"instructionReference":"0x1","offset":0}]}"#,
+        ),
+    ),
+    (
+        "setFunctionBreakpoints",
+        Some(r#"{"breakpoints":[{"verified":false,"reason":"pending"}]}"#),
+    ),
+    (
+        "setExceptionBreakpoints",
+        Some(r#"{"breakpoints":[{"verified":true}]}"#),
+    ),
+    (
+        "dataBreakpointInfo",
+        Some(
+            r#"{"dataId":"d","description":"x","accessTypes":["read","write"],"canPersist":true}"#,
+        ),
+    ),
+    (
+        "setDataBreakpoints",
+        Some(r#"{"breakpoints":[{"verified":true}]}"#),
+    ),
+    (
+        "setInstructionBreakpoints",
+        Some(r#"{"breakpoints":[{"verified":true}]}"#),
+    ),
+    ("continue", Some(r#"{"allThreadsContinued":false}"#)),
+    ("next", None),
+    ("stepIn", None),
+    ("stepOut", None),
+    ("stepBack", None),
+    ("reverseContinue", None),
+    ("restartFrame", None),
+    ("goto", None),
+    ("pause", None),
+    (
+        "stackTrace",
+        Some(
+            r#"{"stackFrames":[{"id":1,"name":"main","source":{"path":"/a.clar",
+            "presentationHint":"emphasize"},"line":3,"column":1,"endLine":3,"endColumn":9,
+            "canRestart":true,"instructionPointerReference":"0x10","moduleId":1,
+            "presentationHint":"label"}],"totalFrames":1}"#,
+        ),
+    ),
+    (
+        "scopes",
+        Some(
+            r#"{"scopes":[{"name":"Locals","presentationHint":"locals","variablesReference":5,
+            "namedVariables":2,"indexedVariables":0,"expensive":false,"source":{"path":"/a.clar"},
+            "line":1,"column":1,"endLine":9,"endColumn":1},{"name":"Return",
+            "presentationHint":"returnValue","variablesReference":6,"expensive":false}]}"#,
+        ),
+    ),
+    (
+        "variables",
+        Some(
+            r#"{"variables":[{"name":"x","value":"1","type":"int","presentationHint":{"kind":"data",
+            "attributes":["readOnly"],"visibility":"public","lazy":false},"evaluateName":"x",
+            "variablesReference":0,"namedVariables":0,"indexedVariables":0,"memoryReference":"0x20",
+            "declarationLocationReference":7,"valueLocationReference":8}]}"#,
+        ),
+    ),
+    (
+        "setVariable",
+        Some(
+            r#"{"value":"2","type":"int","variablesReference":0,"namedVariables":0,"indexedVariables":0}"#,
+        ),
+    ),
+    (
+        "source",
+        Some(r#"{"content":"(ok true)","mimeType":"text/x-clarity"}"#),
+    ),
+    ("threads", Some(r#"{"threads":[{"id":1,"name":"main"}]}"#)),
+    ("terminateThreads", None),
+    (
+        "modules",
+        Some(
+            r#"{"modules":[{"id":"m1","name":"mod","path":"/m","isOptimized":false,"isUserCode":true,
+            "version":"1","symbolStatus":"Symbols Loaded","symbolFilePath":"/m.pdb",
+            "dateTimeStamp":"2022","addressRange":"0x0-0x10"}],"totalModules":1}"#,
+        ),
+    ),
+    (
+        "loadedSources",
+        Some(
+            r#"{"sources":[{"name":"a","path":"/a.clar","sourceReference":0,"origin":"internal module",
+            "sources":[],"adapterData":"x","checksums":[{"algorithm":"SHA256","checksum":"ab"}]}]}"#,
+        ),
+    ),
+    (
+        "evaluate",
+        Some(
+            r#"{"result":"1","type":"int","presentationHint":{"kind":"property"},"variablesReference":0,
+            "namedVariables":0,"indexedVariables":0,"memoryReference":"0x20","valueLocationReference":3}"#,
+        ),
+    ),
+    (
+        "setExpression",
+        Some(
+            r#"{"value":"2","type":"int","presentationHint":{"attributes":["static"]},"variablesReference":0}"#,
+        ),
+    ),
+    (
+        "stepInTargets",
+        Some(r#"{"targets":[{"id":1,"label":"f","line":3,"column":1,"endLine":3,"endColumn":4}]}"#),
+    ),
+    (
+        "gotoTargets",
+        Some(
+            r#"{"targets":[{"id":1,"label":"l","line":3,"column":1,"endLine":3,"endColumn":2,
+            "instructionPointerReference":"0x1"}]}"#,
+        ),
+    ),
+    (
+        "completions",
+        Some(
+            r#"{"targets":[{"label":"x","text":"x","sortText":"a","detail":"d","type":"variable",
+            "start":0,"length":1,"selectionStart":0,"selectionLength":0}]}"#,
+        ),
+    ),
+    (
+        "exceptionInfo",
+        Some(
+            r#"{"exceptionId":"E","description":"d","breakMode":"always","details":{"message":"m",
+            "typeName":"E","fullTypeName":"a.E","evaluateName":"e","stackTrace":"s",
+            "innerException":[{"message":"inner"}]}}"#,
+        ),
+    ),
+    (
+        "readMemory",
+        Some(r#"{"address":"0x1000","unreadableBytes":0,"data":"AAEC"}"#),
+    ),
+    ("writeMemory", Some(r#"{"offset":0,"bytesWritten":3}"#)),
+    (
+        "disassemble",
+        Some(
+            r#"{"instructions":[{"address":"0x1000","instructionBytes":"90","instruction":"nop",
+            "symbol":"main","location":{"path":"/a.clar"},"line":1,"column":1,"endLine":1,
+            "endColumn":2,"presentationHint":"invalid"}]}"#,
+        ),
+    ),
+    (
+        "locations",
+        Some(r#"{"source":{"path":"/a.clar"},"line":3,"column":1,"endLine":3,"endColumn":9}"#),
+    ),
+    (
+        "runInTerminal",
+        Some(r#"{"processId":12,"shellProcessId":11}"#),
+    ),
+    ("startDebugging", None),
+];
+
+const EVENTS: &[(&str, Option<&str>)] = &[
+    ("initialized", None),
+    (
+        "stopped",
+        Some(
+            r#"{"reason":"breakpoint","description":"d","threadId":1,"preserveFocusHint":false,
+            "text":"t","allThreadsStopped":true,"hitBreakpointIds":[1]}"#,
+        ),
+    ),
+    (
+        "continued",
+        Some(r#"{"threadId":1,"allThreadsContinued":true}"#),
+    ),
+    ("exited", Some(r#"{"exitCode":0}"#)),
+    ("terminated", Some(r#"{"restart":{"a":1}}"#)),
+    ("thread", Some(r#"{"reason":"started","threadId":1}"#)),
+    (
+        "output",
+        Some(
+            r#"{"category":"stdout","output":"hi\n","group":"start","variablesReference":0,
+            "source":{"path":"/a.clar"},"line":1,"column":1,"data":{"a":[1]},"locationReference":9}"#,
+        ),
+    ),
+    (
+        "breakpoint",
+        Some(r#"{"reason":"changed","breakpoint":{"id":1,"verified":true}}"#),
+    ),
+    (
+        "module",
+        Some(r#"{"reason":"new","module":{"id":1,"name":"m"}}"#),
+    ),
+    (
+        "loadedSource",
+        Some(r#"{"reason":"new","source":{"path":"/a.clar"}}"#),
+    ),
+    (
+        "process",
+        Some(
+            r#"{"name":"p","systemProcessId":1,"isLocalProcess":true,"startMethod":"launch",
+            "pointerSize":64}"#,
+        ),
+    ),
+    (
+        "capabilities",
+        Some(r#"{"capabilities":{"supportsStepBack":true}}"#),
+    ),
+    (
+        "progressStart",
+        Some(
+            r#"{"progressId":"p","title":"t","requestId":1,"cancellable":true,"message":"m",
+            "percentage":0.5}"#,
+        ),
+    ),
+    (
+        "progressUpdate",
+        Some(r#"{"progressId":"p","message":"m","percentage":50.5}"#),
+    ),
+    (
+        "progressEnd",
+        Some(r#"{"progressId":"p","message":"done"}"#),
+    ),
+    (
+        "invalidated",
+        Some(r#"{"areas":["variables"],"threadId":1,"stackFrameId":2}"#),
+    ),
+    (
+        "memory",
+        Some(r#"{"memoryReference":"0x10","offset":0,"count":4}"#),
+    ),
+];
+
+fn parse(fixture: Option<&str>) -> Option<Value> {
+    fixture.map(|fixture| serde_json::from_str(fixture).expect(fixture))
+}
+
+fn round_trip(expected: Value) -> ProtocolMessage {
+    let message: ProtocolMessage = serde_json::from_value(expected.clone())
+        .unwrap_or_else(|err| panic!("failed to parse {}: {}", expected, err));
+    let actual = serde_json::to_value(&message).unwrap();
+    assert_eq!(actual, expected);
+    message
+}
+
+fn assert_covers_all(indices: BTreeSet<usize>, fixtures: usize) {
+    assert_eq!(indices.len(), fixtures, "duplicate fixtures");
+    assert_eq!(indices, (0..fixtures).collect(), "missing fixtures");
+}
+
+#[test]
+fn requests() {
+    let mut indices = BTreeSet::new();
+    for (command, arguments) in REQUESTS {
+        let mut expected = json!({ "seq": 1, "type": "request", "command": command });
+        if let Some(arguments) = parse(*arguments) {
+            expected["arguments"] = arguments;
+        }
+        match round_trip(expected).message {
+            MessageKind::Request(request) => {
+                assert_eq!(request.command(), *command);
+                indices.insert(request_variant(&request));
+            }
+            other => panic!("expected a request, got {:?}", other),
+        }
+    }
+    assert_covers_all(indices, REQUESTS.len());
+}
+
+#[test]
+fn responses() {
+    let mut indices = BTreeSet::new();
+    for (command, body) in RESPONSES {
+        let mut expected = json!({
+            "seq": 2,
+            "type": "response",
+            "request_seq": 1,
+            "success": true,
+            "command": command,
+        });
+        if let Some(body) = parse(*body) {
+            expected["body"] = body;
+        }
+        match round_trip(expected).message {
+            MessageKind::Response(response) => {
+                assert_eq!(response.command(), *command);
+                indices.insert(response_variant(response.result.as_ref().unwrap()));
+            }
+            other => panic!("expected a response, got {:?}", other),
+        }
+    }
+    assert_covers_all(indices, RESPONSES.len());
+}
+
+#[test]
+fn error_response() {
+    let expected = json!({
+        "seq": 2,
+        "type": "response",
+        "request_seq": 1,
+        "success": false,
+        "command": "evaluate",
+        "message": "failed",
+        "body": {
+            "error": {
+                "id": 1,
+                "format": "{a}",
+                "variables": { "a": "b" },
+                "sendTelemetry": false,
+                "showUser": true,
+                "url": "https://example.com",
+                "urlLabel": "More",
+            },
+        },
+    });
+    match round_trip(expected).message {
+        MessageKind::Response(response) => {
+            assert!(!response.is_success());
+            assert_eq!(response.command(), "evaluate");
+            assert_eq!(response.result.unwrap_err().error.unwrap().id, 1);
+        }
+        other => panic!("expected a response, got {:?}", other),
+    }
+}
+
+#[test]
+fn unstructured_error_response() {
+    let expected = json!({
+        "seq": 2,
+        "type": "response",
+        "request_seq": 1,
+        "success": false,
+        "command": "evaluate",
+        "body": { "error": "boom" },
+    });
+    match round_trip(expected).message {
+        MessageKind::Response(response) => {
+            let error = response.result.unwrap_err();
+            assert_eq!(error.error, None);
+            assert_eq!(error.body, Some(json!({ "error": "boom" })));
+        }
+        other => panic!("expected a response, got {:?}", other),
+    }
+
+    let mut expected = json!({
+        "seq": 2,
+        "type": "response",
+        "request_seq": 1,
+        "success": false,
+        "command": "evaluate",
+    });
+    for body in [
+        json!({ "foo": 1 }),
+        json!({ "error": { "id": 1, "format": "failed" }, "extra": 1 }),
+        json!({ "error": null }),
+        json!("boom"),
+    ] {
+        expected["body"] = body.clone();
+        match round_trip(expected.clone()).message {
+            MessageKind::Response(response) => {
+                let error = response.result.unwrap_err();
+                assert_eq!(error.error, None);
+                assert_eq!(error.body, Some(body));
+            }
+            other => panic!("expected a response, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn events() {
+    let mut indices = BTreeSet::new();
+    for (event, body) in EVENTS {
+        let mut expected = json!({ "seq": 3, "type": "event", "event": event });
+        if let Some(body) = parse(*body) {
+            expected["body"] = body;
+        }
+        match round_trip(expected).message {
+            MessageKind::Event(event) => {
+                indices.insert(event_variant(event.body.as_ref().unwrap()));
+            }
+            other => panic!("expected an event, got {:?}", other),
+        }
+    }
+    assert_covers_all(indices, EVENTS.len());
+}
+
+#[test]
+fn unknown_messages() {
+    round_trip(json!({
+        "seq": 1,
+        "type": "request",
+        "command": "customRequest",
+        "arguments": { "a": [1, 2] },
+    }));
+    round_trip(json!({
+        "seq": 2,
+        "type": "response",
+        "request_seq": 1,
+        "success": true,
+        "command": "customRequest",
+        "body": {},
+    }));
+    round_trip(json!({ "seq": 3, "type": "event", "event": "customEvent" }));
+}
+
+#[test]
+fn known_messages_with_invalid_payloads() {
+    for invalid in [
+        json!({ "seq": 1, "type": "request", "command": "launch", "arguments": [] }),
+        json!({ "seq": 1, "type": "request", "command": "evaluate", "arguments": {} }),
+        json!({
+            "seq": 2,
+            "type": "response",
+            "request_seq": 1,
+            "success": true,
+            "command": "threads",
+            "body": { "threads": 1 },
+        }),
+        json!({ "seq": 3, "type": "event", "event": "stopped", "body": { "reason": 1 } }),
+    ] {
+        let parsed = serde_json::from_value::<ProtocolMessage>(invalid.clone());
+        assert!(parsed.is_err(), "{} parsed as {:?}", invalid, parsed);
+    }
+}
+
+#[test]
+fn restart_nests_the_configuration() {
+    let request: RequestCommand = serde_json::from_value(json!({
+        "command": "restart",
+        "arguments": { "arguments": { "noDebug": true, "program": "main.clar" } },
+    }))
+    .unwrap();
+    let mut configuration = LaunchRequestArguments::default().no_debug(true);
+    configuration
+        .additional_attributes
+        .insert("program".to_string(), json!("main.clar"));
+    assert_eq!(
+        request,
+        RequestCommand::Restart(RestartArguments::default().arguments(configuration))
+    );
+
+    // The configuration is not flattened into the restart arguments.
+    let request: RequestCommand = serde_json::from_value(json!({
+        "command": "restart",
+        "arguments": { "noDebug": true },
+    }))
+    .unwrap();
+    assert_eq!(
+        request,
+        RequestCommand::Restart(RestartArguments::default())
+    );
+}
+
+#[test]
+fn requests_pair_with_their_response_bodies() {
+    for (command, arguments) in REQUESTS {
+        let mut request = json!({ "command": command });
+        if let Some(arguments) = parse(*arguments) {
+            request["arguments"] = arguments;
+        }
+        let (_, body) = RESPONSES
+            .iter()
+            .find(|(name, _)| name == command)
+            .unwrap_or_else(|| panic!("no response fixture for {}", command));
+        let mut response = json!({ "command": command });
+        if let Some(body) = parse(*body) {
+            response["body"] = body;
+        }
+        request_pairs(
+            serde_json::from_value(request).unwrap(),
+            serde_json::from_value(response).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn identifiers() {
+    let frame: StackFrame = serde_json::from_value(json!({
+        "id": 7,
+        "name": "main",
+        "line": 1,
+        "column": 1,
+        "moduleId": "m1",
+        "instructionPointerReference": "0x10",
+    }))
+    .unwrap();
+    assert_eq!(frame.id, FrameId(7));
+    assert_eq!(
+        frame.module_id,
+        Some(ModuleId(NumberOrString::String("m1".to_string())))
+    );
+    assert_eq!(frame.instruction_pointer_reference, Some("0x10".into()));
+
+    let arguments = ScopesArguments { frame_id: frame.id };
+    assert_eq!(
+        serde_json::to_value(&arguments).unwrap(),
+        json!({ "frameId": 7 })
+    );
+    let arguments = VariablesArguments {
+        variables_reference: VariablesReference::new(3).unwrap(),
+        filter: None,
+        start: None,
+        count: None,
+        format: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&arguments).unwrap(),
+        json!({ "variablesReference": 3 })
+    );
+    assert_eq!(ThreadId(1).to_string(), "1");
+}
+
+#[test]
+fn numbers() {
+    // Values from one message fit into the next without casts.
+    let frame: StackFrame = serde_json::from_value(json!({
+        "id": 1,
+        "name": "main",
+        "line": 3_000_000_000u64,
+        "column": 0,
+    }))
+    .unwrap();
+    let arguments = GotoTargetsArguments {
+        source: serde_json::from_value(json!({})).unwrap(),
+        line: frame.line,
+        column: Some(frame.column),
+    };
+    assert_eq!(arguments.line, 3_000_000_000);
+
+    let progress: ProgressUpdateEvent =
+        serde_json::from_value(json!({ "progressId": "p", "percentage": 12.5 })).unwrap();
+    assert_eq!(progress.percentage, Some(12.5));
+
+    // References must not exceed 2^31-1.
+    let err = serde_json::from_value::<Scope>(json!({
+        "name": "Locals",
+        "variablesReference": 2_147_483_648u64,
+        "expensive": false,
+    }))
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("VariablesReference 2147483648 is not between 0 and 2^31-1"));
+    assert!(SourceReference::new(-1).is_err());
+    assert_eq!(
+        SourceReference::new(SourceReference::MAX).map(i64::from),
+        Ok(2_147_483_647)
+    );
+
+    // So must child counts and process IDs.
+    let err = serde_json::from_value::<Scope>(json!({
+        "name": "Locals",
+        "variablesReference": 1,
+        "namedVariables": 2_147_483_648u64,
+        "expensive": false,
+    }))
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("VariableCount 2147483648 is not between 0 and 2^31-1"));
+    let response: RunInTerminalResponse =
+        serde_json::from_value(json!({ "processId": 42, "shellProcessId": 7 })).unwrap();
+    assert_eq!(response.process_id, ProcessId::new(42).ok());
+    assert!(serde_json::from_value::<RunInTerminalResponse>(json!({ "processId": -1 })).is_err());
+}
+
+fn request_pairs(request: RequestCommand, body: ResponseBody) {
+    match request {
+        RequestCommand::Cancel(arguments) => assert_pairs(arguments, body),
+        RequestCommand::RunInTerminal(arguments) => assert_pairs(arguments, body),
+        RequestCommand::StartDebugging(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Initialize(arguments) => assert_pairs(arguments, body),
+        RequestCommand::ConfigurationDone => {
+            assert_pairs(ConfigurationDoneArguments::default(), body)
+        }
+        RequestCommand::Launch(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Attach(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Restart(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Disconnect(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Terminate(arguments) => assert_pairs(arguments, body),
+        RequestCommand::BreakpointLocations(arguments) => assert_pairs(arguments, body),
+        RequestCommand::SetBreakpoints(arguments) => assert_pairs(arguments, body),
+        RequestCommand::SetFunctionBreakpoints(arguments) => assert_pairs(arguments, body),
+        RequestCommand::SetExceptionBreakpoints(arguments) => assert_pairs(arguments, body),
+        RequestCommand::DataBreakpointInfo(arguments) => assert_pairs(arguments, body),
+        RequestCommand::SetDataBreakpoints(arguments) => assert_pairs(arguments, body),
+        RequestCommand::SetInstructionBreakpoints(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Continue(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Next(arguments) => assert_pairs(arguments, body),
+        RequestCommand::StepIn(arguments) => assert_pairs(arguments, body),
+        RequestCommand::StepOut(arguments) => assert_pairs(arguments, body),
+        RequestCommand::StepBack(arguments) => assert_pairs(arguments, body),
+        RequestCommand::ReverseContinue(arguments) => assert_pairs(arguments, body),
+        RequestCommand::RestartFrame(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Goto(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Pause(arguments) => assert_pairs(arguments, body),
+        RequestCommand::StackTrace(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Scopes(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Variables(arguments) => assert_pairs(arguments, body),
+        RequestCommand::SetVariable(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Source(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Threads => assert_pairs(ThreadsArguments::default(), body),
+        RequestCommand::TerminateThreads(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Modules(arguments) => assert_pairs(arguments, body),
+        RequestCommand::LoadedSources => assert_pairs(LoadedSourcesArguments::default(), body),
+        RequestCommand::Evaluate(arguments) => assert_pairs(arguments, body),
+        RequestCommand::SetExpression(arguments) => assert_pairs(arguments, body),
+        RequestCommand::StepInTargets(arguments) => assert_pairs(arguments, body),
+        RequestCommand::GotoTargets(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Completions(arguments) => assert_pairs(arguments, body),
+        RequestCommand::ExceptionInfo(arguments) => assert_pairs(arguments, body),
+        RequestCommand::ReadMemory(arguments) => assert_pairs(arguments, body),
+        RequestCommand::WriteMemory(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Disassemble(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Locations(arguments) => assert_pairs(arguments, body),
+        RequestCommand::Unknown { command, .. } => panic!("unknown command {}", command),
+    }
+}
+
+fn assert_pairs<R>(arguments: R, body: ResponseBody)
+where
+    R: Request,
+    R::Response: Debug + PartialEq,
+{
+    assert_eq!(arguments.into().command(), R::COMMAND);
+    assert_eq!(body.command(), R::COMMAND);
+    let response = R::from_response_body(body.clone())
+        .unwrap_or_else(|_| panic!("{} does not accept its response body", R::COMMAND));
+    assert_eq!(R::into_response_body(response), body);
+    let unknown = ResponseBody::Unknown {
+        command: R::COMMAND.to_string(),
+        body: None,
+    };
+    assert_eq!(
+        R::from_response_body(unknown.clone()),
+        Err(Box::new(unknown))
+    );
+}
+
+// The variant indices below follow the declaration order of each enum, so a
+// new variant without a fixture fails to compile or fails the coverage check.
+
+fn request_variant(value: &RequestCommand) -> usize {
+    match value {
+        RequestCommand::Cancel(_) => 0,
+        RequestCommand::Initialize(_) => 1,
+        RequestCommand::ConfigurationDone => 2,
+        RequestCommand::Launch(_) => 3,
+        RequestCommand::Attach(_) => 4,
+        RequestCommand::Restart(_) => 5,
+        RequestCommand::Disconnect(_) => 6,
+        RequestCommand::Terminate(_) => 7,
+        RequestCommand::BreakpointLocations(_) => 8,
+        RequestCommand::SetBreakpoints(_) => 9,
+        RequestCommand::SetFunctionBreakpoints(_) => 10,
+        RequestCommand::SetExceptionBreakpoints(_) => 11,
+        RequestCommand::DataBreakpointInfo(_) => 12,
+        RequestCommand::SetDataBreakpoints(_) => 13,
+        RequestCommand::SetInstructionBreakpoints(_) => 14,
+        RequestCommand::Continue(_) => 15,
+        RequestCommand::Next(_) => 16,
+        RequestCommand::StepIn(_) => 17,
+        RequestCommand::StepOut(_) => 18,
+        RequestCommand::StepBack(_) => 19,
+        RequestCommand::ReverseContinue(_) => 20,
+        RequestCommand::RestartFrame(_) => 21,
+        RequestCommand::Goto(_) => 22,
+        RequestCommand::Pause(_) => 23,
+        RequestCommand::StackTrace(_) => 24,
+        RequestCommand::Scopes(_) => 25,
+        RequestCommand::Variables(_) => 26,
+        RequestCommand::SetVariable(_) => 27,
+        RequestCommand::Source(_) => 28,
+        RequestCommand::Threads => 29,
+        RequestCommand::TerminateThreads(_) => 30,
+        RequestCommand::Modules(_) => 31,
+        RequestCommand::LoadedSources => 32,
+        RequestCommand::Evaluate(_) => 33,
+        RequestCommand::SetExpression(_) => 34,
+        RequestCommand::StepInTargets(_) => 35,
+        RequestCommand::GotoTargets(_) => 36,
+        RequestCommand::Completions(_) => 37,
+        RequestCommand::ExceptionInfo(_) => 38,
+        RequestCommand::ReadMemory(_) => 39,
+        RequestCommand::WriteMemory(_) => 40,
+        RequestCommand::Disassemble(_) => 41,
+        RequestCommand::Locations(_) => 42,
+        RequestCommand::RunInTerminal(_) => 43,
+        RequestCommand::StartDebugging(_) => 44,
+        RequestCommand::Unknown { .. } => panic!("unrecognized fixture: {:?}", value),
+    }
+}
+
+fn response_variant(value: &ResponseBody) -> usize {
+    match value {
+        ResponseBody::Cancel => 0,
+        ResponseBody::Initialize(_) => 1,
+        ResponseBody::ConfigurationDone => 2,
+        ResponseBody::Launch => 3,
+        ResponseBody::Attach => 4,
+        ResponseBody::Restart => 5,
+        ResponseBody::Disconnect => 6,
+        ResponseBody::Terminate => 7,
+        ResponseBody::BreakpointLocations(_) => 8,
+        ResponseBody::SetBreakpoints(_) => 9,
+        ResponseBody::SetFunctionBreakpoints(_) => 10,
+        ResponseBody::SetExceptionBreakpoints(_) => 11,
+        ResponseBody::DataBreakpointInfo(_) => 12,
+        ResponseBody::SetDataBreakpoints(_) => 13,
+        ResponseBody::SetInstructionBreakpoints(_) => 14,
+        ResponseBody::Continue(_) => 15,
+        ResponseBody::Next => 16,
+        ResponseBody::StepIn => 17,
+        ResponseBody::StepOut => 18,
+        ResponseBody::StepBack => 19,
+        ResponseBody::ReverseContinue => 20,
+        ResponseBody::RestartFrame => 21,
+        ResponseBody::Goto => 22,
+        ResponseBody::Pause => 23,
+        ResponseBody::StackTrace(_) => 24,
+        ResponseBody::Scopes(_) => 25,
+        ResponseBody::Variables(_) => 26,
+        ResponseBody::SetVariable(_) => 27,
+        ResponseBody::Source(_) => 28,
+        ResponseBody::Threads(_) => 29,
+        ResponseBody::TerminateThreads => 30,
+        ResponseBody::Modules(_) => 31,
+        ResponseBody::LoadedSources(_) => 32,
+        ResponseBody::Evaluate(_) => 33,
+        ResponseBody::SetExpression(_) => 34,
+        ResponseBody::StepInTargets(_) => 35,
+        ResponseBody::GotoTargets(_) => 36,
+        ResponseBody::Completions(_) => 37,
+        ResponseBody::ExceptionInfo(_) => 38,
+        ResponseBody::ReadMemory(_) => 39,
+        ResponseBody::WriteMemory(_) => 40,
+        ResponseBody::Disassemble(_) => 41,
+        ResponseBody::Locations(_) => 42,
+        ResponseBody::RunInTerminal(_) => 43,
+        ResponseBody::StartDebugging => 44,
+        ResponseBody::Unknown { .. } => panic!("unrecognized fixture: {:?}", value),
+    }
+}
+
+fn event_variant(value: &EventBody) -> usize {
+    match value {
+        EventBody::Initialized => 0,
+        EventBody::Stopped(_) => 1,
+        EventBody::Continued(_) => 2,
+        EventBody::Exited(_) => 3,
+        EventBody::Terminated(_) => 4,
+        EventBody::Thread(_) => 5,
+        EventBody::Output(_) => 6,
+        EventBody::Breakpoint(_) => 7,
+        EventBody::Module(_) => 8,
+        EventBody::LoadedSource(_) => 9,
+        EventBody::Process(_) => 10,
+        EventBody::Capabilities(_) => 11,
+        EventBody::ProgressStart(_) => 12,
+        EventBody::ProgressUpdate(_) => 13,
+        EventBody::ProgressEnd(_) => 14,
+        EventBody::Invalidated(_) => 15,
+        EventBody::Memory(_) => 16,
+        EventBody::Unknown { .. } => panic!("unrecognized fixture: {:?}", value),
+    }
+}
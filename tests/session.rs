@@ -0,0 +1,193 @@
+use debug_types::events::EventBody;
+use debug_types::requests::*;
+use debug_types::responses::Response;
+use debug_types::session::{Phase, Session, Violation};
+use debug_types::ProtocolMessage;
+use serde_json::json;
+
+fn request(command: &str) -> RequestCommand {
+    let arguments = match command {
+        "initialize" => json!({ "adapterID": "test" }),
+        "setBreakpoints" => json!({ "source": {} }),
+        "stackTrace" | "next" => json!({ "threadId": 1 }),
+        "evaluate" => json!({ "expression": "1 + 1" }),
+        _ => json!({}),
+    };
+    let request: RequestCommand =
+        serde_json::from_value(json!({ "command": command, "arguments": arguments })).unwrap();
+    assert!(!matches!(request, RequestCommand::Unknown { .. }));
+    request
+}
+
+fn response(command: &str, success: bool) -> Response {
+    serde_json::from_value(json!({
+        "request_seq": 1,
+        "success": success,
+        "command": command,
+        "body": if command == "initialize" { json!({}) } else { json!(null) },
+    }))
+    .unwrap()
+}
+
+fn event(event: &str) -> EventBody {
+    let body = match event {
+        "exited" => json!({ "exitCode": 0 }),
+        "stopped" => json!({ "reason": "pause" }),
+        _ => json!({}),
+    };
+    let event: EventBody = serde_json::from_value(json!({ "event": event, "body": body })).unwrap();
+    assert!(!matches!(event, EventBody::Unknown { .. }));
+    event
+}
+
+fn initialized() -> Session {
+    let mut session = Session::new();
+    session.request(&request("initialize")).unwrap();
+    session.response(&response("initialize", true));
+    session.event(&EventBody::Initialized).unwrap();
+    session
+}
+
+#[test]
+fn follows_the_lifecycle() {
+    let mut session = Session::new();
+    assert_eq!(session.phase(), Phase::Uninitialized);
+
+    session.request(&request("initialize")).unwrap();
+    assert_eq!(session.phase(), Phase::Initializing);
+    session.response(&response("initialize", true));
+    assert_eq!(session.phase(), Phase::Initialized);
+    assert!(session.capabilities().is_some());
+
+    session.request(&request("launch")).unwrap();
+    session.event(&EventBody::Initialized).unwrap();
+    assert_eq!(session.phase(), Phase::Configuring);
+    session.request(&request("setBreakpoints")).unwrap();
+    session.request(&RequestCommand::ConfigurationDone).unwrap();
+    assert_eq!(session.phase(), Phase::Configured);
+    session.response(&response("launch", true));
+    assert!(session.is_launched());
+
+    session.request(&RequestCommand::Threads).unwrap();
+    session.event(&event("terminated")).unwrap();
+    assert_eq!(session.phase(), Phase::Terminated);
+    session.event(&event("exited")).unwrap();
+
+    session.request(&request("disconnect")).unwrap();
+    assert_eq!(session.phase(), Phase::Disconnecting);
+    session.response(&response("disconnect", true));
+    assert_eq!(session.phase(), Phase::Disconnected);
+}
+
+#[test]
+fn rejects_requests_out_of_order() {
+    let mut session = Session::new();
+    assert_eq!(
+        session.request(&request("stackTrace")),
+        Err(Violation::NotInitialized {
+            command: "stackTrace".to_string()
+        })
+    );
+    // A rejected request leaves the session as it was.
+    assert_eq!(session.phase(), Phase::Uninitialized);
+
+    session.request(&request("initialize")).unwrap();
+    assert_eq!(
+        session.request(&request("initialize")),
+        Err(Violation::AlreadyInitialized)
+    );
+    session.response(&response("initialize", true));
+    assert_eq!(
+        session.request(&request("setBreakpoints")),
+        Err(Violation::NotConfigurable {
+            command: "setBreakpoints".to_string()
+        })
+    );
+    assert_eq!(
+        session.request(&request("stackTrace")),
+        Err(Violation::NotLaunched {
+            command: "stackTrace".to_string()
+        })
+    );
+    session.request(&request("evaluate")).unwrap();
+
+    session.event(&EventBody::Initialized).unwrap();
+    session.request(&RequestCommand::ConfigurationDone).unwrap();
+    assert_eq!(
+        session.request(&RequestCommand::ConfigurationDone),
+        Err(Violation::AlreadyConfigured)
+    );
+    session.request(&request("attach")).unwrap();
+    assert_eq!(
+        session.request(&request("launch")),
+        Err(Violation::AlreadyLaunched {
+            command: "launch".to_string()
+        })
+    );
+
+    session.event(&event("terminated")).unwrap();
+    assert_eq!(
+        session.request(&request("next")),
+        Err(Violation::Terminated {
+            command: "next".to_string()
+        })
+    );
+
+    session.request(&request("disconnect")).unwrap();
+    assert_eq!(
+        session.request(&request("evaluate")),
+        Err(Violation::Disconnected {
+            message: "evaluate".to_string()
+        })
+    );
+}
+
+#[test]
+fn failed_requests_may_be_retried() {
+    let mut session = Session::new();
+    session.request(&request("initialize")).unwrap();
+    session.response(&response("initialize", false));
+    assert_eq!(session.phase(), Phase::Uninitialized);
+
+    let mut session = initialized();
+    session.request(&request("launch")).unwrap();
+    session.response(&response("launch", false));
+    assert!(!session.is_launched());
+    session.request(&request("launch")).unwrap();
+}
+
+#[test]
+fn flags_unexpected_events() {
+    let mut session = Session::new();
+    assert_eq!(
+        session.event(&EventBody::Initialized),
+        Err(Violation::UnexpectedEvent {
+            event: "initialized".to_string(),
+            phase: Phase::Uninitialized,
+        })
+    );
+
+    let mut session = initialized();
+    assert!(session.event(&EventBody::Initialized).is_err());
+    session.event(&event("terminated")).unwrap();
+    let err = session.event(&event("stopped")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected 'stopped' event while terminated"
+    );
+}
+
+#[test]
+fn observes_messages() {
+    let mut session = Session::new();
+    let messages: Vec<ProtocolMessage> = serde_json::from_value(json!([
+        { "seq": 1, "type": "request", "command": "initialize", "arguments": { "adapterID": "test" } },
+        { "seq": 1, "type": "response", "request_seq": 1, "success": true, "command": "initialize", "body": {} },
+        { "seq": 2, "type": "event", "event": "initialized" },
+    ]))
+    .unwrap();
+    for message in &messages {
+        session.observe(message).unwrap();
+    }
+    assert_eq!(session.phase(), Phase::Configuring);
+}
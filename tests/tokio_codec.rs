@@ -0,0 +1,86 @@
+#![cfg(feature = "tokio")]
+
+use debug_types::codec::tokio::{split, MessageCodec};
+use debug_types::codec::CodecError;
+use debug_types::events::{Event, EventBody};
+use debug_types::requests::RequestCommand;
+use debug_types::{MessageKind, ProtocolMessage};
+use futures::{SinkExt, StreamExt};
+use tokio::io::AsyncWriteExt;
+use tokio_util::codec::FramedRead;
+
+fn threads_request(seq: i64) -> ProtocolMessage {
+    ProtocolMessage {
+        seq,
+        message: MessageKind::Request(RequestCommand::Threads),
+    }
+}
+
+#[tokio::test]
+async fn round_trip_over_duplex() {
+    let (client, adapter) = tokio::io::duplex(4096);
+    let (mut client_stream, mut client_sink) = split(client);
+    let (mut adapter_stream, mut adapter_sink) = split(adapter);
+
+    client_sink.send(threads_request(1)).await.unwrap();
+    client_sink.send(threads_request(2)).await.unwrap();
+    assert_eq!(
+        adapter_stream.next().await.unwrap().unwrap(),
+        threads_request(1)
+    );
+    assert_eq!(
+        adapter_stream.next().await.unwrap().unwrap(),
+        threads_request(2)
+    );
+
+    let initialized = ProtocolMessage {
+        seq: 1,
+        message: MessageKind::Event(Event {
+            body: Some(EventBody::Initialized),
+        }),
+    };
+    adapter_sink.send(&initialized).await.unwrap();
+    assert_eq!(client_stream.next().await.unwrap().unwrap(), initialized);
+
+    drop(adapter_sink);
+    drop(adapter_stream);
+    assert!(client_stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn decodes_partial_frames() {
+    let (mut writer, reader) = tokio::io::duplex(1024);
+    let mut stream = FramedRead::new(reader, MessageCodec::new());
+    let body = br#"{"seq":7,"type":"request","command":"threads"}"#;
+    let frame = format!(
+        "Content-Type: application/vscode-jsonrpc\r\nContent-Length: {}\r\n\r\n{}",
+        body.len(),
+        std::str::from_utf8(body).unwrap()
+    );
+    let task = tokio::spawn(async move {
+        for chunk in frame.as_bytes().chunks(5) {
+            writer.write_all(chunk).await.unwrap();
+            tokio::task::yield_now().await;
+        }
+    });
+    assert_eq!(stream.next().await.unwrap().unwrap(), threads_request(7));
+    task.await.unwrap();
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn rejects_oversized_messages() {
+    let (mut writer, reader) = tokio::io::duplex(1024);
+    let mut stream = FramedRead::new(reader, MessageCodec::new().with_max_message_size(10));
+    writer
+        .write_all(b"Content-Length: 11\r\n\r\n")
+        .await
+        .unwrap();
+    match stream.next().await.unwrap() {
+        Err(CodecError::MessageTooLarge {
+            length: 11,
+            max: 10,
+        }) => {}
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
@@ -0,0 +1,1369 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::json::Json;
+
+/**
+ * The modules of the crate that hold generated definitions. Each one is
+ * written to 'src/<name>/generated.rs' and re-exported by its parent.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Module {
+    Types,
+    Requests,
+    Responses,
+    Events,
+}
+
+impl Module {
+    pub const ALL: [Module; 4] = [
+        Module::Types,
+        Module::Requests,
+        Module::Responses,
+        Module::Events,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Module::Types => "types",
+            Module::Requests => "requests",
+            Module::Responses => "responses",
+            Module::Events => "events",
+        }
+    }
+}
+
+/**
+ * The base message definitions. Their wire format is written by hand in
+ * 'lib.rs', 'responses.rs' and 'events.rs'.
+ */
+const BASE_MESSAGES: &[&str] = &[
+    "ProtocolMessage",
+    "Request",
+    "Response",
+    "ErrorResponse",
+    "Event",
+];
+
+/**
+ * Definitions written by hand in the parent module, because they carry
+ * implementation specific attributes that the schema does not describe, or
+ * are not in the schema at all, like the identifier types.
+ */
+const HAND_WRITTEN: &[(&str, Module)] = &[
+    ("LaunchRequestArguments", Module::Requests),
+    ("AttachRequestArguments", Module::Requests),
+    ("ThreadId", Module::Types),
+    ("FrameId", Module::Types),
+    ("VariablesReference", Module::Types),
+    ("SourceReference", Module::Types),
+    ("VariableCount", Module::Types),
+    ("ProcessId", Module::Types),
+    ("BreakpointId", Module::Types),
+    ("ModuleId", Module::Types),
+    ("ProgressId", Module::Types),
+    ("MemoryReference", Module::Types),
+    ("Address", Module::Types),
+];
+
+/**
+ * Names of the enums declared inline on a property, as (definition, property,
+ * enum). Properties that share a name share one enum with the union of their
+ * values. Other inline enums are named after their definition and property.
+ */
+const ENUM_NAMES: &[(&str, &str, &str)] = &[
+    ("InitializeRequestArguments", "pathFormat", "PathFormat"),
+    ("RunInTerminalRequestArguments", "kind", "TerminalKind"),
+    (
+        "StartDebuggingRequestArguments",
+        "request",
+        "StartDebuggingRequestKind",
+    ),
+    ("VariablesArguments", "filter", "VariableFilter"),
+    ("EvaluateArguments", "context", "EvalContext"),
+    ("StoppedEvent", "reason", "StoppedReason"),
+    ("ThreadEvent", "reason", "Reason"),
+    ("OutputEvent", "category", "Category"),
+    ("OutputEvent", "group", "Group"),
+    ("BreakpointEvent", "reason", "Reason"),
+    ("ModuleEvent", "reason", "Reason"),
+    ("LoadedSourceEvent", "reason", "Reason"),
+    ("ProcessEvent", "startMethod", "StartMethod"),
+    ("ColumnDescriptor", "type", "ColumnType"),
+    ("Source", "presentationHint", "PresentationHint"),
+    ("StackFrame", "presentationHint", "PresentationHint"),
+    ("Scope", "presentationHint", "PresentationHint"),
+    ("VariablePresentationHint", "kind", "VariableKind"),
+    (
+        "VariablePresentationHint",
+        "attributes",
+        "VariableAttribute",
+    ),
+    ("VariablePresentationHint", "visibility", "Visibility"),
+    ("Breakpoint", "reason", "BreakpointReason"),
+    (
+        "DisassembledInstruction",
+        "presentationHint",
+        "InstructionPresentationHint",
+    ),
+];
+
+/**
+ * Enums that get an `Other` variant for unknown values although the schema
+ * lists all of their values, because newer versions of the protocol may add
+ * more.
+ */
+const OPEN_ENUMS: &[&str] = &[
+    "ColumnType",
+    "CompletionItemType",
+    "Group",
+    "StartMethod",
+    "BreakpointReason",
+];
+
+/**
+ * Field names for properties whose name is a Rust keyword, as (definition,
+ * property, field).
+ */
+const FIELD_NAMES: &[(&str, &str, &str)] = &[
+    ("ColumnDescriptor", "type", "column_type"),
+    ("Variable", "type", "var_type"),
+    ("CompletionItem", "type", "item_type"),
+    ("EvaluateResponse", "type", "result_type"),
+    ("SetVariableResponse", "type", "variable_type"),
+    ("SetExpressionResponse", "type", "value_type"),
+];
+
+/**
+ * Field types that differ from the default mapping of the schema type, as
+ * (definition, property, type). Optional properties are still wrapped in an
+ * `Option`.
+ */
+const FIELD_TYPES: &[(&str, &str, &str)] = &[
+    ("Thread", "id", "ThreadId"),
+    ("TerminateThreadsArguments", "threadIds", "Vec<ThreadId>"),
+    ("StackFrame", "id", "FrameId"),
+    ("InvalidatedEvent", "stackFrameId", "FrameId"),
+    ("Breakpoint", "id", "BreakpointId"),
+    ("StoppedEvent", "hitBreakpointIds", "Vec<BreakpointId>"),
+    ("Module", "id", "ModuleId"),
+    ("RestartArguments", "arguments", "LaunchRequestArguments"),
+    ("ReadMemoryResponse", "address", "Address"),
+    ("DisassembledInstruction", "address", "Address"),
+];
+
+/**
+ * Field types for properties that hold an identifier, or a count the protocol
+ * limits to 2^31-1, as (property, type). They apply to every definition,
+ * unless `FIELD_TYPES` says otherwise.
+ */
+const ID_TYPES: &[(&str, &str)] = &[
+    ("threadId", "ThreadId"),
+    ("frameId", "FrameId"),
+    ("variablesReference", "VariablesReference"),
+    ("sourceReference", "SourceReference"),
+    ("moduleId", "ModuleId"),
+    ("progressId", "ProgressId"),
+    ("memoryReference", "MemoryReference"),
+    ("instructionPointerReference", "MemoryReference"),
+    ("instructionReference", "MemoryReference"),
+    ("namedVariables", "VariableCount"),
+    ("indexedVariables", "VariableCount"),
+    ("processId", "ProcessId"),
+    ("shellProcessId", "ProcessId"),
+    ("systemProcessId", "ProcessId"),
+];
+
+const HEADER: &str = "\
+// The definitions in this module are maintained by `cargo xtask codegen`, which
+// regenerates them from the Debug Adapter Protocol JSON schema. Do not edit
+// them by hand.
+";
+
+/**
+ * Generates the source of each module from the Debug Adapter Protocol JSON
+ * schema. The source is not formatted.
+ */
+pub fn generate(schema: &Json) -> Result<Vec<(Module, String)>, String> {
+    let definitions = schema
+        .get("definitions")
+        .and_then(Json::as_object)
+        .ok_or("the schema has no definitions")?;
+    let mut generator = Generator::new(definitions);
+    generator.run()?;
+    Ok(Module::ALL
+        .iter()
+        .map(|&module| (module, generator.module_source(module)))
+        .collect())
+}
+
+struct Struct {
+    module: Module,
+    name: String,
+    doc: Option<String>,
+    shape: Shape,
+    fields: Vec<Field>,
+}
+
+#[derive(PartialEq)]
+enum Shape {
+    Fields,
+    Marker,
+    Transparent,
+}
+
+struct Field {
+    name: String,
+    wire: String,
+    doc: Option<String>,
+    ty: String,
+    skip_if_none: bool,
+}
+
+struct Enum {
+    module: Module,
+    name: String,
+    doc: Option<String>,
+    values: Vec<String>,
+    open: bool,
+}
+
+enum Item {
+    Struct(Struct),
+    Enum(Enum),
+}
+
+impl Item {
+    fn module(&self) -> Module {
+        match self {
+            Item::Struct(s) => s.module,
+            Item::Enum(e) => e.module,
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Item::Struct(s) => &s.name,
+            Item::Enum(e) => &e.name,
+        }
+    }
+}
+
+/**
+ * A variant of 'RequestCommand', 'ResponseBody' or 'EventBody'.
+ */
+struct Variant {
+    name: String,
+    wire: String,
+    payload: Option<String>,
+}
+
+struct RequestInfo {
+    definition: String,
+    variant: Variant,
+    arguments: String,
+}
+
+struct Generator<'a> {
+    definitions: &'a [(String, Json)],
+    items: Vec<Item>,
+    enums: HashMap<String, usize>,
+    arguments: HashSet<String>,
+    requests: Vec<RequestInfo>,
+    responses: Vec<Variant>,
+    events: Vec<Variant>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(definitions: &'a [(String, Json)]) -> Self {
+        Generator {
+            definitions,
+            items: Vec::new(),
+            enums: HashMap::new(),
+            arguments: HashSet::new(),
+            requests: Vec::new(),
+            responses: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    fn definition(&self, name: &str) -> Option<&'a Json> {
+        self.definitions
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, d)| d)
+    }
+
+    fn run(&mut self) -> Result<(), String> {
+        // Argument types live in 'requests', so find them before generating.
+        for (_, definition) in self.definitions {
+            if let Some(("Request", message)) = message_of(definition) {
+                if let Some(arguments) = property(message, "arguments").and_then(ref_name) {
+                    self.arguments.insert(arguments.to_string());
+                }
+            }
+        }
+
+        let mut response_bodies = HashMap::new();
+        for (name, definition) in self.definitions {
+            if BASE_MESSAGES.contains(&name.as_str()) {
+                continue;
+            }
+            match message_of(definition) {
+                Some(("Request", message)) => self.request(name, message)?,
+                Some(("Response", message)) => {
+                    let body = self.response(name, message)?;
+                    response_bodies.insert(name.clone(), body);
+                }
+                Some(("Event", message)) => self.event(name, message)?,
+                Some((base, extension)) => {
+                    let base = self
+                        .definition(base)
+                        .ok_or_else(|| format!("{}: unknown base definition {}", name, base))?;
+                    self.object(name, &extend(base, extension))?
+                }
+                None if is_enum(definition) => self.named_enum(name, definition),
+                None => {
+                    if !HAND_WRITTEN.iter().any(|(n, _)| n == name) {
+                        self.object(name, definition)?
+                    }
+                }
+            }
+        }
+
+        // Responses follow the order of their requests, so that both enums line
+        // up.
+        for request in &self.requests {
+            let name = format!("{}Response", request.definition.trim_end_matches("Request"));
+            let payload = response_bodies
+                .remove(&name)
+                .ok_or_else(|| format!("{}: no response definition", request.definition))?;
+            self.responses.push(Variant {
+                name: request.variant.name.clone(),
+                wire: request.variant.wire.clone(),
+                payload,
+            });
+        }
+        if let Some(name) = response_bodies.keys().next() {
+            return Err(format!("{}: no request definition", name));
+        }
+        Ok(())
+    }
+
+    fn request(&mut self, name: &str, message: &Json) -> Result<(), String> {
+        let command =
+            constant(message, "command").ok_or_else(|| format!("{}: no command", name))?;
+        let variant = pascal_case(command);
+        let arguments = property(message, "arguments").and_then(ref_name);
+        let has_fields = arguments
+            .and_then(|arguments| self.definition(arguments))
+            .and_then(|definition| definition.get("properties"))
+            .is_some();
+        let arguments = match arguments {
+            Some(arguments) => arguments.to_string(),
+            None => {
+                // Requests without arguments get a marker type for 'Request'.
+                let arguments = format!("{}Arguments", variant);
+                self.items.push(Item::Struct(Struct {
+                    module: Module::Requests,
+                    name: arguments.clone(),
+                    doc: Some(format!("The '{}' request has no arguments.", command)),
+                    shape: Shape::Marker,
+                    fields: Vec::new(),
+                }));
+                arguments
+            }
+        };
+        self.requests.push(RequestInfo {
+            definition: name.to_string(),
+            variant: Variant {
+                name: variant,
+                wire: command.to_string(),
+                payload: if has_fields {
+                    Some(arguments.clone())
+                } else {
+                    None
+                },
+            },
+            arguments,
+        });
+        Ok(())
+    }
+
+    fn response(&mut self, name: &str, message: &Json) -> Result<Option<String>, String> {
+        let body = match property(message, "body") {
+            Some(body) => body,
+            None => return Ok(None),
+        };
+        if let Some(target) = ref_name(body) {
+            let field = Field {
+                name: snake_case(target),
+                wire: String::new(),
+                doc: description(body),
+                ty: target.to_string(),
+                skip_if_none: false,
+            };
+            self.items.push(Item::Struct(Struct {
+                module: Module::Responses,
+                name: name.to_string(),
+                doc: description(message),
+                shape: Shape::Transparent,
+                fields: vec![field],
+            }));
+            return Ok(Some(name.to_string()));
+        }
+        if body.get("properties").is_none() {
+            return Ok(None);
+        }
+        self.structure(Module::Responses, name, description(message), body)?;
+        Ok(Some(name.to_string()))
+    }
+
+    fn event(&mut self, name: &str, message: &Json) -> Result<(), String> {
+        let event = constant(message, "event").ok_or_else(|| format!("{}: no event", name))?;
+        let payload = match property(message, "body") {
+            Some(body) if body.get("properties").is_some() => {
+                self.structure(Module::Events, name, description(message), body)?;
+                Some(name.to_string())
+            }
+            _ => None,
+        };
+        self.events.push(Variant {
+            name: pascal_case(event),
+            wire: event.to_string(),
+            payload,
+        });
+        Ok(())
+    }
+
+    fn object(&mut self, name: &str, definition: &Json) -> Result<(), String> {
+        let module = if self.arguments.contains(name) {
+            Module::Requests
+        } else {
+            Module::Types
+        };
+        if definition.get("properties").is_none() {
+            self.items.push(Item::Struct(Struct {
+                module,
+                name: name.to_string(),
+                doc: description(definition),
+                shape: Shape::Marker,
+                fields: Vec::new(),
+            }));
+            return Ok(());
+        }
+        self.structure(module, name, description(definition), definition)
+    }
+
+    fn named_enum(&mut self, name: &str, definition: &Json) {
+        let doc = join_doc(description(definition), values_doc(definition));
+        self.add_enum(Module::Types, name, doc, definition);
+    }
+
+    fn add_enum(&mut self, module: Module, name: &str, doc: Option<String>, schema: &Json) {
+        let open = schema.get("_enum").is_some() || OPEN_ENUMS.contains(&name);
+        let values = enum_values(schema);
+        if let Some(&index) = self.enums.get(name) {
+            if let Item::Enum(existing) = &mut self.items[index] {
+                existing.open |= open;
+                for value in values {
+                    if !existing.values.iter().any(|v| v == value) {
+                        existing.values.push(value.to_string());
+                    }
+                }
+            }
+            return;
+        }
+        self.enums.insert(name.to_string(), self.items.len());
+        self.items.push(Item::Enum(Enum {
+            module,
+            name: name.to_string(),
+            doc,
+            values: values.into_iter().map(str::to_string).collect(),
+            open,
+        }));
+    }
+
+    fn structure(
+        &mut self,
+        module: Module,
+        name: &str,
+        doc: Option<String>,
+        schema: &Json,
+    ) -> Result<(), String> {
+        let required = schema
+            .get("required")
+            .map(Json::strings)
+            .unwrap_or_default();
+        let mut fields = Vec::new();
+        for (wire, property) in schema
+            .get("properties")
+            .and_then(Json::as_object)
+            .unwrap_or_default()
+        {
+            let field_name = match lookup(FIELD_NAMES, name, wire) {
+                Some(field_name) => field_name.to_string(),
+                None => snake_case(wire),
+            };
+            if KEYWORDS.contains(&field_name.as_str()) {
+                return Err(format!("{}.{}: add a FIELD_NAMES entry", name, wire));
+            }
+            let id_type = ID_TYPES.iter().find(|(p, _)| p == wire).map(|(_, ty)| *ty);
+            let (ty, nullable) = match lookup(FIELD_TYPES, name, wire).or(id_type) {
+                Some(ty) => (ty.to_string(), false),
+                None => self.field_type(module, name, wire, property)?,
+            };
+            let optional = !required.contains(&wire.as_str());
+            let field_doc = join_doc(description(property), self.inline_values_doc(property));
+            fields.push(Field {
+                name: field_name,
+                wire: wire.clone(),
+                doc: field_doc,
+                ty: if optional || nullable {
+                    format!("Option<{}>", ty)
+                } else {
+                    ty
+                },
+                skip_if_none: optional,
+            });
+        }
+        self.items.push(Item::Struct(Struct {
+            module,
+            name: name.to_string(),
+            doc,
+            shape: Shape::Fields,
+            fields,
+        }));
+        Ok(())
+    }
+
+    /**
+     * The values of an enum declared inline on a property, for its doc comment.
+     */
+    fn inline_values_doc(&self, property: &Json) -> Option<String> {
+        let schema = match property.get("items") {
+            Some(items) => items,
+            None => property,
+        };
+        if is_enum(schema) {
+            values_doc(schema)
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Maps the schema of a property to a Rust type. Returns the type and
+     * whether the property may be null.
+     */
+    fn field_type(
+        &mut self,
+        module: Module,
+        owner: &str,
+        property: &str,
+        schema: &Json,
+    ) -> Result<(String, bool), String> {
+        if let Some(target) = ref_name(schema) {
+            return Ok((target.to_string(), false));
+        }
+        let ty = match schema.get("type") {
+            Some(Json::Array(_)) => {
+                let mut types = schema.get("type").map(Json::strings).unwrap_or_default();
+                types.sort_unstable();
+                return match types.as_slice() {
+                    types if types.len() >= 6 => Ok(("Value".to_string(), false)),
+                    ["integer", "string"] => Ok(("NumberOrString".to_string(), false)),
+                    ["null", other] | [other, "null"] => Ok((primitive(other)?.to_string(), true)),
+                    _ => Err(format!(
+                        "{}.{}: unsupported type {:?}",
+                        owner, property, types
+                    )),
+                };
+            }
+            Some(Json::String(ty)) => ty.as_str(),
+            None => return Ok(("Value".to_string(), false)),
+            Some(_) => return Err(format!("{}.{}: invalid type", owner, property)),
+        };
+        let ty = match ty {
+            "string" if is_enum(schema) => {
+                let name = match lookup(ENUM_NAMES, owner, property) {
+                    Some(name) => name.to_string(),
+                    None => format!("{}{}", owner, pascal_case(property)),
+                };
+                self.add_enum(module, &name, None, schema);
+                name
+            }
+            "array" => {
+                let items = schema
+                    .get("items")
+                    .ok_or_else(|| format!("{}.{}: array without items", owner, property))?;
+                let (item, nullable) = self.field_type(module, owner, property, items)?;
+                if nullable {
+                    format!("Vec<Option<{}>>", item)
+                } else {
+                    format!("Vec<{}>", item)
+                }
+            }
+            "object" => {
+                if schema.get("properties").is_some() {
+                    return Err(format!(
+                        "{}.{}: inline objects are not supported",
+                        owner, property
+                    ));
+                }
+                let value = match schema.get("additionalProperties") {
+                    Some(additional @ Json::Object(members)) if !members.is_empty() => {
+                        let (value, nullable) =
+                            self.field_type(module, owner, property, additional)?;
+                        if nullable {
+                            format!("Option<{}>", value)
+                        } else {
+                            value
+                        }
+                    }
+                    _ => "Value".to_string(),
+                };
+                format!("HashMap<String, {}>", value)
+            }
+            other => primitive(other)?.to_string(),
+        };
+        Ok((ty, false))
+    }
+
+    fn module_source(&self, module: Module) -> String {
+        let non_eq = self.non_eq_types();
+        let mut code = String::new();
+        // The types referred to by the module, to import those defined elsewhere.
+        let mut used: BTreeSet<&str> = BTreeSet::new();
+        let variants: Vec<&Variant> = match module {
+            Module::Types => Vec::new(),
+            Module::Requests => self.requests.iter().map(|r| &r.variant).collect(),
+            Module::Responses => self.responses.iter().collect(),
+            Module::Events => self.events.iter().collect(),
+        };
+        if !variants.is_empty() {
+            used.insert("Value");
+            used.extend(variants.iter().filter_map(|v| v.payload.as_deref()));
+        }
+        match module {
+            Module::Requests => {
+                message_enum(
+                    &mut code,
+                    "RequestCommand",
+                    "command",
+                    "arguments",
+                    &variants,
+                    &non_eq,
+                    "A command not known to this crate. The raw arguments are preserved as is.",
+                );
+                tag_fn(
+                    &mut code,
+                    "RequestCommand",
+                    "command",
+                    "The command to execute.",
+                    &variants,
+                );
+                for (request, response) in self.requests.iter().zip(&self.responses) {
+                    let response = response.payload.as_deref().unwrap_or("()");
+                    used.insert(&request.arguments);
+                    used.insert(response);
+                    let parens = if request.variant.payload.is_some() {
+                        ""
+                    } else {
+                        "()"
+                    };
+                    writeln!(
+                        code,
+                        "impl_request!({} => {}{}, {:?}, {});",
+                        request.arguments,
+                        request.variant.name,
+                        parens,
+                        request.variant.wire,
+                        response
+                    )
+                    .unwrap();
+                }
+                code.push('\n');
+            }
+            Module::Responses => {
+                message_enum(
+                    &mut code,
+                    "ResponseBody",
+                    "command",
+                    "body",
+                    &variants,
+                    &non_eq,
+                    "A response to a command not known to this crate. The raw body is preserved as is.",
+                );
+                tag_fn(
+                    &mut code,
+                    "ResponseBody",
+                    "command",
+                    "The command this is a response to.",
+                    &variants,
+                );
+                from_payloads(&mut code, "ResponseBody", "body", &variants);
+            }
+            Module::Events => {
+                message_enum(
+                    &mut code,
+                    "EventBody",
+                    "event",
+                    "body",
+                    &variants,
+                    &non_eq,
+                    "An event not known to this crate. The raw body is preserved as is.",
+                );
+                tag_fn(
+                    &mut code,
+                    "EventBody",
+                    "event",
+                    "The type of event.",
+                    &variants,
+                );
+                from_payloads(&mut code, "EventBody", "body", &variants);
+            }
+            Module::Types => {}
+        }
+        for item in self.items.iter().filter(|item| item.module() == module) {
+            match item {
+                Item::Struct(s) => {
+                    used.extend(s.fields.iter().flat_map(|f| identifiers(&f.ty)));
+                    write_struct(&mut code, s, &non_eq)
+                }
+                Item::Enum(e) => write_enum(&mut code, e),
+            }
+        }
+
+        let mut source = String::from(HEADER);
+        source.push('\n');
+        source.push_str(&self.imports(module, &used));
+        source.push('\n');
+        source.push_str(code.trim_end());
+        source.push('\n');
+        source
+    }
+
+    /**
+     * The types that cannot derive `Eq`, because they contain floating point
+     * numbers.
+     */
+    fn non_eq_types(&self) -> HashSet<String> {
+        let mut non_eq: HashSet<String> = std::iter::once("f64".to_string()).collect();
+        loop {
+            let before = non_eq.len();
+            for item in &self.items {
+                if let Item::Struct(s) = item {
+                    if s.fields
+                        .iter()
+                        .any(|f| identifiers(&f.ty).any(|t| non_eq.contains(t)))
+                    {
+                        non_eq.insert(s.name.clone());
+                    }
+                }
+            }
+            if non_eq.len() == before {
+                return non_eq;
+            }
+        }
+    }
+
+    fn imports(&self, module: Module, used: &BTreeSet<&str>) -> String {
+        let serde = if module == Module::Types {
+            "use serde::{Deserialize, Serialize};"
+        } else {
+            "use serde::{Deserialize, Deserializer, Serialize};"
+        };
+        let mut external = vec![serde.to_string()];
+        if used.contains("Value") {
+            external.push("use serde_json::Value;".to_string());
+        }
+        if used.contains("HashMap") {
+            external.push("use std::collections::HashMap;".to_string());
+        }
+
+        let mut internal: Vec<(String, BTreeSet<String>)> = Vec::new();
+        let mut import =
+            |path: String, name: &str| match internal.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, names)) => {
+                    names.insert(name.to_string());
+                }
+                None => internal.push((path, std::iter::once(name.to_string()).collect())),
+            };
+        if used.contains("NumberOrString") {
+            import("crate".to_string(), "NumberOrString");
+        }
+        if module != Module::Types {
+            import("crate".to_string(), "parse_content");
+            import("crate".to_string(), "split_message");
+        }
+        let parent = |other: Module| {
+            if other == module {
+                "super".to_string()
+            } else {
+                format!("crate::{}", other.name())
+            }
+        };
+        if module == Module::Requests {
+            import("super".to_string(), "Request");
+            import("crate::responses".to_string(), "ResponseBody");
+        }
+        for &(name, owner) in HAND_WRITTEN {
+            if used.contains(name) {
+                import(parent(owner), name);
+            }
+        }
+        for item in &self.items {
+            if item.module() != module && used.contains(item.name()) {
+                import(format!("crate::{}", item.module().name()), item.name());
+            }
+        }
+        internal.sort();
+
+        let mut imports = external.join("\n");
+        imports.push_str("\n\n");
+        for (path, names) in internal {
+            let names: Vec<String> = names.into_iter().collect();
+            if names.len() == 1 {
+                writeln!(imports, "use {}::{};", path, names[0]).unwrap();
+            } else {
+                writeln!(imports, "use {}::{{{}}};", path, names.join(", ")).unwrap();
+            }
+        }
+        imports
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "box", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "async", "await", "dyn",
+];
+
+fn identifiers(code: &str) -> impl Iterator<Item = &str> {
+    code.split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .filter(|s| !s.is_empty())
+}
+
+/**
+ * Returns the base message and the message specific part of a request,
+ * response or event definition.
+ */
+fn message_of(definition: &Json) -> Option<(&str, &Json)> {
+    let parts = definition.get("allOf")?.as_array()?;
+    match parts {
+        [base, message] => Some((ref_name(base)?, message)),
+        _ => None,
+    }
+}
+
+/**
+ * A definition that extends another one, with the properties of both.
+ */
+fn extend(base: &Json, extension: &Json) -> Json {
+    let members = |key: &str| {
+        [base, extension]
+            .iter()
+            .filter_map(|schema| schema.get(key))
+            .flat_map(|value| match value {
+                Json::Object(members) => members.clone(),
+                _ => Vec::new(),
+            })
+            .collect::<Vec<_>>()
+    };
+    let required = [base, extension]
+        .iter()
+        .filter_map(|schema| schema.get("required"))
+        .flat_map(Json::strings)
+        .map(|name| Json::String(name.to_string()))
+        .collect();
+    let mut schema = vec![
+        ("type".to_string(), Json::String("object".to_string())),
+        (
+            "properties".to_string(),
+            Json::Object(members("properties")),
+        ),
+        ("required".to_string(), Json::Array(required)),
+    ];
+    if let Some(description) = extension.get("description") {
+        schema.push(("description".to_string(), description.clone()));
+    }
+    Json::Object(schema)
+}
+
+fn property<'j>(schema: &'j Json, name: &str) -> Option<&'j Json> {
+    schema.get("properties")?.get(name)
+}
+
+/**
+ * The single allowed value of a property, such as the command of a request.
+ */
+fn constant<'j>(schema: &'j Json, name: &str) -> Option<&'j str> {
+    property(schema, name)?
+        .get("enum")?
+        .as_array()?
+        .first()?
+        .as_str()
+}
+
+fn ref_name(schema: &Json) -> Option<&str> {
+    schema.get("$ref")?.as_str()?.strip_prefix("#/definitions/")
+}
+
+fn is_enum(schema: &Json) -> bool {
+    schema.get("type").and_then(Json::as_str) == Some("string")
+        && (schema.get("enum").is_some() || schema.get("_enum").is_some())
+}
+
+fn enum_values(schema: &Json) -> Vec<&str> {
+    schema
+        .get("enum")
+        .or_else(|| schema.get("_enum"))
+        .map(Json::strings)
+        .unwrap_or_default()
+}
+
+fn primitive(ty: &str) -> Result<&'static str, String> {
+    match ty {
+        "string" => Ok("String"),
+        "boolean" => Ok("bool"),
+        "integer" => Ok("i64"),
+        "number" => Ok("f64"),
+        _ => Err(format!("unsupported type {:?}", ty)),
+    }
+}
+
+fn lookup(
+    table: &[(&str, &str, &'static str)],
+    owner: &str,
+    property: &str,
+) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(o, p, _)| *o == owner && *p == property)
+        .map(|(_, _, value)| *value)
+}
+
+/**
+ * Converts a description from the schema to the style of this crate, which
+ * quotes names with single quotes rather than backticks.
+ */
+fn description(schema: &Json) -> Option<String> {
+    schema
+        .get("description")
+        .and_then(Json::as_str)
+        .map(|description| description.replace('`', "'"))
+}
+
+/**
+ * Lists the values of an enum, with their descriptions if the schema has any.
+ */
+fn values_doc(schema: &Json) -> Option<String> {
+    let values = enum_values(schema);
+    if values.is_empty() {
+        return None;
+    }
+    let open = schema.get("_enum").is_some();
+    let descriptions = schema
+        .get("enumDescriptions")
+        .map(Json::strings)
+        .unwrap_or_default();
+    let mut doc = String::from("Values:");
+    if descriptions.is_empty() {
+        let values: Vec<String> = values.iter().map(|v| format!("'{}'", v)).collect();
+        write!(doc, " {}", values.join(", ")).unwrap();
+        if open {
+            doc.push_str(", etc.");
+        }
+    } else {
+        for (value, description) in values.iter().zip(descriptions) {
+            write!(doc, "\n'{}': {}", value, description.replace('`', "'")).unwrap();
+        }
+        if open {
+            doc.push_str("\netc.");
+        }
+    }
+    Some(doc)
+}
+
+fn join_doc(description: Option<String>, values: Option<String>) -> Option<String> {
+    match (description, values) {
+        // A blank line ends a list that the description closes with.
+        (Some(description), Some(values)) if ends_with_list(&description) => {
+            Some(format!("{}\n\n{}", description, values))
+        }
+        (Some(description), Some(values)) => Some(format!("{}\n{}", description, values)),
+        (description, values) => description.or(values),
+    }
+}
+
+fn ends_with_list(doc: &str) -> bool {
+    doc.lines()
+        .last()
+        .is_some_and(|line| line.trim_start().starts_with("- "))
+}
+
+/**
+ * Converts a camelCase property name to a snake_case field name. Acronyms
+ * become a single word, so 'clientID' becomes 'client_id'.
+ */
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.trim_start_matches('_').chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+/**
+ * The wire name serde derives for a field with `rename_all = "camelCase"`.
+ */
+fn serde_camel_case(field: &str) -> String {
+    let mut camel = String::new();
+    let mut capitalize = false;
+    for c in field.chars() {
+        if c == '_' {
+            capitalize = true;
+        } else if capitalize {
+            camel.push(c.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+/**
+ * Converts a value or name from the schema to a PascalCase identifier.
+ */
+pub fn pascal_case(value: &str) -> String {
+    value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+/**
+ * The wire name serde derives for a variant with `rename_all = "camelCase"`.
+ */
+fn serde_variant_name(variant: &str) -> String {
+    let mut chars = variant.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn write_doc(code: &mut String, indent: &str, doc: &str) {
+    let width = 80 - indent.len() - 3;
+    writeln!(code, "{}/**", indent).unwrap();
+    for paragraph in doc.lines() {
+        // Continuation lines of a list item are indented to keep them in the
+        // item.
+        let hang = if paragraph.trim_start().starts_with("- ") {
+            "  "
+        } else {
+            ""
+        };
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.trim_start().is_empty() && line.len() + 1 + word.len() > width {
+                writeln!(code, "{} * {}", indent, line).unwrap();
+                line = hang.to_string();
+            }
+            if !line.trim_start().is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        if line.is_empty() {
+            writeln!(code, "{} *", indent).unwrap();
+        } else {
+            writeln!(code, "{} * {}", indent, line).unwrap();
+        }
+    }
+    writeln!(code, "{} */", indent).unwrap();
+}
+
+fn derives(code: &mut String, default: bool, eq: bool) {
+    let mut traits = vec!["Debug"];
+    if default {
+        traits.push("Default");
+    }
+    if eq {
+        traits.push("Eq");
+    }
+    traits.extend(["PartialEq", "Clone", "Deserialize", "Serialize"]);
+    writeln!(code, "#[derive({})]", traits.join(", ")).unwrap();
+}
+
+fn write_struct(code: &mut String, s: &Struct, non_eq: &HashSet<String>) {
+    if let Some(doc) = &s.doc {
+        write_doc(code, "", doc);
+    }
+    let default = s.fields.iter().all(|f| optional_type(&f.ty).is_some());
+    derives(code, default, !non_eq.contains(&s.name));
+    match s.shape {
+        Shape::Marker => {
+            writeln!(code, "pub struct {} {{}}\n", s.name).unwrap();
+            return;
+        }
+        Shape::Transparent => code.push_str("#[serde(transparent)]\n"),
+        Shape::Fields => code.push_str("#[serde(rename_all = \"camelCase\")]\n"),
+    }
+    writeln!(code, "pub struct {} {{", s.name).unwrap();
+    for (i, field) in s.fields.iter().enumerate() {
+        if i > 0 {
+            code.push('\n');
+        }
+        if let Some(doc) = &field.doc {
+            write_doc(code, "    ", doc);
+        }
+        let mut attributes = Vec::new();
+        if s.shape == Shape::Fields && serde_camel_case(&field.name) != field.wire {
+            attributes.push(format!("rename = {:?}", field.wire));
+        }
+        if field.skip_if_none {
+            attributes.push("skip_serializing_if = \"Option::is_none\"".to_string());
+        }
+        if !attributes.is_empty() {
+            writeln!(code, "    #[serde({})]", attributes.join(", ")).unwrap();
+        }
+        writeln!(code, "    pub {}: {},", field.name, field.ty).unwrap();
+    }
+    code.push_str("}\n\n");
+    write_builder(code, s);
+}
+
+/**
+ * Writes a constructor taking the required fields, unless there are none and
+ * the struct derives `Default` instead, and a chainable setter for each
+ * optional field.
+ */
+fn write_builder(code: &mut String, s: &Struct) {
+    let (optional, required): (Vec<&Field>, Vec<&Field>) = s
+        .fields
+        .iter()
+        .partition(|f| optional_type(&f.ty).is_some());
+    writeln!(code, "impl {} {{", s.name).unwrap();
+    if !required.is_empty() {
+        let parameters: Vec<String> = required
+            .iter()
+            .map(|f| format!("{}: impl Into<{}>", f.name, f.ty))
+            .collect();
+        writeln!(
+            code,
+            "    pub fn new({}) -> Self {{\n        {} {{",
+            parameters.join(", "),
+            s.name
+        )
+        .unwrap();
+        for field in &s.fields {
+            match optional_type(&field.ty) {
+                Some(_) => writeln!(code, "            {}: None,", field.name).unwrap(),
+                None => writeln!(code, "            {0}: {0}.into(),", field.name).unwrap(),
+            }
+        }
+        code.push_str("        }\n    }\n\n");
+    }
+    for field in optional {
+        writeln!(
+            code,
+            "    pub fn {0}(mut self, {0}: impl Into<{1}>) -> Self {{\n        self.{0} = Some({0}.into());\n        self\n    }}\n",
+            field.name,
+            optional_type(&field.ty).unwrap_or_default()
+        )
+        .unwrap();
+    }
+    code.push_str("}\n\n");
+}
+
+/**
+ * The type wrapped by an `Option`, for optional and nullable fields.
+ */
+fn optional_type(ty: &str) -> Option<&str> {
+    ty.strip_prefix("Option<")?.strip_suffix('>')
+}
+
+fn write_enum(code: &mut String, e: &Enum) {
+    if let Some(doc) = &e.doc {
+        write_doc(code, "", doc);
+    }
+    derives(code, false, true);
+    code.push_str("#[serde(rename_all = \"camelCase\")]\n");
+    writeln!(code, "pub enum {} {{", e.name).unwrap();
+    for value in &e.values {
+        let variant = pascal_case(value);
+        if serde_variant_name(&variant) != *value {
+            writeln!(code, "    #[serde(rename = {:?})]", value).unwrap();
+        }
+        writeln!(code, "    {},", variant).unwrap();
+    }
+    if e.open {
+        code.push_str("    #[serde(untagged)]\n    Other(String),\n");
+    }
+    code.push_str("}\n\n");
+}
+
+/**
+ * Writes an adjacently tagged enum with a variant per message and an untagged
+ * fallback for unknown messages.
+ */
+fn message_enum(
+    code: &mut String,
+    name: &str,
+    tag: &str,
+    content: &str,
+    variants: &[&Variant],
+    non_eq: &HashSet<String>,
+    unknown_doc: &str,
+) {
+    let eq = variants
+        .iter()
+        .all(|v| v.payload.as_ref().map_or(true, |p| !non_eq.contains(p)));
+    let eq = if eq { "Eq, " } else { "" };
+    writeln!(code, "#[derive(Debug, {}PartialEq, Clone, Serialize)]", eq).unwrap();
+    writeln!(
+        code,
+        "#[serde(rename_all = \"camelCase\", tag = {:?}, content = {:?})]",
+        tag, content
+    )
+    .unwrap();
+    writeln!(code, "pub enum {} {{", name).unwrap();
+    for variant in variants {
+        if serde_variant_name(&variant.name) != variant.wire {
+            writeln!(code, "    #[serde(rename = {:?})]", variant.wire).unwrap();
+        }
+        match &variant.payload {
+            Some(payload) => writeln!(code, "    {}({}),", variant.name, payload).unwrap(),
+            None => writeln!(code, "    {},", variant.name).unwrap(),
+        }
+    }
+    code.push('\n');
+    write_doc(code, "    ", unknown_doc);
+    writeln!(
+        code,
+        "    #[serde(untagged)]\n    Unknown {{\n        {}: String,\n        #[serde(skip_serializing_if = \"Option::is_none\")]\n        {}: Option<Value>,\n    }},",
+        tag, content
+    )
+    .unwrap();
+    code.push_str("}\n\n");
+    write_deserialize(code, name, tag, content, variants);
+}
+
+/**
+ * Writes the deserialization of a message enum. Only unknown tags fall back to
+ * the 'Unknown' variant, so that a known message with an invalid payload fails
+ * to parse. A missing payload parses as an empty object, and the payload of a
+ * variant without one is ignored.
+ */
+fn write_deserialize(
+    code: &mut String,
+    name: &str,
+    tag: &str,
+    content: &str,
+    variants: &[&Variant],
+) {
+    writeln!(
+        code,
+        "impl<'de> Deserialize<'de> for {} {{\n    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{",
+        name
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "        let ({}, {}) = split_message(deserializer, {:?}, {:?})?;\n        Ok(match {}.as_str() {{",
+        tag, content, tag, content, tag
+    )
+    .unwrap();
+    for variant in variants {
+        match &variant.payload {
+            Some(_) => writeln!(
+                code,
+                "            {:?} => {}::{}(parse_content({})?),",
+                variant.wire, name, variant.name, content
+            )
+            .unwrap(),
+            None => writeln!(
+                code,
+                "            {:?} => {}::{},",
+                variant.wire, name, variant.name
+            )
+            .unwrap(),
+        }
+    }
+    writeln!(
+        code,
+        "            _ => {}::Unknown {{ {}, {} }},\n        }})\n    }}\n}}\n",
+        name, tag, content
+    )
+    .unwrap();
+}
+
+/**
+ * Writes a function returning the tag of a message enum, named after the tag.
+ */
+fn tag_fn(code: &mut String, name: &str, tag: &str, doc: &str, variants: &[&Variant]) {
+    writeln!(code, "impl {} {{", name).unwrap();
+    write_doc(code, "    ", doc);
+    writeln!(
+        code,
+        "    pub fn {}(&self) -> &str {{\n        match self {{",
+        tag
+    )
+    .unwrap();
+    for variant in variants {
+        let pattern = if variant.payload.is_some() { "(_)" } else { "" };
+        writeln!(
+            code,
+            "            {}::{}{} => {:?},",
+            name, variant.name, pattern, variant.wire
+        )
+        .unwrap();
+    }
+    writeln!(
+        code,
+        "            {}::Unknown {{ {tag}, .. }} => {tag},",
+        name,
+        tag = tag
+    )
+    .unwrap();
+    code.push_str("        }\n    }\n}\n\n");
+}
+
+/**
+ * Writes a conversion from each payload into its variant of a message enum.
+ */
+fn from_payloads(code: &mut String, name: &str, content: &str, variants: &[&Variant]) {
+    for variant in variants {
+        if let Some(payload) = &variant.payload {
+            writeln!(
+                code,
+                "impl From<{payload}> for {name} {{\n    fn from({content}: {payload}) -> Self {{\n        {name}::{variant}({content})\n    }}\n}}\n",
+                payload = payload,
+                name = name,
+                content = content,
+                variant = variant.name
+            )
+            .unwrap();
+        }
+    }
+}
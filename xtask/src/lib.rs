@@ -0,0 +1,8 @@
+/*!
+
+Development tasks for the crate, run with `cargo xtask <task>`.
+
+*/
+
+pub mod codegen;
+pub mod json;
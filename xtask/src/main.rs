@@ -0,0 +1,143 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command, Stdio};
+
+use xtask::codegen;
+use xtask::json::Json;
+
+const UPSTREAM: &str = "https://raw.githubusercontent.com/microsoft/debug-adapter-protocol";
+
+const USAGE: &str = "usage: cargo xtask codegen [--check] [--schema <path>]
+       cargo xtask schema <commit>";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("codegen") => run_codegen(&args[1..]),
+        Some("schema") => run_schema(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        exit(1);
+    }
+}
+
+fn root() -> Result<&'static Path, String> {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .ok_or_else(|| "xtask is not in a workspace".to_string())
+}
+
+/**
+ * Replaces 'schema/debugAdapterProtocol.json' with the file as of the given
+ * commit of the upstream repository, records the commit in 'schema/UPSTREAM'
+ * and regenerates the code. The file is stored unmodified.
+ */
+fn run_schema(args: &[String]) -> Result<(), String> {
+    let commit = match args {
+        [commit] => commit,
+        _ => return Err(USAGE.to_string()),
+    };
+    if commit.len() != 40 || !commit.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!(
+            "'{}' is not a full commit hash; pin the schema to a commit, not a branch or tag",
+            commit
+        ));
+    }
+    let url = format!("{}/{}/debugAdapterProtocol.json", UPSTREAM, commit);
+    let output = Command::new("curl")
+        .args(["--fail", "--silent", "--show-error", "--location", &url])
+        .output()
+        .map_err(|err| format!("cannot run curl: {}", err))?;
+    if !output.status.success() {
+        return Err(format!(
+            "cannot download {}: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    serde_json::from_slice::<Json>(&output.stdout)
+        .map_err(|err| format!("cannot parse {}: {}", url, err))?;
+
+    let dir = root()?.join("schema");
+    let schema_path = dir.join("debugAdapterProtocol.json");
+    fs::write(&schema_path, &output.stdout)
+        .map_err(|err| format!("cannot write {}: {}", schema_path.display(), err))?;
+    let upstream_path = dir.join("UPSTREAM");
+    fs::write(&upstream_path, format!("{}\n{}\n", commit, url))
+        .map_err(|err| format!("cannot write {}: {}", upstream_path.display(), err))?;
+    run_codegen(&[])
+}
+
+/**
+ * Regenerates 'src/<module>/generated.rs' from the schema. With '--check',
+ * fails instead if any of them is out of date.
+ */
+fn run_codegen(args: &[String]) -> Result<(), String> {
+    let root = root()?;
+    let mut schema_path = root.join("schema").join("debugAdapterProtocol.json");
+    let mut check = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--schema" => schema_path = PathBuf::from(args.next().ok_or(USAGE)?),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let text = fs::read_to_string(&schema_path).map_err(|err| {
+        format!(
+            "cannot read {}: {}\ndownload the schema with `cargo xtask schema <commit>`",
+            schema_path.display(),
+            err
+        )
+    })?;
+    let schema: Json = serde_json::from_str(&text)
+        .map_err(|err| format!("cannot parse {}: {}", schema_path.display(), err))?;
+
+    let mut stale = Vec::new();
+    for (module, source) in codegen::generate(&schema)? {
+        let source = rustfmt(&source)?;
+        let path = root.join("src").join(module.name()).join("generated.rs");
+        if check {
+            if fs::read_to_string(&path).ok().as_deref() != Some(source.as_str()) {
+                stale.push(path.display().to_string());
+            }
+        } else {
+            fs::write(&path, source)
+                .map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+        }
+    }
+    if !stale.is_empty() {
+        return Err(format!(
+            "out of date, run `cargo xtask codegen`: {}",
+            stale.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+fn rustfmt(source: &str) -> Result<String, String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("cannot run rustfmt: {}", err))?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .map_err(|err| format!("cannot write to rustfmt: {}", err))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|err| format!("rustfmt failed: {}", err))?;
+    if !output.status.success() {
+        return Err("rustfmt failed to format the generated code".to_string());
+    }
+    String::from_utf8(output.stdout).map_err(|err| err.to_string())
+}
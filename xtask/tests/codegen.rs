@@ -0,0 +1,208 @@
+use xtask::codegen::{self, Module};
+use xtask::json::Json;
+
+fn generate() -> Vec<(Module, String)> {
+    let schema: Json = serde_json::from_str(include_str!("fixtures/schema.json")).unwrap();
+    codegen::generate(&schema).unwrap()
+}
+
+fn module(modules: &[(Module, String)], module: Module) -> String {
+    let source = &modules.iter().find(|(m, _)| *m == module).unwrap().1;
+    // Compare without whitespace, since the source is not formatted.
+    source.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn assert_contains(source: &str, snippet: &str) {
+    let snippet = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert!(
+        source.contains(&snippet),
+        "missing:\n{}\n\nin:\n{}",
+        snippet,
+        source
+    );
+}
+
+#[test]
+fn requests() {
+    let requests = module(&generate(), Module::Requests);
+    assert_contains(
+        &requests,
+        r#"pub enum RequestCommand {
+            Initialize(InitializeRequestArguments),
+            Threads,
+            ConfigurationDone,"#,
+    );
+    assert_contains(
+        &requests,
+        r#"RequestCommand::ConfigurationDone => "configurationDone",
+        RequestCommand::Unknown { command, .. } => command,"#,
+    );
+    assert_contains(
+        &requests,
+        r#"impl_request!(InitializeRequestArguments => Initialize, "initialize", InitializeResponse);
+        impl_request!(ThreadsArguments => Threads(), "threads", ThreadsResponse);
+        impl_request!(ConfigurationDoneArguments => ConfigurationDone(), "configurationDone", ());"#,
+    );
+    assert_contains(
+        &requests,
+        "#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)] pub struct ThreadsArguments {}",
+    );
+    assert_contains(
+        &requests,
+        r#"#[serde(rename = "clientID", skip_serializing_if = "Option::is_none")]
+        pub client_id: Option<String>,"#,
+    );
+    assert_contains(
+        &requests,
+        "#[serde(rename = \"adapterID\")] pub adapter_id: String,",
+    );
+    assert_contains(
+        &requests,
+        r#"#[serde(rename = "supportsANSIStyling", skip_serializing_if = "Option::is_none")]
+        pub supports_ansi_styling: Option<bool>,"#,
+    );
+    assert_contains(
+        &requests,
+        "pub enum PathFormat { Path, Uri, #[serde(untagged)] Other(String), }",
+    );
+    assert_contains(
+        &requests,
+        "use crate::responses::{InitializeResponse, ResponseBody, ThreadsResponse};",
+    );
+}
+
+#[test]
+fn responses() {
+    let responses = module(&generate(), Module::Responses);
+    assert_contains(
+        &responses,
+        "pub enum ResponseBody { Initialize(InitializeResponse), Threads(ThreadsResponse), ConfigurationDone,",
+    );
+    assert_contains(
+        &responses,
+        "#[serde(transparent)] pub struct InitializeResponse { /** * The capabilities of this debug adapter. */ pub capabilities: Capabilities, }",
+    );
+    assert_contains(&responses, "pub struct ThreadsResponse {");
+    assert_contains(&responses, "pub threads: Vec<Thread>,");
+    assert_contains(&responses, "use crate::types::{Capabilities, Thread};");
+}
+
+#[test]
+fn events() {
+    let events = module(&generate(), Module::Events);
+    assert_contains(
+        &events,
+        r#"#[serde(rename_all = "camelCase", tag = "event", content = "body")]
+        pub enum EventBody { Stopped(StoppedEvent), Initialized,"#,
+    );
+    assert_contains(
+        &events,
+        r#"/**
+         * The reason for the event.
+         * Values: 'step', 'breakpoint', 'function breakpoint', etc.
+         */
+        pub reason: StoppedReason,"#,
+    );
+    assert_contains(
+        &events,
+        r#"Breakpoint, #[serde(rename = "function breakpoint")] FunctionBreakpoint, #[serde(untagged)] Other(String),"#,
+    );
+    assert_contains(&events, "pub thread_id: Option<ThreadId>,");
+    assert_contains(
+        &events,
+        "pub hit_breakpoint_ids: Option<Vec<BreakpointId>>,",
+    );
+    assert_contains(&events, "use crate::types::{BreakpointId, ThreadId};");
+    assert_contains(
+        &events,
+        r#"pub fn event(&self) -> &str { match self { EventBody::Stopped(_) => "stopped", EventBody::Initialized => "initialized","#,
+    );
+    assert_contains(&events, "EventBody::Unknown { event, .. } => event,");
+    assert_contains(
+        &events,
+        "impl From<StoppedEvent> for EventBody { fn from(body: StoppedEvent) -> Self { EventBody::Stopped(body) } }",
+    );
+}
+
+#[test]
+fn types() {
+    let types = module(&generate(), Module::Types);
+    assert_contains(
+        &types,
+        r#"pub enum ChecksumAlgorithm { #[serde(rename = "MD5")] MD5, #[serde(rename = "SHA1")] SHA1, Timestamp, }"#,
+    );
+    assert_contains(
+        &types,
+        "pub supported_checksum_algorithms: Option<Vec<ChecksumAlgorithm>>,",
+    );
+    assert_contains(&types, "pub data: Option<Value>,");
+    assert_contains(&types, "pub code: Option<NumberOrString>,");
+    assert_contains(&types, "pub env: Option<HashMap<String, Option<String>>>,");
+    assert_contains(&types, "pub weight: Option<f64>,");
+    assert_contains(&types, "pub count: Option<i64>,");
+    assert_contains(&types, "pub id: ThreadId,");
+    assert_contains(&types, "use super::ThreadId;");
+    // 'f64' is not 'Eq', so neither is the struct holding it.
+    assert_contains(&types, "#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)] #[serde(rename_all = \"camelCase\")] pub struct Thread {");
+    assert_contains(
+        &types,
+        "use serde_json::Value; use std::collections::HashMap;",
+    );
+    assert_contains(&types, "use crate::NumberOrString;");
+    // Only optional fields, so there is a default.
+    assert_contains(&types, "#[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize)] #[serde(rename_all = \"camelCase\")] pub struct Capabilities {");
+    assert_contains(
+        &types,
+        "pub fn new(id: impl Into<ThreadId>, name: impl Into<String>) -> Self { Thread { id: id.into(), name: name.into(), data: None,",
+    );
+    assert_contains(
+        &types,
+        "pub fn count(mut self, count: impl Into<i64>) -> Self { self.count = Some(count.into()); self }",
+    );
+}
+
+#[test]
+fn keyword_fields_need_a_name() {
+    let schema: Json = serde_json::from_str(
+        r#"{ "definitions": { "Widget": {
+            "type": "object",
+            "properties": { "type": { "type": "string" } }
+        } } }"#,
+    )
+    .unwrap();
+    let err = codegen::generate(&schema).unwrap_err();
+    assert!(err.contains("Widget.type"), "{}", err);
+}
+
+#[test]
+fn extended_definitions_have_the_base_properties() {
+    let schema: Json = serde_json::from_str(
+        r##"{ "definitions": {
+            "ValueFormat": {
+                "type": "object",
+                "properties": { "hex": { "type": "boolean" } }
+            },
+            "StackFrameFormat": {
+                "allOf": [ { "$ref": "#/definitions/ValueFormat" }, {
+                    "type": "object",
+                    "description": "Formatting of a stack frame.",
+                    "properties": { "line": { "type": "boolean" } },
+                    "required": [ "line" ]
+                }]
+            }
+        } }"##,
+    )
+    .unwrap();
+    let types = module(&codegen::generate(&schema).unwrap(), Module::Types);
+    assert_contains(
+        &types,
+        r#"/** * Formatting of a stack frame. */
+        #[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct StackFrameFormat {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub hex: Option<bool>,
+            pub line: bool,
+        }"#,
+    );
+}